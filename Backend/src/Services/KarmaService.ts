@@ -41,6 +41,7 @@ export class KarmaService {
     static async handleUpvoteKarma(buzzOwnerId: string) {
         return await this.addKarma(buzzOwnerId, 1);
     }
+    // Keep in step with `GlobalConfig.tipKarmaRule.karmaPerTip`, which credits the same bonus on-chain.
     static async handleTipKarma(buzzOwnerId: string) {
         return await this.addKarma(buzzOwnerId, 5);
     }
@@ -1,6 +1,7 @@
 import { connection, privy, program } from "../Singelton/index"
 import fs from "fs"
-import { Keypair, PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { Ed25519Program, Keypair, PublicKey } from "@solana/web3.js";
 import {
     SystemProgram, VersionedTransaction,
     TransactionMessage
@@ -17,6 +18,16 @@ const wallet = JSON.parse(fs.readFileSync("./admin_key.json", "utf-8"));
 const CONFIG_SEED = Buffer.from("config");
 const USER_SEED = Buffer.from("user");
 const MINT_AUTHORITY_SEED = Buffer.from("mint_authority");
+const MILESTONE_REGISTRY_SEED = Buffer.from("milestone_registry");
+const BADGE_COLLECTION_SEED = Buffer.from("badge_collection");
+const BADGE_SEED = Buffer.from("badge");
+const MILESTONE_RECORD_SEED = Buffer.from("milestone_record");
+const KARMA_HISTORY_SEED = Buffer.from("karma_history");
+const MERKLE_EPOCH_SEED = Buffer.from("merkle_epoch");
+const TIP_KARMA_SEED = Buffer.from("tip_karma");
+const KARMA_VOUCHER_PREFIX = Buffer.from("voix:karma_voucher");
+const KARMA_VOUCHER_TTL_SECS = 10 * 60;
+const KARMA_LEAF_PREFIX = Buffer.from("voix:karma_leaf");
 
 const admin = Keypair.fromSecretKey(Buffer.from(wallet))
 const [globalConfigPda] = PublicKey.findProgramAddressSync(
@@ -25,9 +36,6 @@ const [globalConfigPda] = PublicKey.findProgramAddressSync(
 );
 
 
-const NFT_NAME = "Voix Milestone";
-const NFT_SYMBOL = "VOIX";
-const NFT_URI = "https://voix.com/metadata/";
 const MPL_TOKEN_METADATA_PROGRAM_ID = new PublicKey(
     "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
   );
@@ -51,21 +59,27 @@ export class VoixContract {
 
     }
 
-    static async setbuilder_merkle_root() {
-        const root1 = Buffer.from(Array.from({ length: 32 }, (_, i) => i + 1));
-        const CONFIG_SEED = Buffer.from("config")
-        const newEpoch = new BN(1);
+    // Publishes the karma leaves of `entries` as the next epoch's root, which users then
+    // redeem with `sync_karma_from_root`. The leaf count is the size of that tree.
+    static async setbuilder_merkle_root(entries: { user: PublicKey, karma: number }[]) {
+        const configAccount = await program.account.globalConfig.fetch(globalConfigPda);
+        const newEpoch = configAccount.epoch.add(new BN(1));
+        const { root, leafCount } = this.getKarmaMerkleTree(entries, newEpoch);
 
-        const [globalConfigPda] = PublicKey.findProgramAddressSync(
-            [CONFIG_SEED],
+        // Every root is also kept in its own `MerkleEpoch` account, so old proofs stay checkable
+        const [merkleEpochPda] = PublicKey.findProgramAddressSync(
+            [MERKLE_EPOCH_SEED, newEpoch.toArrayLike(Buffer, "le", 8)],
             program.programId
         );
 
         await program.methods
-            .submitMerkleRoot(Array.from(root1) as [number, ...number[]], newEpoch)
+            .submitMerkleRoot(Array.from(root) as [number, ...number[]], newEpoch, new BN(leafCount), null)
             .accounts({
-                admin: admin.publicKey,
+                merklePublisher: admin.publicKey,
+                // @ts-ignore
                 globalConfig: globalConfigPda,
+                merkleEpoch: merkleEpochPda,
+                systemProgram: SystemProgram.programId,
             })
             .signers([admin])
             .rpc();
@@ -77,9 +91,10 @@ export class VoixContract {
             .updateUserKarma(newkarma)
             .accounts({
                 // @ts-ignore
-                admin: admin.publicKey,
+                karmaOracle: admin.publicKey,
                 globalConfig: globalConfigPda,
                 userAccount: user1AccountPda,
+                karmaHistory: await this.getKarmaHistoryPda(user_pub_key),
                 userToUpdate: user_pub_key
             })
             .signers([admin])
@@ -91,9 +106,10 @@ export class VoixContract {
         const result = await program.methods
             .initializeUser()
             .accounts({
-                admin: admin.publicKey,
-                user: user_pub_key,
+                userSponsor: admin.publicKey,
                 // @ts-ignore
+                globalConfig: globalConfigPda,
+                user: user_pub_key,
                 userAccount: user1AccountPda,
                 systemProgram: SystemProgram.programId,
             })
@@ -109,13 +125,112 @@ export class VoixContract {
         await program.methods.updateUserKarma(new_karma).accounts({
 
             // @ts-ignore
-            admin: admin.publicKey,
+            karmaOracle: admin.publicKey,
             globalConfig: globalConfigPda,
             userAccount: user1AccountPda,
+            karmaHistory: await this.getKarmaHistoryPda(user_pub_key),
             userToUpdate: user_pub_key,
         }).signers([admin]).rpc();
     }
 
+    // Adds `delta` on-chain instead of overwriting karma. The sequence number makes a racing
+    // or retried delta fail with `KarmaSequenceMismatch` rather than being lost or doubled.
+    static async apply_karma_delta(user_pub_key: PublicKey, delta: number, reason: "upvote" | "downvote" | "tip" | "moderation") {
+        const user1AccountPda = this.getUserPda(user_pub_key)
+        const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+        await program.methods
+            .applyKarmaDelta(delta, { [reason]: {} } as any, userAccount.karmaSeq)
+            .accounts({
+                // @ts-ignore
+                karmaOracle: admin.publicKey,
+                globalConfig: globalConfigPda,
+                userAccount: user1AccountPda,
+                karmaHistory: await this.getKarmaHistoryPda(user_pub_key),
+                userToUpdate: user_pub_key,
+            })
+            .signers([admin])
+            .rpc();
+    }
+
+    // Sets karma for many users in one transaction. Keep batches small enough to fit
+    // in a transaction (roughly 20 users, fewer if they keep a karma history).
+    static async batch_update_karma(entries: { user: PublicKey, karma: number }[]) {
+        const remainingAccounts = [];
+        for (const { user } of entries) {
+            remainingAccounts.push({ pubkey: this.getUserPda(user), isSigner: false, isWritable: true });
+            // A user's history, if they keep one, goes right after their account
+            const karmaHistoryPda = await this.getKarmaHistoryPda(user);
+            if (karmaHistoryPda) {
+                remainingAccounts.push({ pubkey: karmaHistoryPda, isSigner: false, isWritable: true });
+            }
+        }
+        await program.methods
+            .batchUpdateKarma(entries)
+            .accounts({
+                karmaOracle: admin.publicKey,
+                // @ts-ignore
+                globalConfig: globalConfigPda,
+            })
+            .remainingAccounts(remainingAccounts)
+            .signers([admin])
+            .rpc();
+    }
+
+    // Signs a karma voucher the user redeems with `claim_karma`, so no admin transaction is
+    // needed per user. The ed25519 instruction must go right before `claim_karma`.
+    static async create_karma_voucher(user_pub_key: PublicKey, new_karma: number) {
+        const user1AccountPda = this.getUserPda(user_pub_key)
+        const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+        const voucher = {
+            programId: program.programId,
+            user: user_pub_key,
+            karma: new_karma,
+            nonce: userAccount.karmaNonce.add(new BN(1)),
+            expiresAt: new BN(Math.floor(Date.now() / 1000) + KARMA_VOUCHER_TTL_SECS),
+        };
+        const message = Buffer.concat([
+            KARMA_VOUCHER_PREFIX,
+            program.coder.types.encode("karmaVoucher", voucher),
+        ]);
+        const ed25519Instruction = Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: admin.secretKey,
+            message,
+        });
+        return { voucher, ed25519Instruction };
+    }
+
+    // A "super upvote": moves karma from the giver to the receiver, signed by the giver's wallet.
+    // Capped per day by `GlobalConfig.karmaGiftRules`.
+    static async gift_karma(giver_pubkey: PublicKey, reciver_pubkey: PublicKey, amount: number, signer_string: string) {
+        try {
+            const instruction = await program.methods.giftKarma(amount).accounts({
+                giver: giver_pubkey,
+                // @ts-ignore
+                globalConfig: globalConfigPda,
+                giverAccount: this.getUserPda(giver_pubkey),
+                receiverAccount: this.getUserPda(reciver_pubkey),
+                giverKarmaHistory: await this.getKarmaHistoryPda(giver_pubkey),
+                receiverKarmaHistory: await this.getKarmaHistoryPda(reciver_pubkey),
+                receiver: reciver_pubkey,
+            }).instruction();
+            const { blockhash: recentBlockhash } = await connection.getLatestBlockhash();
+            const message = new TransactionMessage({
+                payerKey: giver_pubkey,
+                instructions: [instruction],
+                recentBlockhash
+            });
+            const transaction = new VersionedTransaction(message.compileToV0Message());
+            const data = await privy.wallets().solana().signTransaction(signer_string, {
+                transaction: Buffer.from(transaction.serialize()).toString('base64')
+            });
+            await connection.sendRawTransaction(Buffer.from(data.signed_transaction, 'base64'));
+            return true;
+        } catch (error) {
+            console.error("gift_karma error:", error);
+            throw new Error(`Failed to gift karma: ${error instanceof Error ? error.message : String(error)}`);
+        }
+    }
+
     static async tipuser_sol(tipper_pubkey: PublicKey, reciver_pubkey: PublicKey, amount: number, signer_string: string) {
         try {
             // Convert SOL to lamports (1 SOL = 1e9 lamports)
@@ -126,6 +241,9 @@ export class VoixContract {
                 receiver: reciver_pubkey,
                 // @ts-ignore
                 receiverAccount: user2AccountPda,
+                globalConfig: globalConfigPda,
+                tipperAccount: await this.getTipperAccountPda(tipper_pubkey, reciver_pubkey),
+                ...(await this.getTipKarmaAccounts(tipper_pubkey, reciver_pubkey, tipamount, null)),
                 systemProgram: SystemProgram.programId,
             }).instruction();
             const { blockhash: recentBlockhash } = await connection.getLatestBlockhash();
@@ -175,6 +293,9 @@ export class VoixContract {
                     receiver: reciver_pubkey,
                     // @ts-ignore
                     receiverAccount: reciverAccountPda,
+                    globalConfig: globalConfigPda,
+                    tipperAccount: await this.getTipperAccountPda(tipper_pubkey, reciver_pubkey),
+                    ...(await this.getTipKarmaAccounts(tipper_pubkey, reciver_pubkey, new BN(tipAmount), mint)),
                     mint: mint,
                     tipperTokenAccount: tipperTokenAccount,
                     receiverTokenAccount: receiverTokenAccount,
@@ -214,10 +335,29 @@ export class VoixContract {
                 [MINT_AUTHORITY_SEED],
                 program.programId
               );
-            
+            const [milestoneRegistryPda] = PublicKey.findProgramAddressSync(
+                [MILESTONE_REGISTRY_SEED],
+                program.programId
+            );
     
-            const mintKeypair = Keypair.generate();
-            const mint = mintKeypair.publicKey;
+            // The badge mint and its milestone record are PDAs of (user, level).
+            // A milestone minted again after a revocation gets a new mint, seeded
+            // with the record's revocation count.
+            const [milestoneRecord] = PublicKey.findProgramAddressSync(
+                [MILESTONE_RECORD_SEED, user_pub_key.toBuffer(), Buffer.from([milestone_level])],
+                program.programId
+            );
+            const record = await program.account.milestoneRecord.fetchNullable(milestoneRecord);
+            const revocations = record?.revocations ?? 0;
+            const [mint] = PublicKey.findProgramAddressSync(
+                [
+                    BADGE_SEED,
+                    user_pub_key.toBuffer(),
+                    Buffer.from([milestone_level]),
+                    ...(revocations > 0 ? [Buffer.from([revocations])] : []),
+                ],
+                program.programId
+            );
             const { metadataAccount, masterEditionAccount } = await this.getMetaplexPDAs(
               mint
             );
@@ -226,22 +366,34 @@ export class VoixContract {
               user_pub_key
             );
             const user1AccountPda = this.getUserPda(user_pub_key)
+            // Every badge is verified into the Voix collection NFT
+            const [collectionMint] = PublicKey.findProgramAddressSync(
+                [BADGE_COLLECTION_SEED],
+                program.programId
+            );
+            const {
+                metadataAccount: collectionMetadata,
+                masterEditionAccount: collectionMasterEdition,
+            } = await this.getMetaplexPDAs(collectionMint);
+            // The badge name, symbol and URI come from the on-chain milestone registry
             let instruction = await program.methods
-            .mintMilestoneNft(
-              milestone_level,
-              `${NFT_NAME} ${milestone_level == 1 ? "Bronze" : milestone_level == 2 ? "Silver" : "Gold"}`,
-              NFT_SYMBOL,
-              `${NFT_URI}${milestone_level == 1 ? "bronze" : milestone_level == 2 ? "silver" : "gold"}`
-            )
+            .mintMilestoneNft(milestone_level)
             .accounts({
               user: user_pub_key,
               // @ts-ignore
               userAccount: user1AccountPda,
+              karmaHistory: await this.getKarmaHistoryPda(user_pub_key),
+              globalConfig: globalConfigPda,
+              milestoneRegistry: milestoneRegistryPda,
               mintAuthority: mintAuthorityPda,
+              milestoneRecord,
               mint: mint,
               tokenAccount: tokenAccount,
               metadataAccount: metadataAccount,
               masterEditionAccount: masterEditionAccount,
+              collectionMint,
+              collectionMetadata,
+              collectionMasterEdition,
               tokenProgram: TOKEN_PROGRAM_ID,
               associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
               systemProgram: SystemProgram.programId,
@@ -255,10 +407,7 @@ export class VoixContract {
                 recentBlockhash
             });
             const yourSolanaTransaction = new VersionedTransaction(message.compileToV0Message());
-            
-            // Sign with mint keypair first (required since mint is a signer account)
-            yourSolanaTransaction.sign([mintKeypair]);
-            
+
             const data = await privy.wallets().solana().signTransaction(signer_string, {
                 transaction: Buffer.from(yourSolanaTransaction.serialize()).toString('base64')
             });
@@ -281,6 +430,76 @@ export class VoixContract {
         return user1AccountPda;
     }
 
+    // Tips count toward the tipper's achievements when they have a Voix account.
+    // Self-tips are never counted and can't pass it.
+    static async getTipperAccountPda(tipper_pubkey: PublicKey, reciver_pubkey: PublicKey): Promise<PublicKey | null> {
+        if (tipper_pubkey.equals(reciver_pubkey)) {
+            return null;
+        }
+        const tipperAccountPda = this.getUserPda(tipper_pubkey);
+        const info = await connection.getAccountInfo(tipperAccountPda);
+        return info ? tipperAccountPda : null;
+    }
+
+    // A tip from another Voix user earns the receiver karma on-chain once it clears
+    // `GlobalConfig.tipKarmaRule`, so the tip instruction then needs the pair's credit
+    // account (created by the tip) and the receiver's history. Skipped otherwise, so
+    // small tips don't pay rent for a credit account. `mint` is null for SOL tips;
+    // SPL tips only earn karma in the rule's `splMint`.
+    static async getTipKarmaAccounts(tipper_pubkey: PublicKey, reciver_pubkey: PublicKey, amount: BN, mint: PublicKey | null) {
+        const none = { tipKarmaCredit: null, receiverKarmaHistory: null };
+        if (!(await this.getTipperAccountPda(tipper_pubkey, reciver_pubkey))) {
+            return none;
+        }
+        const { tipKarmaRule: rule } = await program.account.globalConfig.fetch(globalConfigPda);
+        const minAmount = !mint
+            ? rule.minSolAmount
+            : mint.equals(rule.splMint) ? rule.minSplAmount : new BN(0);
+        if (rule.karmaPerTip === 0 || minAmount.isZero() || amount.lt(minAmount)) {
+            return none;
+        }
+        const [tipKarmaCredit] = PublicKey.findProgramAddressSync(
+            [TIP_KARMA_SEED, tipper_pubkey.toBuffer(), reciver_pubkey.toBuffer()],
+            program.programId
+        );
+        return { tipKarmaCredit, receiverKarmaHistory: await this.getKarmaHistoryPda(reciver_pubkey) };
+    }
+
+    // Builds the tree `sync_karma_from_root` checks proofs against: each leaf is
+    // sha256(KARMA_LEAF_PREFIX || user || karma (u32 LE) || epoch (u64 LE)), parents hash
+    // the sorted pair and a node without a sibling moves up unchanged.
+    static getKarmaMerkleTree(entries: { user: PublicKey, karma: number }[], epoch: BN) {
+        if (entries.length === 0) {
+            throw new Error("A karma Merkle tree needs at least one entry");
+        }
+        const sha256 = (...parts: Buffer[]) => createHash("sha256").update(Buffer.concat(parts)).digest();
+        let level = entries.map(({ user, karma }) => sha256(
+            KARMA_LEAF_PREFIX,
+            user.toBuffer(),
+            new BN(karma).toArrayLike(Buffer, "le", 4),
+            epoch.toArrayLike(Buffer, "le", 8)
+        ));
+        while (level.length > 1) {
+            const next: Buffer[] = [];
+            for (let i = 0; i < level.length; i += 2) {
+                const [a, b] = [level[i], level[i + 1]];
+                next.push(!b ? a : Buffer.compare(a, b) <= 0 ? sha256(a, b) : sha256(b, a));
+            }
+            level = next;
+        }
+        return { root: level[0], leafCount: entries.length };
+    }
+
+    // Karma writes must pass the user's `KarmaHistory` once they have created one
+    static async getKarmaHistoryPda(user_pub_key: PublicKey): Promise<PublicKey | null> {
+        const [karmaHistoryPda] = PublicKey.findProgramAddressSync(
+            [KARMA_HISTORY_SEED, user_pub_key.toBuffer()],
+            program.programId
+        );
+        const info = await connection.getAccountInfo(karmaHistoryPda);
+        return info ? karmaHistoryPda : null;
+    }
+
     static async getMetaplexPDAs(mint: PublicKey)  {
         const [metadataAccount] = PublicKey.findProgramAddressSync(
           [
@@ -0,0 +1,6231 @@
+/**
+ * Program IDL in camelCase format in order to be used in JS/TS.
+ *
+ * Note that this is only a type helper and is not the actual IDL. The original
+ * IDL can be found at `target/idl/voix.json`.
+ */
+export type Voix = {
+  "address": "41ixdTNskpBiezYZyjigB5k3MTgdv3CcFHYFuVPy3mbK",
+  "metadata": {
+    "name": "voix",
+    "version": "0.1.0",
+    "spec": "0.1.0",
+    "description": "Created with Anchor"
+  },
+  "instructions": [
+    {
+      "name": "initializeConfig",
+      "docs": [
+        "Instruction to initialize the platform's global configuration (only admin)"
+      ],
+      "discriminator": [
+        208,
+        127,
+        21,
+        1,
+        194,
+        190,
+        196,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeUser",
+      "docs": [
+        "Instruction for the user sponsor to create a new user's on-chain UserAccount PDA"
+      ],
+      "discriminator": [
+        111,
+        17,
+        185,
+        250,
+        60,
+        122,
+        38,
+        254
+      ],
+      "accounts": [
+        {
+          "name": "userSponsor",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "user",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "submitMerkleRoot",
+      "docs": [
+        "Instruction for the Merkle publisher (backend) to submit the latest Merkle root of all off-chain content (posts, comments, etc.)"
+      ],
+      "discriminator": [
+        191,
+        196,
+        3,
+        78,
+        21,
+        12,
+        96,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "merklePublisher",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "merkleEpoch",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "merkleRoot",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "epoch",
+          "type": "u64"
+        },
+        {
+          "name": "leafCount",
+          "type": "u64"
+        },
+        {
+          "name": "treeUri",
+          "type": {
+            "option": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateUserKarma",
+      "docs": [
+        "Instruction for the karma oracle (backend) to update a user's on-chain karma score."
+      ],
+      "discriminator": [
+        180,
+        9,
+        120,
+        194,
+        10,
+        140,
+        159,
+        10
+      ],
+      "accounts": [
+        {
+          "name": "karmaOracle",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "userToUpdate",
+          "docs": [
+            "It's not a signer, just an address used to find the `user_account` PDA."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "newKarma",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "tipUserSol",
+      "docs": [
+        "Instruction for a user to tip another user with native SOL."
+      ],
+      "discriminator": [
+        239,
+        233,
+        131,
+        3,
+        126,
+        235,
+        78,
+        245
+      ],
+      "accounts": [
+        {
+          "name": "tipper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "receiver",
+          "docs": [
+            "checking it against the `receiver_account` PDA."
+          ],
+          "writable": true
+        },
+        {
+          "name": "receiverAccount",
+          "writable": true
+        },
+        {
+          "name": "tipperAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tipKarmaCredit",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiverKarmaHistory",
+          "docs": [
+            "Required when the tip earns karma and the receiver has a `KarmaHistory`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "tipUserSpl",
+      "docs": [
+        "Instruction for a user to tip another user with any SPL Token (e.g., USDC)."
+      ],
+      "discriminator": [
+        135,
+        148,
+        107,
+        138,
+        222,
+        156,
+        33,
+        183
+      ],
+      "accounts": [
+        {
+          "name": "tipper",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "receiver"
+        },
+        {
+          "name": "receiverAccount",
+          "writable": true
+        },
+        {
+          "name": "tipperAccount",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "tipKarmaCredit",
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiverKarmaHistory",
+          "docs": [
+            "Required when the tip earns karma and the receiver has a `KarmaHistory`."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mint"
+        },
+        {
+          "name": "tipperTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "receiverTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "mintMilestoneNft",
+      "docs": [
+        "Instruction for a user to mint a milestone NFT (e.g., \"Bronze Badge\")",
+        "The badge metadata is taken from the milestone registry."
+      ],
+      "discriminator": [
+        13,
+        9,
+        43,
+        63,
+        136,
+        223,
+        110,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry"
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "milestoneRecord",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true
+        },
+        {
+          "name": "masterEditionAccount",
+          "writable": true
+        },
+        {
+          "name": "collectionMint"
+        },
+        {
+          "name": "collectionMetadata",
+          "writable": true
+        },
+        {
+          "name": "collectionMasterEdition"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "rent"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "The Metaplex Token Metadata Program."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneLevel",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "proposeAdmin",
+      "docs": [
+        "Instruction for the admin to nominate a new admin (step 1 of the handover)."
+      ],
+      "discriminator": [
+        121,
+        214,
+        199,
+        212,
+        87,
+        39,
+        117,
+        234
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "newAdmin",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "acceptAdmin",
+      "docs": [
+        "Instruction for the nominated admin to accept the role (step 2 of the handover)."
+      ],
+      "discriminator": [
+        112,
+        42,
+        45,
+        90,
+        116,
+        181,
+        13,
+        170
+      ],
+      "accounts": [
+        {
+          "name": "newAdmin",
+          "docs": [
+            "The nominated admin. Must match `global_config.pending_admin`."
+          ],
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelAdminProposal",
+      "docs": [
+        "Instruction for the admin to withdraw a pending admin nomination."
+      ],
+      "discriminator": [
+        68,
+        6,
+        145,
+        131,
+        16,
+        73,
+        182,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "grantRole",
+      "docs": [
+        "Instruction for the admin to assign a role (karma oracle, Merkle publisher, ...) to a key."
+      ],
+      "discriminator": [
+        218,
+        234,
+        128,
+        15,
+        82,
+        33,
+        236,
+        253
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        },
+        {
+          "name": "authority",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "revokeRole",
+      "docs": [
+        "Instruction for the admin to take a role away from its current holder."
+      ],
+      "discriminator": [
+        179,
+        232,
+        2,
+        180,
+        48,
+        227,
+        82,
+        7
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "role",
+          "type": {
+            "defined": {
+              "name": "role"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "enableCouncil",
+      "docs": [
+        "Instruction for the admin to hand configuration changes over to an M-of-N council."
+      ],
+      "discriminator": [
+        77,
+        196,
+        39,
+        179,
+        255,
+        73,
+        171,
+        178
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "council",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "members",
+          "type": {
+            "vec": "pubkey"
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createCouncilProposal",
+      "docs": [
+        "Instruction for a council member to propose a configuration change."
+      ],
+      "discriminator": [
+        152,
+        57,
+        51,
+        174,
+        86,
+        182,
+        114,
+        75
+      ],
+      "accounts": [
+        {
+          "name": "proposer",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "council",
+          "writable": true
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "action",
+          "type": {
+            "defined": {
+              "name": "councilAction"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "approveCouncilProposal",
+      "docs": [
+        "Instruction for a council member to approve a pending proposal."
+      ],
+      "discriminator": [
+        30,
+        80,
+        146,
+        69,
+        203,
+        242,
+        216,
+        104
+      ],
+      "accounts": [
+        {
+          "name": "member",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "council"
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "executeCouncilProposal",
+      "docs": [
+        "Instruction to run a council proposal once it has enough approvals.",
+        "Badge actions take the badge's accounts in `remaining_accounts`."
+      ],
+      "discriminator": [
+        169,
+        70,
+        236,
+        8,
+        221,
+        14,
+        132,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "executor",
+          "docs": [
+            "Anyone may execute a proposal once it has enough approvals."
+          ],
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "council",
+          "writable": true
+        },
+        {
+          "name": "proposal",
+          "writable": true
+        },
+        {
+          "name": "milestoneRegistry",
+          "docs": [
+            "Only needed for the milestone tier and metadata actions."
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setPaused",
+      "docs": [
+        "Instruction for the pauser to stop or resume tips, mints, onboarding or karma writes."
+      ],
+      "discriminator": [
+        91,
+        60,
+        125,
+        192,
+        176,
+        225,
+        166,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "pauser",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "pausedFlags",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "migrateConfig",
+      "docs": [
+        "Instruction for the admin to upgrade a pre-versioning GlobalConfig to the current layout."
+      ],
+      "discriminator": [
+        92,
+        131,
+        58,
+        105,
+        210,
+        154,
+        224,
+        193
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "docs": [
+            "Must be the admin recorded in the old layout. Pays the extra rent."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "docs": [
+            "so the PDA seeds, owner and discriminator are checked by hand."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUserAccount",
+      "docs": [
+        "Instruction for the admin or the user to upgrade an old UserAccount to the current layout."
+      ],
+      "discriminator": [
+        198,
+        152,
+        90,
+        54,
+        134,
+        206,
+        92,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Either the user themselves or the admin. Pays the extra rent."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "user"
+        },
+        {
+          "name": "userAccount",
+          "docs": [
+            "so the PDA seeds, owner and discriminator are checked by hand."
+          ],
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeMilestoneRegistry",
+      "docs": [
+        "Instruction for the admin to create the milestone registry with the default tiers."
+      ],
+      "discriminator": [
+        25,
+        199,
+        207,
+        78,
+        214,
+        125,
+        12,
+        28
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addMilestoneTier",
+      "docs": [
+        "Instruction for the admin to add a new milestone tier."
+      ],
+      "discriminator": [
+        23,
+        150,
+        251,
+        120,
+        203,
+        242,
+        156,
+        130
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": {
+            "defined": {
+              "name": "milestoneTier"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateMilestoneTier",
+      "docs": [
+        "Instruction for the admin to edit an existing milestone tier."
+      ],
+      "discriminator": [
+        20,
+        233,
+        1,
+        106,
+        237,
+        231,
+        21,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "tier",
+          "type": {
+            "defined": {
+              "name": "milestoneTier"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "retireMilestoneTier",
+      "docs": [
+        "Instruction for the admin to retire a milestone tier so it can no longer be minted."
+      ],
+      "discriminator": [
+        168,
+        13,
+        147,
+        174,
+        197,
+        192,
+        208,
+        46
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "level",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateMilestoneMetadata",
+      "docs": [
+        "Instruction for the admin to change the canonical badge metadata of a milestone tier."
+      ],
+      "discriminator": [
+        85,
+        170,
+        139,
+        114,
+        57,
+        233,
+        214,
+        82
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "level",
+          "type": "u8"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "createBadgeCollection",
+      "docs": [
+        "Instruction for the admin to create the Voix collection NFT that badges are verified into."
+      ],
+      "discriminator": [
+        244,
+        233,
+        26,
+        190,
+        133,
+        135,
+        53,
+        78
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "collectionMint",
+          "writable": true
+        },
+        {
+          "name": "collectionTokenAccount",
+          "writable": true
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true
+        },
+        {
+          "name": "masterEditionAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "rent"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "The Metaplex Token Metadata Program."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "setTierTransferable",
+      "docs": [
+        "Instruction for the admin to choose whether a tier's badges are soulbound or transferable."
+      ],
+      "discriminator": [
+        184,
+        33,
+        140,
+        212,
+        219,
+        56,
+        209,
+        214
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "level",
+          "type": "u8"
+        },
+        {
+          "name": "transferable",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "thawBadge",
+      "docs": [
+        "Instruction for the admin to unfreeze a soulbound badge (support cases)."
+      ],
+      "discriminator": [
+        26,
+        230,
+        50,
+        193,
+        207,
+        55,
+        57,
+        190
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "badge",
+          "accounts": [
+            {
+              "name": "mintAuthority"
+            },
+            {
+              "name": "mint"
+            },
+            {
+              "name": "tokenAccount",
+              "writable": true
+            },
+            {
+              "name": "masterEditionAccount"
+            },
+            {
+              "name": "tokenProgram"
+            },
+            {
+              "name": "tokenMetadataProgram",
+              "docs": [
+                "The Metaplex Token Metadata Program."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setBadgeMode",
+      "docs": [
+        "Instruction for the admin to switch between per-milestone NFTs and a single reputation badge."
+      ],
+      "discriminator": [
+        111,
+        168,
+        158,
+        78,
+        8,
+        27,
+        98,
+        38
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": {
+              "name": "badgeMode"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "upgradeReputationBadge",
+      "docs": [
+        "Instruction for a user to move their reputation badge up to a higher milestone tier."
+      ],
+      "discriminator": [
+        181,
+        34,
+        26,
+        44,
+        234,
+        173,
+        249,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry"
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "badgeMint"
+        },
+        {
+          "name": "badgeTokenAccount"
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true
+        },
+        {
+          "name": "milestoneRecord",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "The Metaplex Token Metadata Program."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneLevel",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateBadgeMetadata",
+      "docs": [
+        "Instruction for the admin to rewrite the name, symbol or URI of an already-minted badge."
+      ],
+      "discriminator": [
+        115,
+        4,
+        163,
+        51,
+        57,
+        64,
+        212,
+        229
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "badge",
+          "accounts": [
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "mintAuthority"
+            },
+            {
+              "name": "mint",
+              "docs": [
+                "is checked in `rewrite_badge_metadata_2022`; otherwise only used for seeds."
+              ],
+              "writable": true
+            },
+            {
+              "name": "metadataAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "docs": [
+                "Required for Token-2022 badges."
+              ],
+              "optional": true
+            },
+            {
+              "name": "systemProgram"
+            },
+            {
+              "name": "tokenMetadataProgram",
+              "docs": [
+                "The Metaplex Token Metadata Program."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "symbol",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "uri",
+          "type": {
+            "option": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "batchUpdateBadgeMetadata",
+      "docs": [
+        "Same as `update_badge_metadata`, for every badge metadata account in `remaining_accounts`."
+      ],
+      "discriminator": [
+        20,
+        215,
+        155,
+        88,
+        134,
+        77,
+        236,
+        86
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "tokenProgram",
+          "docs": [
+            "Required when a Token-2022 badge is passed."
+          ],
+          "optional": true
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "The Metaplex Token Metadata Program."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "name",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "symbol",
+          "type": {
+            "option": "string"
+          }
+        },
+        {
+          "name": "uri",
+          "type": {
+            "option": "string"
+          }
+        }
+      ]
+    },
+    {
+      "name": "revokeMilestone",
+      "docs": [
+        "Instruction for the admin to take back a milestone (e.g. after a karma clawback)."
+      ],
+      "discriminator": [
+        102,
+        162,
+        108,
+        157,
+        42,
+        0,
+        43,
+        175
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestone",
+          "accounts": [
+            {
+              "name": "milestoneRegistry"
+            },
+            {
+              "name": "user"
+            },
+            {
+              "name": "userAccount",
+              "writable": true
+            },
+            {
+              "name": "milestoneRecord",
+              "writable": true
+            },
+            {
+              "name": "mintAuthority"
+            },
+            {
+              "name": "mint",
+              "writable": true
+            },
+            {
+              "name": "tokenAccount",
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "masterEditionAccount"
+            },
+            {
+              "name": "metadataAccount",
+              "docs": [
+                "can't be burned drops back to a lower tier; checked in `rewrite_badge_metadata`."
+              ],
+              "writable": true,
+              "optional": true
+            },
+            {
+              "name": "tokenProgram",
+              "docs": [
+                "The badge's token program: Token for Metaplex badges, Token-2022 otherwise."
+              ]
+            },
+            {
+              "name": "tokenMetadataProgram",
+              "docs": [
+                "The Metaplex Token Metadata Program."
+              ]
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneLevel",
+          "type": "u8"
+        },
+        {
+          "name": "reasonCode",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setBadgeStandard",
+      "docs": [
+        "Instruction for the admin to choose between Metaplex NFTs and Token-2022 badges."
+      ],
+      "discriminator": [
+        152,
+        255,
+        11,
+        237,
+        153,
+        207,
+        215,
+        179
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "standard",
+          "type": {
+            "defined": {
+              "name": "badgeStandard"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "mintMilestoneBadge2022",
+      "docs": [
+        "Instruction for a user to mint a milestone badge as a Token-2022 mint with on-mint metadata."
+      ],
+      "discriminator": [
+        202,
+        50,
+        23,
+        201,
+        182,
+        193,
+        45,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "milestoneRegistry"
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "milestoneRecord",
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "account size) depends on whether the tier is soulbound."
+          ],
+          "writable": true
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": [
+        {
+          "name": "milestoneLevel",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createAchievement",
+      "docs": [
+        "Instruction for the admin to define a new achievement badge."
+      ],
+      "discriminator": [
+        41,
+        79,
+        246,
+        230,
+        218,
+        83,
+        35,
+        240
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "newAchievement",
+          "accounts": [
+            {
+              "name": "payer",
+              "writable": true,
+              "signer": true
+            },
+            {
+              "name": "achievement",
+              "writable": true
+            },
+            {
+              "name": "systemProgram"
+            }
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "achievementId",
+          "type": "u8"
+        },
+        {
+          "name": "criterion",
+          "type": {
+            "defined": {
+              "name": "achievementCriterion"
+            }
+          }
+        },
+        {
+          "name": "threshold",
+          "type": "u64"
+        },
+        {
+          "name": "name",
+          "type": "string"
+        },
+        {
+          "name": "symbol",
+          "type": "string"
+        },
+        {
+          "name": "uri",
+          "type": "string"
+        },
+        {
+          "name": "soulbound",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "claimAchievement",
+      "docs": [
+        "Instruction for a user to claim an achievement badge once its criterion is met."
+      ],
+      "discriminator": [
+        107,
+        181,
+        102,
+        247,
+        207,
+        212,
+        251,
+        24
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "achievement"
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "karmaOracle",
+          "docs": [
+            "The karma oracle, co-signing claims of `Attestation` achievements."
+          ],
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "mintAuthority"
+        },
+        {
+          "name": "mint",
+          "writable": true
+        },
+        {
+          "name": "tokenAccount",
+          "writable": true
+        },
+        {
+          "name": "metadataAccount",
+          "writable": true
+        },
+        {
+          "name": "masterEditionAccount",
+          "writable": true
+        },
+        {
+          "name": "collectionMint"
+        },
+        {
+          "name": "collectionMetadata",
+          "writable": true
+        },
+        {
+          "name": "collectionMasterEdition"
+        },
+        {
+          "name": "tokenProgram"
+        },
+        {
+          "name": "associatedTokenProgram"
+        },
+        {
+          "name": "systemProgram"
+        },
+        {
+          "name": "rent"
+        },
+        {
+          "name": "tokenMetadataProgram",
+          "docs": [
+            "The Metaplex Token Metadata Program."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "achievementId",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimKarma",
+      "docs": [
+        "Instruction for a user to redeem a karma voucher signed by the karma oracle."
+      ],
+      "discriminator": [
+        209,
+        64,
+        245,
+        252,
+        29,
+        12,
+        233,
+        14
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "instructionsSysvar",
+          "docs": [
+            "instruction that must come right before this one."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voucher",
+          "type": {
+            "defined": {
+              "name": "karmaVoucher"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "syncKarmaFromRoot",
+      "docs": [
+        "Instruction for a user to set their karma from a Merkle proof against the published root."
+      ],
+      "discriminator": [
+        131,
+        134,
+        14,
+        172,
+        103,
+        13,
+        146,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "karma",
+          "type": "u32"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "applyKarmaDelta",
+      "docs": [
+        "Instruction for the karma oracle to add a signed delta to a user's karma."
+      ],
+      "discriminator": [
+        98,
+        193,
+        162,
+        184,
+        65,
+        89,
+        220,
+        155
+      ],
+      "accounts": [
+        {
+          "name": "karmaOracle",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "userToUpdate",
+          "docs": [
+            "It's not a signer, just an address used to find the `user_account` PDA."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "delta",
+          "type": "i32"
+        },
+        {
+          "name": "reason",
+          "type": {
+            "defined": {
+              "name": "karmaReason"
+            }
+          }
+        },
+        {
+          "name": "expectedSeq",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "batchUpdateKarma",
+      "docs": [
+        "Same as `update_user_karma`, for every `UserAccount` passed in `remaining_accounts`."
+      ],
+      "discriminator": [
+        82,
+        184,
+        21,
+        58,
+        231,
+        37,
+        124,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "karmaOracle",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "entries",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "karmaEntry"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setKarmaLimits",
+      "docs": [
+        "Instruction for the admin to set the per-epoch karma limits and the mint cooldown."
+      ],
+      "discriminator": [
+        95,
+        51,
+        194,
+        126,
+        49,
+        126,
+        15,
+        85
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "limits",
+          "type": {
+            "defined": {
+              "name": "karmaLimits"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "setKarmaDecay",
+      "docs": [
+        "Instruction for the admin to set how karma decays over time."
+      ],
+      "discriminator": [
+        244,
+        96,
+        37,
+        177,
+        17,
+        189,
+        127,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "decay",
+          "type": {
+            "defined": {
+              "name": "karmaDecay"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "settleKarma",
+      "docs": [
+        "Permissionless crank that stores a user's decayed karma."
+      ],
+      "discriminator": [
+        61,
+        207,
+        5,
+        177,
+        48,
+        12,
+        104,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig"
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "docs": [
+            "Required once the user has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "user",
+          "docs": [
+            "It's not a signer, just an address used to find the `user_account` PDA."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeKarmaHistory",
+      "docs": [
+        "Instruction for a user to start keeping their recent karma changes on-chain."
+      ],
+      "discriminator": [
+        39,
+        88,
+        70,
+        17,
+        134,
+        248,
+        41,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "userAccount",
+          "writable": true
+        },
+        {
+          "name": "karmaHistory",
+          "writable": true
+        },
+        {
+          "name": "systemProgram"
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setKarmaGiftRules",
+      "docs": [
+        "Instruction for the admin to set the daily cap and minimum balance for karma gifts."
+      ],
+      "discriminator": [
+        145,
+        138,
+        138,
+        186,
+        190,
+        57,
+        157,
+        120
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rules",
+          "type": {
+            "defined": {
+              "name": "karmaGiftRules"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "giftKarma",
+      "docs": [
+        "Instruction for a user to give part of their karma to another user."
+      ],
+      "discriminator": [
+        199,
+        36,
+        145,
+        226,
+        218,
+        84,
+        240,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "giver",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        },
+        {
+          "name": "giverAccount",
+          "writable": true
+        },
+        {
+          "name": "receiverAccount",
+          "writable": true
+        },
+        {
+          "name": "giverKarmaHistory",
+          "docs": [
+            "Required once the giver has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiverKarmaHistory",
+          "docs": [
+            "Required once the receiver has created one (`UserAccount.has_karma_history`)."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "receiver",
+          "docs": [
+            "It's not a signer, just an address used to find the `receiver_account` PDA.",
+            "Must differ from the giver, or both accounts would be the same PDA."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "setTipKarmaRule",
+      "docs": [
+        "Instruction for the admin to set when tips earn the receiver karma."
+      ],
+      "discriminator": [
+        223,
+        0,
+        75,
+        8,
+        34,
+        119,
+        130,
+        162
+      ],
+      "accounts": [
+        {
+          "name": "admin",
+          "signer": true
+        },
+        {
+          "name": "globalConfig",
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "rule",
+          "type": {
+            "defined": {
+              "name": "tipKarmaRule"
+            }
+          }
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "achievement",
+      "discriminator": [
+        30,
+        253,
+        162,
+        142,
+        30,
+        160,
+        66,
+        62
+      ]
+    },
+    {
+      "name": "council",
+      "discriminator": [
+        28,
+        184,
+        149,
+        185,
+        217,
+        59,
+        252,
+        96
+      ]
+    },
+    {
+      "name": "councilProposal",
+      "discriminator": [
+        13,
+        42,
+        75,
+        75,
+        233,
+        185,
+        118,
+        188
+      ]
+    },
+    {
+      "name": "globalConfig",
+      "discriminator": [
+        149,
+        8,
+        156,
+        202,
+        160,
+        252,
+        176,
+        217
+      ]
+    },
+    {
+      "name": "karmaHistory",
+      "discriminator": [
+        192,
+        47,
+        110,
+        23,
+        164,
+        70,
+        240,
+        106
+      ]
+    },
+    {
+      "name": "merkleEpoch",
+      "discriminator": [
+        7,
+        239,
+        65,
+        4,
+        4,
+        49,
+        97,
+        159
+      ]
+    },
+    {
+      "name": "milestoneRecord",
+      "discriminator": [
+        251,
+        118,
+        88,
+        101,
+        199,
+        181,
+        221,
+        85
+      ]
+    },
+    {
+      "name": "milestoneRegistry",
+      "discriminator": [
+        254,
+        186,
+        19,
+        26,
+        72,
+        214,
+        62,
+        56
+      ]
+    },
+    {
+      "name": "tipKarmaCredit",
+      "discriminator": [
+        239,
+        114,
+        255,
+        45,
+        87,
+        231,
+        187,
+        1
+      ]
+    },
+    {
+      "name": "userAccount",
+      "discriminator": [
+        211,
+        33,
+        136,
+        16,
+        186,
+        110,
+        242,
+        127
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "accountMigrated",
+      "discriminator": [
+        153,
+        121,
+        252,
+        128,
+        30,
+        241,
+        166,
+        101
+      ]
+    },
+    {
+      "name": "achievementClaimed",
+      "discriminator": [
+        14,
+        49,
+        71,
+        199,
+        214,
+        248,
+        116,
+        232
+      ]
+    },
+    {
+      "name": "achievementCreated",
+      "discriminator": [
+        132,
+        184,
+        14,
+        25,
+        160,
+        22,
+        94,
+        45
+      ]
+    },
+    {
+      "name": "adminAccepted",
+      "discriminator": [
+        174,
+        12,
+        76,
+        139,
+        158,
+        99,
+        110,
+        254
+      ]
+    },
+    {
+      "name": "adminProposalCancelled",
+      "discriminator": [
+        158,
+        7,
+        69,
+        243,
+        15,
+        126,
+        0,
+        184
+      ]
+    },
+    {
+      "name": "adminProposed",
+      "discriminator": [
+        129,
+        249,
+        226,
+        227,
+        199,
+        82,
+        110,
+        243
+      ]
+    },
+    {
+      "name": "badgeCollectionCreated",
+      "discriminator": [
+        198,
+        195,
+        46,
+        187,
+        103,
+        218,
+        190,
+        97
+      ]
+    },
+    {
+      "name": "badgeMetadataUpdated",
+      "discriminator": [
+        103,
+        163,
+        239,
+        206,
+        205,
+        171,
+        138,
+        28
+      ]
+    },
+    {
+      "name": "badgeModeChanged",
+      "discriminator": [
+        26,
+        211,
+        8,
+        241,
+        30,
+        205,
+        250,
+        148
+      ]
+    },
+    {
+      "name": "badgeStandardChanged",
+      "discriminator": [
+        232,
+        186,
+        11,
+        77,
+        46,
+        82,
+        45,
+        106
+      ]
+    },
+    {
+      "name": "badgeThawed",
+      "discriminator": [
+        56,
+        236,
+        128,
+        234,
+        221,
+        245,
+        172,
+        68
+      ]
+    },
+    {
+      "name": "councilEnabled",
+      "discriminator": [
+        113,
+        36,
+        25,
+        203,
+        9,
+        237,
+        45,
+        49
+      ]
+    },
+    {
+      "name": "councilProposalApproved",
+      "discriminator": [
+        207,
+        137,
+        153,
+        236,
+        216,
+        255,
+        206,
+        246
+      ]
+    },
+    {
+      "name": "councilProposalCreated",
+      "discriminator": [
+        27,
+        191,
+        183,
+        38,
+        227,
+        17,
+        101,
+        173
+      ]
+    },
+    {
+      "name": "councilProposalExecuted",
+      "discriminator": [
+        27,
+        59,
+        241,
+        239,
+        134,
+        169,
+        243,
+        127
+      ]
+    },
+    {
+      "name": "karmaBatchUpdated",
+      "discriminator": [
+        179,
+        57,
+        61,
+        215,
+        169,
+        6,
+        247,
+        3
+      ]
+    },
+    {
+      "name": "karmaDecayChanged",
+      "discriminator": [
+        36,
+        100,
+        31,
+        145,
+        59,
+        164,
+        166,
+        150
+      ]
+    },
+    {
+      "name": "karmaGiftRulesChanged",
+      "discriminator": [
+        136,
+        204,
+        179,
+        99,
+        123,
+        4,
+        88,
+        189
+      ]
+    },
+    {
+      "name": "karmaGifted",
+      "discriminator": [
+        164,
+        35,
+        134,
+        122,
+        217,
+        74,
+        122,
+        198
+      ]
+    },
+    {
+      "name": "karmaHistoryInitialized",
+      "discriminator": [
+        46,
+        48,
+        160,
+        56,
+        152,
+        138,
+        92,
+        106
+      ]
+    },
+    {
+      "name": "karmaLimitsChanged",
+      "discriminator": [
+        172,
+        136,
+        14,
+        93,
+        7,
+        6,
+        173,
+        177
+      ]
+    },
+    {
+      "name": "karmaSettled",
+      "discriminator": [
+        198,
+        201,
+        55,
+        62,
+        70,
+        187,
+        93,
+        203
+      ]
+    },
+    {
+      "name": "karmaUpdated",
+      "discriminator": [
+        95,
+        138,
+        225,
+        85,
+        101,
+        112,
+        95,
+        121
+      ]
+    },
+    {
+      "name": "merkleRootSubmitted",
+      "discriminator": [
+        185,
+        69,
+        218,
+        17,
+        200,
+        145,
+        187,
+        77
+      ]
+    },
+    {
+      "name": "milestoneMetadataUpdated",
+      "discriminator": [
+        225,
+        144,
+        50,
+        67,
+        151,
+        24,
+        55,
+        230
+      ]
+    },
+    {
+      "name": "milestoneNftMinted",
+      "discriminator": [
+        78,
+        190,
+        147,
+        17,
+        196,
+        105,
+        166,
+        191
+      ]
+    },
+    {
+      "name": "milestoneRevoked",
+      "discriminator": [
+        85,
+        17,
+        229,
+        41,
+        120,
+        96,
+        165,
+        29
+      ]
+    },
+    {
+      "name": "milestoneTierChanged",
+      "discriminator": [
+        149,
+        44,
+        236,
+        130,
+        80,
+        149,
+        206,
+        117
+      ]
+    },
+    {
+      "name": "milestoneTransferableChanged",
+      "discriminator": [
+        49,
+        231,
+        211,
+        196,
+        140,
+        187,
+        9,
+        100
+      ]
+    },
+    {
+      "name": "pauseChanged",
+      "discriminator": [
+        238,
+        188,
+        213,
+        78,
+        134,
+        209,
+        178,
+        218
+      ]
+    },
+    {
+      "name": "reputationBadgeUpgraded",
+      "discriminator": [
+        66,
+        37,
+        63,
+        239,
+        145,
+        139,
+        192,
+        66
+      ]
+    },
+    {
+      "name": "roleGranted",
+      "discriminator": [
+        220,
+        183,
+        89,
+        228,
+        143,
+        63,
+        246,
+        58
+      ]
+    },
+    {
+      "name": "roleRevoked",
+      "discriminator": [
+        167,
+        183,
+        52,
+        229,
+        126,
+        206,
+        62,
+        61
+      ]
+    },
+    {
+      "name": "tipKarmaRuleChanged",
+      "discriminator": [
+        30,
+        58,
+        159,
+        33,
+        31,
+        67,
+        80,
+        55
+      ]
+    },
+    {
+      "name": "userInitialized",
+      "discriminator": [
+        66,
+        195,
+        5,
+        223,
+        42,
+        84,
+        135,
+        60
+      ]
+    },
+    {
+      "name": "userTipped",
+      "discriminator": [
+        113,
+        249,
+        216,
+        10,
+        22,
+        173,
+        217,
+        246
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "unauthorized",
+      "msg": "You are not authorized to perform this action."
+    },
+    {
+      "code": 6001,
+      "name": "invalidTipAmount",
+      "msg": "Tip amount must be greater than 0."
+    },
+    {
+      "code": 6002,
+      "name": "mathOverflow",
+      "msg": "Math overflow occurred."
+    },
+    {
+      "code": 6003,
+      "name": "insufficientKarma",
+      "msg": "You do not have enough karma for this milestone."
+    },
+    {
+      "code": 6004,
+      "name": "milestoneAlreadyMinted",
+      "msg": "You have already minted this milestone NFT."
+    },
+    {
+      "code": 6005,
+      "name": "invalidMilestoneLevel",
+      "msg": "The milestone level specified is invalid."
+    },
+    {
+      "code": 6006,
+      "name": "invalidEpoch",
+      "msg": "The provided Merkle epoch is not sequential."
+    },
+    {
+      "code": 6007,
+      "name": "noPendingAdmin",
+      "msg": "There is no pending admin proposal."
+    },
+    {
+      "code": 6008,
+      "name": "invalidAdminProposal",
+      "msg": "The proposed admin must be a new, non-default key."
+    },
+    {
+      "code": 6009,
+      "name": "missingKarmaOracleRole",
+      "msg": "The signer does not hold the karma oracle role."
+    },
+    {
+      "code": 6010,
+      "name": "missingMerklePublisherRole",
+      "msg": "The signer does not hold the Merkle publisher role."
+    },
+    {
+      "code": 6011,
+      "name": "missingUserSponsorRole",
+      "msg": "The signer does not hold the user sponsor role."
+    },
+    {
+      "code": 6012,
+      "name": "missingPauserRole",
+      "msg": "The signer does not hold the pauser role."
+    },
+    {
+      "code": 6013,
+      "name": "invalidRoleAuthority",
+      "msg": "A role cannot be granted to the default public key."
+    },
+    {
+      "code": 6014,
+      "name": "councilModeActive",
+      "msg": "Council mode is active; this change must go through a council proposal."
+    },
+    {
+      "code": 6015,
+      "name": "councilModeInactive",
+      "msg": "Council mode is not active."
+    },
+    {
+      "code": 6016,
+      "name": "invalidCouncilConfig",
+      "msg": "The council members or threshold are invalid."
+    },
+    {
+      "code": 6017,
+      "name": "notCouncilMember",
+      "msg": "The signer is not a council member."
+    },
+    {
+      "code": 6018,
+      "name": "proposalAlreadyApproved",
+      "msg": "This council member has already approved the proposal."
+    },
+    {
+      "code": 6019,
+      "name": "proposalAlreadyExecuted",
+      "msg": "This council proposal has already been executed."
+    },
+    {
+      "code": 6020,
+      "name": "proposalThresholdNotMet",
+      "msg": "The council proposal does not have enough approvals yet."
+    },
+    {
+      "code": 6021,
+      "name": "proposalExpired",
+      "msg": "This council proposal has expired."
+    },
+    {
+      "code": 6022,
+      "name": "proposalStale",
+      "msg": "This council proposal was made for an earlier council."
+    },
+    {
+      "code": 6023,
+      "name": "programPaused",
+      "msg": "This part of the program is currently paused."
+    },
+    {
+      "code": 6024,
+      "name": "invalidPauseFlags",
+      "msg": "The pause flags contain unknown bits."
+    },
+    {
+      "code": 6025,
+      "name": "accountAlreadyMigrated",
+      "msg": "This account already uses the current layout."
+    },
+    {
+      "code": 6026,
+      "name": "invalidAccountData",
+      "msg": "The account data does not match any known layout."
+    },
+    {
+      "code": 6027,
+      "name": "milestoneTierExists",
+      "msg": "A milestone tier with this level already exists."
+    },
+    {
+      "code": 6028,
+      "name": "invalidMilestoneFlag",
+      "msg": "The milestone flag index is out of range, already used, or can't be changed."
+    },
+    {
+      "code": 6029,
+      "name": "milestoneRegistryFull",
+      "msg": "The milestone registry has no room for another tier."
+    },
+    {
+      "code": 6030,
+      "name": "milestoneTierRetired",
+      "msg": "This milestone tier has been retired."
+    },
+    {
+      "code": 6031,
+      "name": "missingMilestoneRegistry",
+      "msg": "This action requires the milestone registry account."
+    },
+    {
+      "code": 6032,
+      "name": "badgeNameTooLong",
+      "msg": "The badge name is longer than 32 bytes."
+    },
+    {
+      "code": 6033,
+      "name": "badgeSymbolTooLong",
+      "msg": "The badge symbol is longer than 10 bytes."
+    },
+    {
+      "code": 6034,
+      "name": "badgeUriTooLong",
+      "msg": "The badge URI is longer than 200 bytes."
+    },
+    {
+      "code": 6035,
+      "name": "badgeCollectionExists",
+      "msg": "The badge collection has already been created."
+    },
+    {
+      "code": 6036,
+      "name": "badgeCollectionNotCreated",
+      "msg": "The badge collection has not been created yet."
+    },
+    {
+      "code": 6037,
+      "name": "badgeNotFrozen",
+      "msg": "This badge is not frozen."
+    },
+    {
+      "code": 6038,
+      "name": "wrongBadgeMode",
+      "msg": "This instruction is not available in the current badge mode."
+    },
+    {
+      "code": 6039,
+      "name": "reputationBadgeExists",
+      "msg": "The user already has a reputation badge; upgrade it instead."
+    },
+    {
+      "code": 6040,
+      "name": "reputationBadgeMismatch",
+      "msg": "The badge mint does not match the user's reputation badge."
+    },
+    {
+      "code": 6041,
+      "name": "invalidBadgeUpgrade",
+      "msg": "A reputation badge can only be upgraded to a higher milestone tier."
+    },
+    {
+      "code": 6042,
+      "name": "emptyMetadataUpdate",
+      "msg": "Provide at least one of name, symbol or URI to update."
+    },
+    {
+      "code": 6043,
+      "name": "invalidBadgeMetadata",
+      "msg": "The account is not the metadata of a Voix badge."
+    },
+    {
+      "code": 6044,
+      "name": "milestoneAlreadyRevoked",
+      "msg": "This milestone has already been revoked."
+    },
+    {
+      "code": 6045,
+      "name": "missingBadgeMetadata",
+      "msg": "The badge metadata account is needed to roll the reputation badge back."
+    },
+    {
+      "code": 6046,
+      "name": "wrongBadgeStandard",
+      "msg": "This instruction does not mint the configured badge standard."
+    },
+    {
+      "code": 6047,
+      "name": "invalidAchievementId",
+      "msg": "The achievement id is out of range (0-63)."
+    },
+    {
+      "code": 6048,
+      "name": "achievementAlreadyClaimed",
+      "msg": "The user has already claimed this achievement."
+    },
+    {
+      "code": 6049,
+      "name": "achievementCriterionNotMet",
+      "msg": "The user does not meet this achievement's criterion yet."
+    },
+    {
+      "code": 6050,
+      "name": "selfTipNotCounted",
+      "msg": "Self-tips don't count towards tips sent; leave out the tipper account."
+    },
+    {
+      "code": 6051,
+      "name": "invalidVoucherSignature",
+      "msg": "The voucher is not signed by the karma oracle."
+    },
+    {
+      "code": 6052,
+      "name": "invalidVoucher",
+      "msg": "The voucher is for a different user or program."
+    },
+    {
+      "code": 6053,
+      "name": "voucherExpired",
+      "msg": "The voucher has expired."
+    },
+    {
+      "code": 6054,
+      "name": "voucherAlreadyUsed",
+      "msg": "The voucher nonce has already been used."
+    },
+    {
+      "code": 6055,
+      "name": "invalidMerkleProof",
+      "msg": "The Merkle proof does not match the current root."
+    },
+    {
+      "code": 6056,
+      "name": "merkleProofTooLong",
+      "msg": "The Merkle proof is longer than allowed."
+    },
+    {
+      "code": 6057,
+      "name": "karmaAlreadySynced",
+      "msg": "The user's karma has already been synced for this epoch."
+    },
+    {
+      "code": 6058,
+      "name": "karmaSequenceMismatch",
+      "msg": "The karma sequence number doesn't match; re-read the account and retry."
+    },
+    {
+      "code": 6059,
+      "name": "karmaBatchLengthMismatch",
+      "msg": "The batch needs exactly one user account per entry."
+    },
+    {
+      "code": 6060,
+      "name": "invalidBatchUserAccount",
+      "msg": "The user account doesn't match its batch entry."
+    },
+    {
+      "code": 6061,
+      "name": "userKarmaLimitExceeded",
+      "msg": "This karma increase goes over the per-user limit for this epoch."
+    },
+    {
+      "code": 6062,
+      "name": "epochKarmaLimitExceeded",
+      "msg": "This karma increase goes over the total limit for this epoch."
+    },
+    {
+      "code": 6063,
+      "name": "mintCooldownActive",
+      "msg": "The user's karma went up too recently to mint a milestone badge."
+    },
+    {
+      "code": 6064,
+      "name": "invalidKarmaDecay",
+      "msg": "The karma decay half-life or rate must be greater than 0."
+    },
+    {
+      "code": 6065,
+      "name": "missingKarmaHistory",
+      "msg": "The user has a karma history account; pass it to record this change."
+    },
+    {
+      "code": 6066,
+      "name": "karmaGiftingDisabled",
+      "msg": "Karma gifting is turned off."
+    },
+    {
+      "code": 6067,
+      "name": "invalidGiftAmount",
+      "msg": "Gift amount must be greater than 0."
+    },
+    {
+      "code": 6068,
+      "name": "selfGiftNotAllowed",
+      "msg": "You can't gift karma to yourself."
+    },
+    {
+      "code": 6069,
+      "name": "giftDailyCapExceeded",
+      "msg": "This gift goes over your daily karma gifting cap."
+    },
+    {
+      "code": 6070,
+      "name": "giftBelowMinBalance",
+      "msg": "This gift would take your karma below the minimum balance."
+    },
+    {
+      "code": 6071,
+      "name": "missingTipKarmaCredit",
+      "msg": "This tip earns karma; pass the tip karma credit account."
+    },
+    {
+      "code": 6072,
+      "name": "treeUriTooLong",
+      "msg": "The Merkle tree URI is too long."
+    },
+    {
+      "code": 6073,
+      "name": "badgeNameEmpty",
+      "msg": "The badge name can't be empty."
+    },
+    {
+      "code": 6074,
+      "name": "badgeUriEmpty",
+      "msg": "The badge URI can't be empty."
+    },
+    {
+      "code": 6075,
+      "name": "councilAccountsMismatch",
+      "msg": "The accounts passed are not the ones the council proposal names."
+    },
+    {
+      "code": 6076,
+      "name": "reservedKarmaReason",
+      "msg": "Decay and gift karma changes are only recorded by the program."
+    }
+  ],
+  "types": [
+    {
+      "name": "accountMigrated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "account",
+            "type": "pubkey"
+          },
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "payer",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "achievement",
+      "docs": [
+        "An admin-defined badge that is earned by something other than a karma tier,",
+        "e.g. \"first tip sent\" or \"1-year member\".",
+        "PDA seeds: `[ACHIEVEMENT_SEED, achievement_id]`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `ACHIEVEMENT_VERSION`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "id",
+            "docs": [
+              "The achievement's id, also its bit in `UserAccount.achievements` (0-63)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "criterion",
+            "docs": [
+              "What the user must have done to claim it."
+            ],
+            "type": {
+              "defined": {
+                "name": "achievementCriterion"
+              }
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The value the criterion's counter must reach (unused for `Attestation`)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "Canonical badge metadata, used for every badge of this achievement."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "soulbound",
+            "docs": [
+              "Whether claimed badges are frozen in the user's wallet."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Zeroed space kept for future fields."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                31
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "achievementClaimed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "achievementId",
+            "type": "u8"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "achievementCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "achievementId",
+            "type": "u8"
+          },
+          {
+            "name": "criterion",
+            "type": {
+              "defined": {
+                "name": "achievementCriterion"
+              }
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u64"
+          },
+          {
+            "name": "soulbound",
+            "type": "bool"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "achievementCriterion",
+      "docs": [
+        "How an achievement is earned. The on-chain counters are compared against",
+        "`Achievement.threshold`; anything tracked off-chain (e.g. \"100 buzzes\")",
+        "uses `Attestation` instead."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "karma"
+          },
+          {
+            "name": "solReceived"
+          },
+          {
+            "name": "tipsSent"
+          },
+          {
+            "name": "accountAge"
+          },
+          {
+            "name": "attestation"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminAccepted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "newAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposalCancelled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "cancelledAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "adminProposed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "pendingAdmin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeCollectionCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "collectionMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeMetadataUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeMode",
+      "docs": [
+        "How milestone badges are issued. The first variant is the zero default."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "perMilestone"
+          },
+          {
+            "name": "reputation"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeModeChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousMode",
+            "type": {
+              "defined": {
+                "name": "badgeMode"
+              }
+            }
+          },
+          {
+            "name": "badgeMode",
+            "type": {
+              "defined": {
+                "name": "badgeMode"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeStandard",
+      "docs": [
+        "The token standard used for new milestone badges. The first variant is the zero default."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "metaplexNft"
+          },
+          {
+            "name": "token2022"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeStandardChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousStandard",
+            "type": {
+              "defined": {
+                "name": "badgeStandard"
+              }
+            }
+          },
+          {
+            "name": "badgeStandard",
+            "type": {
+              "defined": {
+                "name": "badgeStandard"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "badgeThawed",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "pubkey"
+          },
+          {
+            "name": "tokenAccount",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "council",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "docs": [
+              "The keys allowed to create and approve council proposals.",
+              "SET BY ADMIN using: `enable_council` instruction.",
+              "UPDATED BY COUNCIL using: `CouncilAction::UpdateCouncil`."
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "How many member approvals a proposal needs before it can be executed."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "proposalCount",
+            "docs": [
+              "A counter used to derive the next `CouncilProposal` PDA.",
+              "UPDATED BY PROGRAM during: `create_council_proposal` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "Bumped whenever the council is (re-)enabled or its members change,",
+              "so proposals made for an earlier council can't be approved or executed.",
+              "UPDATED BY PROGRAM during: `enable_council` and `CouncilAction::UpdateCouncil`."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilAction",
+      "docs": [
+        "The sensitive configuration changes that go through the council",
+        "while `GlobalConfig.council_enabled` is set.",
+        "",
+        "Council mode policy: the admin key alone can no longer change the",
+        "configuration, the badge definitions or anyone's karma or badges. Every",
+        "admin instruction that does fails with `CouncilModeActive`, and the changes",
+        "the council still needs are a variant here. The only admin instructions",
+        "left to the single key are one-time setup that can't be redone once done.",
+        "Pausing belongs to the pauser role and always stays a single signature.",
+        "",
+        "Actions on a badge, a milestone or a new achievement take its accounts in",
+        "the `remaining_accounts` of `execute_council_proposal`, in the order of the",
+        "target accounts the admin instruction nests. `batch_update_badge_metadata`",
+        "has no variant and stays unavailable in council mode: each badge takes its",
+        "own `UpdateBadgeMetadata` proposal."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "setAdmin",
+            "fields": [
+              {
+                "name": "newAdmin",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "grantRole",
+            "fields": [
+              {
+                "name": "role",
+                "type": {
+                  "defined": {
+                    "name": "role"
+                  }
+                }
+              },
+              {
+                "name": "authority",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "revokeRole",
+            "fields": [
+              {
+                "name": "role",
+                "type": {
+                  "defined": {
+                    "name": "role"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "updateCouncil",
+            "fields": [
+              {
+                "name": "members",
+                "type": {
+                  "vec": "pubkey"
+                }
+              },
+              {
+                "name": "threshold",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "disableCouncil"
+          },
+          {
+            "name": "addMilestoneTier",
+            "fields": [
+              {
+                "name": "tier",
+                "type": {
+                  "defined": {
+                    "name": "milestoneTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "updateMilestoneTier",
+            "fields": [
+              {
+                "name": "tier",
+                "type": {
+                  "defined": {
+                    "name": "milestoneTier"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "retireMilestoneTier",
+            "fields": [
+              {
+                "name": "level",
+                "type": "u8"
+              }
+            ]
+          },
+          {
+            "name": "setTierTransferable",
+            "fields": [
+              {
+                "name": "level",
+                "type": "u8"
+              },
+              {
+                "name": "transferable",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "updateMilestoneMetadata",
+            "fields": [
+              {
+                "name": "level",
+                "type": "u8"
+              },
+              {
+                "name": "name",
+                "type": "string"
+              },
+              {
+                "name": "symbol",
+                "type": "string"
+              },
+              {
+                "name": "uri",
+                "type": "string"
+              }
+            ]
+          },
+          {
+            "name": "setBadgeMode",
+            "fields": [
+              {
+                "name": "mode",
+                "type": {
+                  "defined": {
+                    "name": "badgeMode"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setBadgeStandard",
+            "fields": [
+              {
+                "name": "standard",
+                "type": {
+                  "defined": {
+                    "name": "badgeStandard"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setKarmaLimits",
+            "fields": [
+              {
+                "name": "limits",
+                "type": {
+                  "defined": {
+                    "name": "karmaLimits"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setKarmaDecay",
+            "fields": [
+              {
+                "name": "decay",
+                "type": {
+                  "defined": {
+                    "name": "karmaDecay"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setKarmaGiftRules",
+            "fields": [
+              {
+                "name": "rules",
+                "type": {
+                  "defined": {
+                    "name": "karmaGiftRules"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "setTipKarmaRule",
+            "fields": [
+              {
+                "name": "rule",
+                "type": {
+                  "defined": {
+                    "name": "tipKarmaRule"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "thawBadge",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "revokeMilestone",
+            "fields": [
+              {
+                "name": "user",
+                "type": "pubkey"
+              },
+              {
+                "name": "milestoneLevel",
+                "type": "u8"
+              },
+              {
+                "name": "reasonCode",
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "createAchievement",
+            "fields": [
+              {
+                "name": "achievementId",
+                "type": "u8"
+              },
+              {
+                "name": "criterion",
+                "type": {
+                  "defined": {
+                    "name": "achievementCriterion"
+                  }
+                }
+              },
+              {
+                "name": "threshold",
+                "type": "u64"
+              },
+              {
+                "name": "name",
+                "type": "string"
+              },
+              {
+                "name": "symbol",
+                "type": "string"
+              },
+              {
+                "name": "uri",
+                "type": "string"
+              },
+              {
+                "name": "soulbound",
+                "type": "bool"
+              }
+            ]
+          },
+          {
+            "name": "updateBadgeMetadata",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              },
+              {
+                "name": "name",
+                "type": {
+                  "option": "string"
+                }
+              },
+              {
+                "name": "symbol",
+                "type": {
+                  "option": "string"
+                }
+              },
+              {
+                "name": "uri",
+                "type": {
+                  "option": "string"
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilEnabled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "members",
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "threshold",
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "id",
+            "docs": [
+              "The sequential id this proposal was created with (also part of its PDA seeds)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "docs": [
+              "The council member who created the proposal."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "docs": [
+              "The configuration change to run once enough members approve."
+            ],
+            "type": {
+              "defined": {
+                "name": "councilAction"
+              }
+            }
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "The members who approved so far (the proposer approves on creation).",
+              "UPDATED BY PROGRAM during: `approve_council_proposal` instruction."
+            ],
+            "type": {
+              "vec": "pubkey"
+            }
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "Whether the action has already been applied.",
+              "SET BY PROGRAM during: `execute_council_proposal` instruction."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "createdAt",
+            "type": "i64"
+          },
+          {
+            "name": "generation",
+            "docs": [
+              "The `Council.generation` the proposal was made for."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "After this time the proposal can no longer be approved or executed",
+              "(`created_at + COUNCIL_PROPOSAL_LIFETIME_SECS`)."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilProposalApproved",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "member",
+            "type": "pubkey"
+          },
+          {
+            "name": "approvals",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilProposalCreated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "councilAction"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "councilProposalExecuted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "proposalId",
+            "type": "u64"
+          },
+          {
+            "name": "executor",
+            "type": "pubkey"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": {
+                "name": "councilAction"
+              }
+            }
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `CONFIG_VERSION`).",
+              "SET BY PROGRAM during: `initialize_config` / `migrate_config` instructions."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "docs": [
+              "The public key of your backend server.",
+              "This key manages the program configuration and hands out the roles below.",
+              "SET by: `initialize_config` instruction.",
+              "ROTATED using: `propose_admin` + `accept_admin` instructions."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "merkleRoot",
+            "docs": [
+              "The 32-byte Merkle root hash of all off-chain content (posts/comments).",
+              "It may also commit to karma leaves (see `KARMA_LEAF_PREFIX`),",
+              "which users redeem with `sync_karma_from_root`.",
+              "Every root is also kept in the `MerkleEpoch` account of its epoch.",
+              "UPDATED BY BACKEND using: `submit_merkle_root` instruction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "A simple counter to track the current Merkle root version.",
+              "UPDATED BY BACKEND using: `submit_merkle_root` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pendingAdmin",
+            "docs": [
+              "The admin key nominated by the current admin, waiting to accept the handover.",
+              "`None` when no handover is in progress.",
+              "SET BY ADMIN using: `propose_admin` / `cancel_admin_proposal` instructions.",
+              "CLEARED BY NEW ADMIN using: `accept_admin` instruction."
+            ],
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "karmaOracle",
+            "docs": [
+              "The key allowed to write user karma.",
+              "`Pubkey::default()` means the role is currently unassigned.",
+              "SET BY ADMIN using: `grant_role` / `revoke_role` instructions."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "merklePublisher",
+            "docs": [
+              "The key allowed to submit new Merkle roots.",
+              "SET BY ADMIN using: `grant_role` / `revoke_role` instructions."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "userSponsor",
+            "docs": [
+              "The key that sponsors (pays rent for) new `UserAccount`s.",
+              "SET BY ADMIN using: `grant_role` / `revoke_role` instructions."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "pauser",
+            "docs": [
+              "The key allowed to pause and unpause the program.",
+              "SET BY ADMIN using: `grant_role` / `revoke_role` instructions."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "councilEnabled",
+            "docs": [
+              "When set, admin-only configuration changes must go through the",
+              "`Council` PDA (M-of-N approvals) instead of the single admin key.",
+              "SET BY ADMIN using: `enable_council` instruction.",
+              "CLEARED BY COUNCIL using: `CouncilAction::DisableCouncil`."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "pausedFlags",
+            "docs": [
+              "A bit-flag of the instruction groups that are currently stopped.",
+              "(e.g., 1 = Tips, 2 = Mints, 4 = Onboarding, 8 = Karma writes)",
+              "UPDATED BY PAUSER using: `set_paused` instruction."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "badgeCollection",
+            "docs": [
+              "The mint of the Voix collection NFT that every milestone badge is verified into.",
+              "`Pubkey::default()` until the collection has been created.",
+              "SET BY ADMIN using: `create_badge_collection` instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "badgeMode",
+            "docs": [
+              "Whether users collect one NFT per milestone or a single upgradeable badge.",
+              "SET BY ADMIN using: `set_badge_mode` instruction."
+            ],
+            "type": {
+              "defined": {
+                "name": "badgeMode"
+              }
+            }
+          },
+          {
+            "name": "badgeStandard",
+            "docs": [
+              "Which token program new badges are minted with.",
+              "SET BY ADMIN using: `set_badge_standard` instruction."
+            ],
+            "type": {
+              "defined": {
+                "name": "badgeStandard"
+              }
+            }
+          },
+          {
+            "name": "karmaLimits",
+            "docs": [
+              "Guard rails on karma writes, so a stolen oracle key can't max out karma",
+              "and mint every badge at once. All zero (no limits) by default.",
+              "SET BY ADMIN using: `set_karma_limits` instruction."
+            ],
+            "type": {
+              "defined": {
+                "name": "karmaLimits"
+              }
+            }
+          },
+          {
+            "name": "karmaLimitEpoch",
+            "docs": [
+              "The Merkle epoch `epoch_karma_increase` is counted for.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "epochKarmaIncrease",
+            "docs": [
+              "Total karma added across all users during `karma_limit_epoch`.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "karmaDecay",
+            "docs": [
+              "How user karma decays over time. Applied lazily whenever karma is read or written.",
+              "SET BY ADMIN using: `set_karma_decay` instruction."
+            ],
+            "type": {
+              "defined": {
+                "name": "karmaDecay"
+              }
+            }
+          },
+          {
+            "name": "karmaGiftRules",
+            "docs": [
+              "Limits on users gifting karma to each other with `gift_karma`.",
+              "All zero (gifting off) by default.",
+              "SET BY ADMIN using: `set_karma_gift_rules` instruction."
+            ],
+            "type": {
+              "defined": {
+                "name": "karmaGiftRules"
+              }
+            }
+          },
+          {
+            "name": "tipKarmaRule",
+            "docs": [
+              "Karma credited to the receiver of a tip, so on-chain karma doesn't drift",
+              "from the backend's tip bonus. All zero (no credit) by default.",
+              "SET BY ADMIN using: `set_tip_karma_rule` instruction."
+            ],
+            "type": {
+              "defined": {
+                "name": "tipKarmaRule"
+              }
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Zeroed space kept for future fields, so new settings don't need a realloc.",
+              "A new field takes its bytes from here and must treat zero as its default."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                27
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaBatchUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "entries",
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "karmaEntry"
+                }
+              }
+            }
+          },
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaChange",
+      "docs": [
+        "One entry in a `KarmaHistory`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "oldKarma",
+            "docs": [
+              "Karma before the change, after any decay up to that moment."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "newKarma",
+            "type": "u32"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "`None` for absolute writes (`update_user_karma`, vouchers and Merkle syncs)."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "karmaReason"
+                }
+              }
+            }
+          },
+          {
+            "name": "signer",
+            "docs": [
+              "The key that authorized the change (the karma oracle, Merkle publisher or user).",
+              "`Pubkey::default()` for `Decay`."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaDecay",
+      "docs": [
+        "How karma decays between writes. The first variant is the zero default."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "none"
+          },
+          {
+            "name": "halfLife",
+            "fields": [
+              {
+                "name": "halfLifeSecs",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "linear",
+            "fields": [
+              {
+                "name": "pointsPerDay",
+                "type": "u32"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaDecayChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousDecay",
+            "type": {
+              "defined": {
+                "name": "karmaDecay"
+              }
+            }
+          },
+          {
+            "name": "karmaDecay",
+            "type": {
+              "defined": {
+                "name": "karmaDecay"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaEntry",
+      "docs": [
+        "One user's new karma in a `batch_update_karma` call. Entry `i` belongs to",
+        "the `UserAccount` passed at `remaining_accounts[i]`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "docs": [
+              "The wallet whose karma is set."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "karma",
+            "docs": [
+              "The user's new karma score."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaGiftRules",
+      "docs": [
+        "Limits on `gift_karma`. Days are UTC; a `daily_cap` of 0 turns gifting off."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "dailyCap",
+            "docs": [
+              "Most karma a single user can give away per day."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "minBalance",
+            "docs": [
+              "Karma the giver must still have after a gift."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaGiftRulesChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousRules",
+            "type": {
+              "defined": {
+                "name": "karmaGiftRules"
+              }
+            }
+          },
+          {
+            "name": "karmaGiftRules",
+            "type": {
+              "defined": {
+                "name": "karmaGiftRules"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaGifted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "giver",
+            "type": "pubkey"
+          },
+          {
+            "name": "receiver",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u32"
+          },
+          {
+            "name": "giverKarma",
+            "type": "u32"
+          },
+          {
+            "name": "receiverKarma",
+            "type": "u32"
+          },
+          {
+            "name": "giftedToday",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaHistory",
+      "docs": [
+        "The last `KARMA_HISTORY_LEN` karma changes of one user, so support can",
+        "answer \"why did my karma change?\" straight from the chain.",
+        "PDA seeds: `[KARMA_HISTORY_SEED, user]`. Optional; created with `initialize_karma_history`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `KARMA_HISTORY_VERSION`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "The wallet whose karma changes are kept."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nextIndex",
+            "docs": [
+              "Where the next change goes once `changes` is full; that slot holds the oldest change.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "changes",
+            "docs": [
+              "A ring buffer of changes. Until it is full, they are simply in order.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "karmaChange"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaHistoryInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaLimits",
+      "docs": [
+        "Limits on karma writes. Windows follow the Merkle `epoch`; a value of 0 turns that limit off."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxIncreasePerUser",
+            "docs": [
+              "Most karma a single user can gain per epoch."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxIncreasePerEpoch",
+            "docs": [
+              "Most karma all users together can gain per epoch."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "mintCooldownSecs",
+            "docs": [
+              "Seconds a user must wait after a karma increase before minting a milestone badge."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaLimitsChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousLimits",
+            "type": {
+              "defined": {
+                "name": "karmaLimits"
+              }
+            }
+          },
+          {
+            "name": "karmaLimits",
+            "type": {
+              "defined": {
+                "name": "karmaLimits"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaReason",
+      "docs": [
+        "Why a karma delta was applied. Carried in `KarmaUpdated` for the backend's audit trail.",
+        "`apply_karma_delta` rejects `Decay` and `Gift`, which only the program records."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "upvote"
+          },
+          {
+            "name": "downvote"
+          },
+          {
+            "name": "tip"
+          },
+          {
+            "name": "moderation"
+          },
+          {
+            "name": "decay"
+          },
+          {
+            "name": "gift"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaSettled",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldKarma",
+            "type": "u32"
+          },
+          {
+            "name": "newKarma",
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "oldKarma",
+            "type": "u32"
+          },
+          {
+            "name": "newKarma",
+            "type": "u32"
+          },
+          {
+            "name": "reason",
+            "docs": [
+              "`None` for absolute writes (`update_user_karma`, vouchers and Merkle syncs)."
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "karmaReason"
+                }
+              }
+            }
+          },
+          {
+            "name": "oracle",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "karmaVoucher",
+      "docs": [
+        "A karma value signed off-chain by the karma oracle and redeemed by the",
+        "user with `claim_karma`. Not an account: the signed message is",
+        "`KARMA_VOUCHER_PREFIX || borsh(KarmaVoucher)`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "programId",
+            "docs": [
+              "The Voix program the voucher is for, so devnet vouchers can't be used on mainnet."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "The wallet whose karma is set."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "karma",
+            "docs": [
+              "The user's new karma score."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Must be higher than `UserAccount.karma_nonce`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expiresAt",
+            "docs": [
+              "Unix timestamp after which the voucher can't be redeemed."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleEpoch",
+      "docs": [
+        "A Merkle root as it was published for one epoch. Never changed after",
+        "`submit_merkle_root` creates it, so proofs against old epochs stay checkable",
+        "after `GlobalConfig.merkle_root` has moved on.",
+        "PDA seeds: `[MERKLE_EPOCH_SEED, epoch (u64 LE)]`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `MERKLE_EPOCH_VERSION`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "leafCount",
+            "docs": [
+              "How many leaves the tree has, so clients can check a proof's length."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "publisher",
+            "docs": [
+              "The Merkle publisher that submitted the root."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "submittedSlot",
+            "type": "u64"
+          },
+          {
+            "name": "submittedAt",
+            "type": "i64"
+          },
+          {
+            "name": "treeUri",
+            "docs": [
+              "A content-addressed link to the full tree dump (e.g. `ipfs://<cid>`),",
+              "so anyone can rebuild proofs for this epoch."
+            ],
+            "type": {
+              "option": "string"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "merkleRootSubmitted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "publisher",
+            "type": "pubkey"
+          },
+          {
+            "name": "merkleRoot",
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "epoch",
+            "type": "u64"
+          },
+          {
+            "name": "leafCount",
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneMetadataUpdated",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "name",
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneNftMinted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "milestoneLevel",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneRecord",
+      "docs": [
+        "One record per user and milestone level, created when the badge is minted.",
+        "PDA seeds: `[MILESTONE_RECORD_SEED, user, milestone_level]`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `MILESTONE_RECORD_VERSION`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "user",
+            "docs": [
+              "The wallet that earned the milestone."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "level",
+            "docs": [
+              "The milestone level (matches `MilestoneTier.level`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The badge mint. In reputation mode this is the user's single badge,",
+              "so several records can point at the same mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "slot",
+            "docs": [
+              "The slot the milestone was reached in."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "karma",
+            "docs": [
+              "The user's karma at that moment."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "revoked",
+            "docs": [
+              "Set when moderation takes the milestone back.",
+              "SET BY ADMIN using: `revoke_milestone` instruction."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "reasonCode",
+            "docs": [
+              "The off-chain moderation reason code given at revocation (0 while not revoked)."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "revokedAt",
+            "docs": [
+              "When the milestone was revoked (0 while not revoked)."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "revocations",
+            "docs": [
+              "How many times this milestone was revoked. A revoked milestone can be",
+              "reached again; its badge is then minted at a fresh address (see `badge_seed`).",
+              "UPDATED BY ADMIN using: `revoke_milestone` instruction."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Zeroed space kept for future fields."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                52
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneRegistry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `MILESTONE_REGISTRY_VERSION`)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tiers",
+            "docs": [
+              "Every milestone tier ever added, including retired ones.",
+              "UPDATED BY ADMIN using: `add_milestone_tier` / `update_milestone_tier` /",
+              "`retire_milestone_tier` instructions (or the matching council actions)."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "milestoneTier"
+                }
+              }
+            }
+          },
+          {
+            "name": "transferableFlags",
+            "docs": [
+              "A bit-flag of the tiers (by `flag_index`) whose badges may be transferred.",
+              "Zero means every tier is soulbound: minting freezes the badge in the user's wallet.",
+              "UPDATED BY ADMIN using: `set_tier_transferable` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Zeroed space kept for future registry-wide settings."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                56
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "milestoneLevel",
+            "type": "u8"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "reasonCode",
+            "type": "u16"
+          },
+          {
+            "name": "burned",
+            "docs": [
+              "False when the program doesn't control the badge (e.g. a transferable badge):",
+              "it stays with its holder and only the `MilestoneRecord` is marked revoked."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "badgeLevel",
+            "docs": [
+              "The level the user's reputation badge shows afterwards (0 outside reputation mode)."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneTier",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "docs": [
+              "The level users pass to `mint_milestone_nft` (e.g., 1 = Bronze). Never 0."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "karmaRequired",
+            "docs": [
+              "The minimum `UserAccount.karma` needed to mint this tier."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "name",
+            "docs": [
+              "Canonical badge metadata for this tier. `mint_milestone_nft` always uses",
+              "these values; callers can't choose their own name, symbol or URI."
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "type": "string"
+          },
+          {
+            "name": "uri",
+            "type": "string"
+          },
+          {
+            "name": "flagIndex",
+            "docs": [
+              "The bit in `UserAccount.minted_milestones` that records this tier.",
+              "Never changes once the tier exists, and never reused by another tier."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "retired",
+            "docs": [
+              "Retired tiers stay in the registry (so their flag stays reserved)",
+              "but can no longer be minted."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneTierChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "karmaRequired",
+            "type": "u32"
+          },
+          {
+            "name": "flagIndex",
+            "type": "u8"
+          },
+          {
+            "name": "retired",
+            "type": "bool"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "milestoneTransferableChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "level",
+            "type": "u8"
+          },
+          {
+            "name": "transferable",
+            "type": "bool"
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pauseChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pausedFlags",
+            "type": "u8"
+          },
+          {
+            "name": "pauser",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "reputationBadgeUpgraded",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "nftMint",
+            "type": "pubkey"
+          },
+          {
+            "name": "previousLevel",
+            "type": "u8"
+          },
+          {
+            "name": "newLevel",
+            "type": "u8"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "role",
+      "docs": [
+        "The delegated authorities stored on `GlobalConfig`.",
+        "Each role gates only its own instructions, so losing one backend key",
+        "doesn't hand over the whole program."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "karmaOracle"
+          },
+          {
+            "name": "merklePublisher"
+          },
+          {
+            "name": "userSponsor"
+          },
+          {
+            "name": "pauser"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roleGranted",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "role"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "roleRevoked",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "role",
+            "type": {
+              "defined": {
+                "name": "role"
+              }
+            }
+          },
+          {
+            "name": "previousAuthority",
+            "type": "pubkey"
+          },
+          {
+            "name": "admin",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tipKarmaCredit",
+      "docs": [
+        "Counts the tips one tipper has had credited as karma to one receiver in the",
+        "current Merkle epoch, so `TipKarmaRule.max_credits_per_pair` can stop wash-tipping.",
+        "PDA seeds: `[TIP_KARMA_SEED, tipper, receiver]`. Created by the tipper's first credited tip."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tipper",
+            "type": "pubkey"
+          },
+          {
+            "name": "receiver",
+            "type": "pubkey"
+          },
+          {
+            "name": "epoch",
+            "docs": [
+              "The Merkle epoch `credits` is counted for.",
+              "UPDATED BY PROGRAM during: `tip_user_sol` / `tip_user_spl` instructions."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "credits",
+            "docs": [
+              "How many of this pair's tips have earned karma during `epoch`.",
+              "UPDATED BY PROGRAM during: `tip_user_sol` / `tip_user_spl` instructions."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tipKarmaRule",
+      "docs": [
+        "When a tip earns the receiver karma. Only tips from another registered user",
+        "(one that passes its `tipper_account`) count. A `karma_per_tip` of 0 turns",
+        "the rule off, and a minimum of 0 means that kind of tip never earns karma.",
+        "SPL tips only count in `spl_mint`, so nobody can farm karma with a worthless mint."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "karmaPerTip",
+            "docs": [
+              "Karma the receiver gets for each qualifying tip."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "maxCreditsPerPair",
+            "docs": [
+              "Most tips from one tipper to one receiver that earn karma per Merkle epoch.",
+              "0 means no cap."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "minSolAmount",
+            "docs": [
+              "Smallest SOL tip, in lamports, that earns karma."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minSplAmount",
+            "docs": [
+              "Smallest SPL tip, in `spl_mint` base units, that earns karma."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "splMint",
+            "docs": [
+              "The only mint whose tips earn karma.",
+              "`Pubkey::default()` means SPL tips never earn karma."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "tipKarmaRuleChanged",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "previousRule",
+            "type": {
+              "defined": {
+                "name": "tipKarmaRule"
+              }
+            }
+          },
+          {
+            "name": "tipKarmaRule",
+            "type": {
+              "defined": {
+                "name": "tipKarmaRule"
+              }
+            }
+          },
+          {
+            "name": "authority",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userAccount",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "The layout version of this account (see `USER_ACCOUNT_VERSION`).",
+              "SET BY PROGRAM during: `initialize_user` / `migrate_user_account` instructions."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "userPubkey",
+            "docs": [
+              "The user's wallet address.",
+              "SET ONCE by: `initialize_user` instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "karma",
+            "docs": [
+              "The user's karma score as of `last_karma_update`, before any decay since.",
+              "This value is read from your off-chain 'Karma' table (`Karma.points`).",
+              "UPDATED BY BACKEND using: `update_user_karma` instruction."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "mintedMilestones",
+            "docs": [
+              "A bit-flag to track which milestone NFTs the user has already claimed.",
+              "This prevents double-minting. Bit `n` belongs to the registry tier with `flag_index = n`.",
+              "(e.g., 1 = Bronze, 2 = Silver, 4 = Gold)",
+              "UPDATED BY PROGRAM during: `mint_milestone_nft` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalSolTipped",
+            "docs": [
+              "A simple counter for total SOL received from tips.",
+              "This is purely on-chain and not synced from your backend.",
+              "UPDATED BY PROGRAM during: `tip_user_sol` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "badgeMint",
+            "docs": [
+              "The mint of the user's single reputation badge (`BadgeMode::Reputation`).",
+              "`Pubkey::default()` until the badge has been minted.",
+              "SET BY PROGRAM during: `mint_milestone_nft` instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "badgeLevel",
+            "docs": [
+              "The milestone level currently shown by the reputation badge (0 = none).",
+              "UPDATED BY PROGRAM during: `mint_milestone_nft` / `upgrade_reputation_badge` instructions."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tipsSent",
+            "docs": [
+              "How many tips this user has sent (SOL or SPL).",
+              "Only counted when the tipper passes their own `UserAccount` to the tip instruction.",
+              "UPDATED BY PROGRAM during: `tip_user_sol` / `tip_user_spl` instructions."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "createdAt",
+            "docs": [
+              "When the account was created. 0 for accounts created before this field existed.",
+              "SET ONCE by: `initialize_user` instruction."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "achievements",
+            "docs": [
+              "A bit-flag of the achievements the user has claimed (bit `n` = achievement id `n`).",
+              "UPDATED BY PROGRAM during: `claim_achievement` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "karmaNonce",
+            "docs": [
+              "The nonce of the last karma voucher redeemed. Vouchers must carry a higher",
+              "nonce, so each one can be used once and older ones can't overwrite newer karma.",
+              "UPDATED BY PROGRAM during: `claim_karma` instruction and every karma oracle write."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "karmaSyncedEpoch",
+            "docs": [
+              "The Merkle epoch the user's karma was last synced from (0 = never).",
+              "Proofs must be for a later epoch, so an old proof can't roll karma back.",
+              "UPDATED BY PROGRAM during: `sync_karma_from_root` instruction and every karma oracle write."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "karmaSeq",
+            "docs": [
+              "How many karma deltas have been applied to this account. The karma oracle",
+              "passes the value it expects, so racing or retried deltas fail instead of",
+              "being lost or counted twice.",
+              "UPDATED BY PROGRAM during: `apply_karma_delta` instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "karmaLimitEpoch",
+            "docs": [
+              "The Merkle epoch `epoch_karma_increase` is counted for.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "epochKarmaIncrease",
+            "docs": [
+              "Karma gained during `karma_limit_epoch`, capped by `KarmaLimits.max_increase_per_user`.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "lastKarmaIncreaseAt",
+            "docs": [
+              "When the user's karma last went up. Starts the milestone mint cooldown.",
+              "UPDATED BY PROGRAM during: every karma write."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "lastKarmaUpdate",
+            "docs": [
+              "When `karma` was last written or settled; `GlobalConfig.karma_decay` runs from here.",
+              "0 until the first write, and karma doesn't decay before that.",
+              "UPDATED BY PROGRAM during: every karma write, badge mint and `settle_karma`."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "hasKarmaHistory",
+            "docs": [
+              "Whether the user has a `KarmaHistory` PDA. Karma writes must then pass it.",
+              "SET BY USER using: `initialize_karma_history` instruction."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "giftDay",
+            "docs": [
+              "The UTC day (days since the Unix epoch) `karma_gifted_today` is counted for.",
+              "UPDATED BY PROGRAM during: `gift_karma` instruction."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "karmaGiftedToday",
+            "docs": [
+              "Karma this user has given away during `gift_day`, capped by `KarmaGiftRules.daily_cap`.",
+              "UPDATED BY PROGRAM during: `gift_karma` instruction."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Zeroed space kept for future fields, so new counters don't need a realloc.",
+              "A new field takes its bytes from here and must treat zero as its default."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                14
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "userInitialized",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "type": "pubkey"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userTipped",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "tipper",
+            "type": "pubkey"
+          },
+          {
+            "name": "receiver",
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "mint",
+            "type": {
+              "option": "pubkey"
+            }
+          },
+          {
+            "name": "karmaCredited",
+            "docs": [
+              "Karma credited to the receiver under `GlobalConfig.tip_karma_rule` (0 if none)."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "timestamp",
+            "type": "i64"
+          }
+        ]
+      }
+    }
+  ],
+  "constants": [
+    {
+      "name": "achievementBadgeSeed",
+      "type": "bytes",
+      "value": "[97, 99, 104, 105, 101, 118, 101, 109, 101, 110, 116, 95, 98, 97, 100, 103, 101]"
+    },
+    {
+      "name": "achievementSeed",
+      "type": "bytes",
+      "value": "[97, 99, 104, 105, 101, 118, 101, 109, 101, 110, 116]"
+    },
+    {
+      "name": "achievementVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "badgeCollectionSeed",
+      "type": "bytes",
+      "value": "[98, 97, 100, 103, 101, 95, 99, 111, 108, 108, 101, 99, 116, 105, 111, 110]"
+    },
+    {
+      "name": "badgeSeed",
+      "type": "bytes",
+      "value": "[98, 97, 100, 103, 101]"
+    },
+    {
+      "name": "bronzeFlagIndex",
+      "type": "u8",
+      "value": "0"
+    },
+    {
+      "name": "bronzeKarmaReq",
+      "type": "u32",
+      "value": "1000"
+    },
+    {
+      "name": "configSeed",
+      "type": "bytes",
+      "value": "[99, 111, 110, 102, 105, 103]"
+    },
+    {
+      "name": "configVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "councilProposalSeed",
+      "type": "bytes",
+      "value": "[99, 111, 117, 110, 99, 105, 108, 95, 112, 114, 111, 112, 111, 115, 97, 108]"
+    },
+    {
+      "name": "councilSeed",
+      "type": "bytes",
+      "value": "[99, 111, 117, 110, 99, 105, 108]"
+    },
+    {
+      "name": "goldFlagIndex",
+      "type": "u8",
+      "value": "2"
+    },
+    {
+      "name": "goldKarmaReq",
+      "type": "u32",
+      "value": "10000"
+    },
+    {
+      "name": "karmaHistorySeed",
+      "type": "bytes",
+      "value": "[107, 97, 114, 109, 97, 95, 104, 105, 115, 116, 111, 114, 121]"
+    },
+    {
+      "name": "karmaHistoryVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "karmaLeafPrefix",
+      "type": "bytes",
+      "value": "[118, 111, 105, 120, 58, 107, 97, 114, 109, 97, 95, 108, 101, 97, 102]"
+    },
+    {
+      "name": "karmaVoucherPrefix",
+      "type": "bytes",
+      "value": "[118, 111, 105, 120, 58, 107, 97, 114, 109, 97, 95, 118, 111, 117, 99, 104, 101, 114]"
+    },
+    {
+      "name": "maxAchievementId",
+      "type": "u8",
+      "value": "63"
+    },
+    {
+      "name": "maxMerkleProofLen",
+      "type": "u8",
+      "value": "32"
+    },
+    {
+      "name": "maxMilestoneFlagIndex",
+      "type": "u8",
+      "value": "63"
+    },
+    {
+      "name": "merkleEpochSeed",
+      "type": "bytes",
+      "value": "[109, 101, 114, 107, 108, 101, 95, 101, 112, 111, 99, 104]"
+    },
+    {
+      "name": "merkleEpochVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "milestoneRecordSeed",
+      "type": "bytes",
+      "value": "[109, 105, 108, 101, 115, 116, 111, 110, 101, 95, 114, 101, 99, 111, 114, 100]"
+    },
+    {
+      "name": "milestoneRecordVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "milestoneRegistrySeed",
+      "type": "bytes",
+      "value": "[109, 105, 108, 101, 115, 116, 111, 110, 101, 95, 114, 101, 103, 105, 115, 116, 114, 121]"
+    },
+    {
+      "name": "milestoneRegistryVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "mintAuthoritySeed",
+      "type": "bytes",
+      "value": "[109, 105, 110, 116, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
+    },
+    {
+      "name": "pauseKarma",
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "pauseMints",
+      "type": "u8",
+      "value": "2"
+    },
+    {
+      "name": "pauseOnboarding",
+      "type": "u8",
+      "value": "4"
+    },
+    {
+      "name": "pauseTips",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "silverFlagIndex",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "silverKarmaReq",
+      "type": "u32",
+      "value": "5000"
+    },
+    {
+      "name": "tipKarmaSeed",
+      "type": "bytes",
+      "value": "[116, 105, 112, 95, 107, 97, 114, 109, 97]"
+    },
+    {
+      "name": "userAccountVersion",
+      "type": "u8",
+      "value": "1"
+    },
+    {
+      "name": "userSeed",
+      "type": "bytes",
+      "value": "[117, 115, 101, 114]"
+    }
+  ]
+};
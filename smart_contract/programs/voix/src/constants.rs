@@ -10,10 +10,130 @@ pub const USER_SEED: &[u8] = b"user";
 #[constant]
 pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
 
-// --- KARMA REQUIREMENTS ---
-// These are the karma point thresholds required to mint an NFT.
-// Your backend will read your 'Karma' table and sync these points.
-// The mint instruction will read the on-chain 'UserAccount.karma' to check.
+#[constant]
+pub const COUNCIL_SEED: &[u8] = b"council";
+
+#[constant]
+pub const COUNCIL_PROPOSAL_SEED: &[u8] = b"council_proposal";
+
+#[constant]
+pub const MILESTONE_REGISTRY_SEED: &[u8] = b"milestone_registry";
+
+#[constant]
+pub const BADGE_COLLECTION_SEED: &[u8] = b"badge_collection";
+
+// Badge mints and records are derived from `[seed, user, milestone_level]`,
+// so anyone can find a user's badge for a level without scanning wallets.
+#[constant]
+pub const BADGE_SEED: &[u8] = b"badge";
+
+#[constant]
+pub const MILESTONE_RECORD_SEED: &[u8] = b"milestone_record";
+
+#[constant]
+pub const ACHIEVEMENT_SEED: &[u8] = b"achievement";
+
+// Achievement badge mints are derived from `[seed, user, achievement_id]`.
+#[constant]
+pub const ACHIEVEMENT_BADGE_SEED: &[u8] = b"achievement_badge";
+
+#[constant]
+pub const KARMA_HISTORY_SEED: &[u8] = b"karma_history";
+
+// Published roots are kept as `[seed, epoch (u64 LE)]`, one account per epoch.
+#[constant]
+pub const MERKLE_EPOCH_SEED: &[u8] = b"merkle_epoch";
+
+// Tip karma credits are derived from `[seed, tipper, receiver]`.
+#[constant]
+pub const TIP_KARMA_SEED: &[u8] = b"tip_karma";
+
+// --- KARMA VOUCHERS ---
+// Prefix of the message the karma oracle signs for `claim_karma`:
+// `KARMA_VOUCHER_PREFIX || borsh(KarmaVoucher)`. Keeps a voucher signature
+// from being valid for any other message the oracle key signs.
+#[constant]
+pub const KARMA_VOUCHER_PREFIX: &[u8] = b"voix:karma_voucher";
+
+// --- KARMA MERKLE LEAVES ---
+// A published Merkle root may also commit to karma leaves
+// `sha256(KARMA_LEAF_PREFIX || user || karma (u32 LE) || epoch (u64 LE))`,
+// which users redeem with `sync_karma_from_root`. Parent nodes are
+// `sha256(min(a, b) || max(a, b))`, so a proof is just the sibling hashes.
+#[constant]
+pub const KARMA_LEAF_PREFIX: &[u8] = b"voix:karma_leaf";
+
+// Upper bound on the proof length, enough for 2^32 leaves.
+#[constant]
+pub const MAX_MERKLE_PROOF_LEN: u8 = 32;
+
+// Maximum length of `MerkleEpoch.tree_uri` (e.g. an `ipfs://` or `ar://` link).
+pub const MAX_TREE_URI_LEN: usize = 200;
+
+// --- KARMA HISTORY ---
+// How many karma changes a `KarmaHistory` keeps before overwriting the oldest.
+pub const KARMA_HISTORY_LEN: usize = 16;
+
+// --- KARMA DECAY ---
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+// --- COUNCIL LIMITS ---
+// Upper bound on the number of signers in the admin council.
+// This sizes the `Council` and `CouncilProposal` accounts.
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
+
+// How long a council proposal can be approved and executed after it's created.
+pub const COUNCIL_PROPOSAL_LIFETIME_SECS: i64 = 7 * 24 * 60 * 60;
+
+// --- ACCOUNT VERSIONS ---
+// The layout version written into 'GlobalConfig.version' / 'UserAccount.version'.
+// Accounts created before versioning are upgraded with the `migrate_*` instructions.
+
+#[constant]
+pub const CONFIG_VERSION: u8 = 1;
+
+#[constant]
+pub const USER_ACCOUNT_VERSION: u8 = 1;
+
+#[constant]
+pub const MILESTONE_REGISTRY_VERSION: u8 = 1;
+
+#[constant]
+pub const MILESTONE_RECORD_VERSION: u8 = 1;
+
+#[constant]
+pub const ACHIEVEMENT_VERSION: u8 = 1;
+
+#[constant]
+pub const KARMA_HISTORY_VERSION: u8 = 1;
+
+#[constant]
+pub const MERKLE_EPOCH_VERSION: u8 = 1;
+
+// --- PAUSE BIT-FLAGS ---
+// These are the bit-flags used in the 'GlobalConfig.paused_flags' u8 field.
+// Each flag stops one group of instructions, so e.g. NFT mints can be halted
+// during an incident while tips keep working.
+
+#[constant]
+pub const PAUSE_TIPS: u8 = 1;
+
+#[constant]
+pub const PAUSE_MINTS: u8 = 2;
+
+#[constant]
+pub const PAUSE_ONBOARDING: u8 = 4;
+
+#[constant]
+pub const PAUSE_KARMA: u8 = 8;
+
+// Mask of every known pause flag, used to reject unknown bits.
+pub const PAUSE_ALL: u8 = PAUSE_TIPS | PAUSE_MINTS | PAUSE_ONBOARDING | PAUSE_KARMA;
+
+// --- DEFAULT MILESTONE TIERS ---
+// These are the tiers `initialize_milestone_registry` seeds the on-chain
+// 'MilestoneRegistry' with. After that the admin edits tiers on-chain and
+// the mint instruction only reads the registry.
 
 #[constant]
 pub const BRONZE_KARMA_REQ: u32 = 1000;
@@ -24,15 +144,33 @@ pub const SILVER_KARMA_REQ: u32 = 5000;
 #[constant]
 pub const GOLD_KARMA_REQ: u32 = 10000;
 
-// --- MILESTONE BIT-FLAGS ---
-// These are the bit-flags used in the 'UserAccount.minted_milestones' u8 field
-// to track which NFTs have been claimed.
+// Bit positions in 'UserAccount.minted_milestones'. The bit for a tier is
+// `1 << flag_index`, so these match the old 1 / 2 / 4 flags.
 
 #[constant]
-pub const BRONZE_MILESTONE_FLAG: u8 = 1;
+pub const BRONZE_FLAG_INDEX: u8 = 0;
 
 #[constant]
-pub const SILVER_MILESTONE_FLAG: u8 = 2;
+pub const SILVER_FLAG_INDEX: u8 = 1;
 
 #[constant]
-pub const GOLD_MILESTONE_FLAG: u8 = 4;
+pub const GOLD_FLAG_INDEX: u8 = 2;
+
+// --- MILESTONE LIMITS ---
+// 'UserAccount.minted_milestones' is a u64, so flag indexes run from 0 to 63.
+
+#[constant]
+pub const MAX_MILESTONE_FLAG_INDEX: u8 = 63;
+
+// Upper bound on the number of tiers; this sizes the 'MilestoneRegistry' account.
+pub const MAX_MILESTONE_TIERS: usize = 16;
+
+// 'UserAccount.achievements' is a u64, so achievement ids run from 0 to 63.
+
+#[constant]
+pub const MAX_ACHIEVEMENT_ID: u8 = 63;
+
+// Maximum badge metadata lengths, matching the Token Metadata program limits.
+pub const MAX_BADGE_NAME_LEN: usize = 32;
+pub const MAX_BADGE_SYMBOL_LEN: usize = 10;
+pub const MAX_BADGE_URI_LEN: usize = 200;
@@ -22,4 +22,214 @@ pub enum VoixError {
 
     #[msg("The provided Merkle epoch is not sequential.")]
     InvalidEpoch,
-}
\ No newline at end of file
+
+    #[msg("There is no pending admin proposal.")]
+    NoPendingAdmin,
+
+    #[msg("The proposed admin must be a new, non-default key.")]
+    InvalidAdminProposal,
+
+    #[msg("The signer does not hold the karma oracle role.")]
+    MissingKarmaOracleRole,
+
+    #[msg("The signer does not hold the Merkle publisher role.")]
+    MissingMerklePublisherRole,
+
+    #[msg("The signer does not hold the user sponsor role.")]
+    MissingUserSponsorRole,
+
+    #[msg("The signer does not hold the pauser role.")]
+    MissingPauserRole,
+
+    #[msg("A role cannot be granted to the default public key.")]
+    InvalidRoleAuthority,
+
+    #[msg("Council mode is active; this change must go through a council proposal.")]
+    CouncilModeActive,
+
+    #[msg("Council mode is not active.")]
+    CouncilModeInactive,
+
+    #[msg("The council members or threshold are invalid.")]
+    InvalidCouncilConfig,
+
+    #[msg("The signer is not a council member.")]
+    NotCouncilMember,
+
+    #[msg("This council member has already approved the proposal.")]
+    ProposalAlreadyApproved,
+
+    #[msg("This council proposal has already been executed.")]
+    ProposalAlreadyExecuted,
+
+    #[msg("The council proposal does not have enough approvals yet.")]
+    ProposalThresholdNotMet,
+
+    #[msg("This council proposal has expired.")]
+    ProposalExpired,
+
+    #[msg("This council proposal was made for an earlier council.")]
+    ProposalStale,
+
+    #[msg("This part of the program is currently paused.")]
+    ProgramPaused,
+
+    #[msg("The pause flags contain unknown bits.")]
+    InvalidPauseFlags,
+
+    #[msg("This account already uses the current layout.")]
+    AccountAlreadyMigrated,
+
+    #[msg("The account data does not match any known layout.")]
+    InvalidAccountData,
+
+    #[msg("A milestone tier with this level already exists.")]
+    MilestoneTierExists,
+
+    #[msg("The milestone flag index is out of range, already used, or can't be changed.")]
+    InvalidMilestoneFlag,
+
+    #[msg("The milestone registry has no room for another tier.")]
+    MilestoneRegistryFull,
+
+    #[msg("This milestone tier has been retired.")]
+    MilestoneTierRetired,
+
+    #[msg("This action requires the milestone registry account.")]
+    MissingMilestoneRegistry,
+
+    #[msg("The badge name is longer than 32 bytes.")]
+    BadgeNameTooLong,
+
+    #[msg("The badge symbol is longer than 10 bytes.")]
+    BadgeSymbolTooLong,
+
+    #[msg("The badge URI is longer than 200 bytes.")]
+    BadgeUriTooLong,
+
+    #[msg("The badge collection has already been created.")]
+    BadgeCollectionExists,
+
+    #[msg("The badge collection has not been created yet.")]
+    BadgeCollectionNotCreated,
+
+    #[msg("This badge is not frozen.")]
+    BadgeNotFrozen,
+
+    #[msg("This instruction is not available in the current badge mode.")]
+    WrongBadgeMode,
+
+    #[msg("The user already has a reputation badge; upgrade it instead.")]
+    ReputationBadgeExists,
+
+    #[msg("The badge mint does not match the user's reputation badge.")]
+    ReputationBadgeMismatch,
+
+    #[msg("A reputation badge can only be upgraded to a higher milestone tier.")]
+    InvalidBadgeUpgrade,
+
+    #[msg("Provide at least one of name, symbol or URI to update.")]
+    EmptyMetadataUpdate,
+
+    #[msg("The account is not the metadata of a Voix badge.")]
+    InvalidBadgeMetadata,
+
+    #[msg("This milestone has already been revoked.")]
+    MilestoneAlreadyRevoked,
+
+    #[msg("The badge metadata account is needed to roll the reputation badge back.")]
+    MissingBadgeMetadata,
+
+    #[msg("This instruction does not mint the configured badge standard.")]
+    WrongBadgeStandard,
+
+    #[msg("The achievement id is out of range (0-63).")]
+    InvalidAchievementId,
+
+    #[msg("The user has already claimed this achievement.")]
+    AchievementAlreadyClaimed,
+
+    #[msg("The user does not meet this achievement's criterion yet.")]
+    AchievementCriterionNotMet,
+
+    #[msg("Self-tips don't count towards tips sent; leave out the tipper account.")]
+    SelfTipNotCounted,
+
+    #[msg("The voucher is not signed by the karma oracle.")]
+    InvalidVoucherSignature,
+
+    #[msg("The voucher is for a different user or program.")]
+    InvalidVoucher,
+
+    #[msg("The voucher has expired.")]
+    VoucherExpired,
+
+    #[msg("The voucher nonce has already been used.")]
+    VoucherAlreadyUsed,
+
+    #[msg("The Merkle proof does not match the current root.")]
+    InvalidMerkleProof,
+
+    #[msg("The Merkle proof is longer than allowed.")]
+    MerkleProofTooLong,
+
+    #[msg("The user's karma has already been synced for this epoch.")]
+    KarmaAlreadySynced,
+
+    #[msg("The karma sequence number doesn't match; re-read the account and retry.")]
+    KarmaSequenceMismatch,
+
+    #[msg("The batch needs exactly one user account per entry.")]
+    KarmaBatchLengthMismatch,
+
+    #[msg("The user account doesn't match its batch entry.")]
+    InvalidBatchUserAccount,
+
+    #[msg("This karma increase goes over the per-user limit for this epoch.")]
+    UserKarmaLimitExceeded,
+
+    #[msg("This karma increase goes over the total limit for this epoch.")]
+    EpochKarmaLimitExceeded,
+
+    #[msg("The user's karma went up too recently to mint a milestone badge.")]
+    MintCooldownActive,
+
+    #[msg("The karma decay half-life or rate must be greater than 0.")]
+    InvalidKarmaDecay,
+
+    #[msg("The user has a karma history account; pass it to record this change.")]
+    MissingKarmaHistory,
+
+    #[msg("Karma gifting is turned off.")]
+    KarmaGiftingDisabled,
+
+    #[msg("Gift amount must be greater than 0.")]
+    InvalidGiftAmount,
+
+    #[msg("You can't gift karma to yourself.")]
+    SelfGiftNotAllowed,
+
+    #[msg("This gift goes over your daily karma gifting cap.")]
+    GiftDailyCapExceeded,
+
+    #[msg("This gift would take your karma below the minimum balance.")]
+    GiftBelowMinBalance,
+
+    #[msg("This tip earns karma; pass the tip karma credit account.")]
+    MissingTipKarmaCredit,
+
+    #[msg("The Merkle tree URI is too long.")]
+    TreeUriTooLong,
+
+    #[msg("The badge name can't be empty.")]
+    BadgeNameEmpty,
+
+    #[msg("The badge URI can't be empty.")]
+    BadgeUriEmpty,
+
+    #[msg("The accounts passed are not the ones the council proposal names.")]
+    CouncilAccountsMismatch,
+
+    #[msg("Decay and gift karma changes are only recorded by the program.")]
+    ReservedKarmaReason,
+}
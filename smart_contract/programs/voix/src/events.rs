@@ -1,3 +1,7 @@
+use crate::state::{
+    AchievementCriterion, BadgeMode, BadgeStandard, CouncilAction, KarmaDecay, KarmaEntry,
+    KarmaGiftRules, KarmaLimits, KarmaReason, Role, TipKarmaRule,
+};
 use anchor_lang::prelude::*;
 
 #[event]
@@ -8,17 +12,28 @@ pub struct UserInitialized {
 
 #[event]
 pub struct MerkleRootSubmitted {
-    pub admin: Pubkey,
+    pub publisher: Pubkey,
     pub merkle_root: [u8; 32],
     pub epoch: u64,
+    pub leaf_count: u64,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct KarmaUpdated {
     pub user: Pubkey,
+    pub old_karma: u32,
     pub new_karma: u32,
-    pub admin: Pubkey,
+    /// `None` for absolute writes (`update_user_karma`, vouchers and Merkle syncs).
+    pub reason: Option<KarmaReason>,
+    pub oracle: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaBatchUpdated {
+    pub entries: Vec<KarmaEntry>,
+    pub oracle: Pubkey,
     pub timestamp: i64,
 }
 
@@ -28,6 +43,8 @@ pub struct UserTipped {
     pub receiver: Pubkey,
     pub amount: u64,
     pub mint: Option<Pubkey>,
+    /// Karma credited to the receiver under `GlobalConfig.tip_karma_rule` (0 if none).
+    pub karma_credited: u32,
     pub timestamp: i64,
 }
 
@@ -38,3 +55,256 @@ pub struct MilestoneNftMinted {
     pub milestone_level: u8,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub admin: Pubkey,
+    pub cancelled_admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub authority: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub previous_authority: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilEnabled {
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: CouncilAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalApproved {
+    pub proposal_id: u64,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CouncilProposalExecuted {
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action: CouncilAction,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseChanged {
+    pub previous_flags: u8,
+    pub paused_flags: u8,
+    pub pauser: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+    pub payer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneTierChanged {
+    pub level: u8,
+    pub karma_required: u32,
+    pub flag_index: u8,
+    pub retired: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneMetadataUpdated {
+    pub level: u8,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeCollectionCreated {
+    pub collection_mint: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneTransferableChanged {
+    pub level: u8,
+    pub transferable: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeThawed {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeModeChanged {
+    pub previous_mode: BadgeMode,
+    pub badge_mode: BadgeMode,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReputationBadgeUpgraded {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+    pub previous_level: u8,
+    pub new_level: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeMetadataUpdated {
+    pub mint: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MilestoneRevoked {
+    pub user: Pubkey,
+    pub milestone_level: u8,
+    pub nft_mint: Pubkey,
+    pub reason_code: u16,
+    /// False when the program doesn't control the badge (e.g. a transferable badge):
+    /// it stays with its holder and only the `MilestoneRecord` is marked revoked.
+    pub burned: bool,
+    /// The level the user's reputation badge shows afterwards (0 outside reputation mode).
+    pub badge_level: u8,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BadgeStandardChanged {
+    pub previous_standard: BadgeStandard,
+    pub badge_standard: BadgeStandard,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaLimitsChanged {
+    pub previous_limits: KarmaLimits,
+    pub karma_limits: KarmaLimits,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaDecayChanged {
+    pub previous_decay: KarmaDecay,
+    pub karma_decay: KarmaDecay,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaGiftRulesChanged {
+    pub previous_rules: KarmaGiftRules,
+    pub karma_gift_rules: KarmaGiftRules,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaGifted {
+    pub giver: Pubkey,
+    pub receiver: Pubkey,
+    pub amount: u32,
+    pub giver_karma: u32,
+    pub receiver_karma: u32,
+    pub gifted_today: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TipKarmaRuleChanged {
+    pub previous_rule: TipKarmaRule,
+    pub tip_karma_rule: TipKarmaRule,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaSettled {
+    pub user: Pubkey,
+    pub old_karma: u32,
+    pub new_karma: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct KarmaHistoryInitialized {
+    pub user: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AchievementCreated {
+    pub achievement_id: u8,
+    pub criterion: AchievementCriterion,
+    pub threshold: u64,
+    pub soulbound: bool,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AchievementClaimed {
+    pub user: Pubkey,
+    pub achievement_id: u8,
+    pub nft_mint: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,50 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::AdminAccepted;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The nominated admin. Must match `global_config.pending_admin`.
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// Completes the handover started by `propose_admin`.
+/// From now on every `has_one = admin` check follows the new key.
+pub fn accept_admin_handler(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // --- Security Check ---
+    let pending_admin = global_config
+        .pending_admin
+        .ok_or(VoixError::NoPendingAdmin)?;
+    require_keys_eq!(
+        pending_admin,
+        ctx.accounts.new_admin.key(),
+        VoixError::Unauthorized
+    );
+
+    // --- Update State ---
+    let previous_admin = global_config.admin;
+    global_config.admin = pending_admin;
+    global_config.pending_admin = None;
+
+    // --- Emit Event ---
+    emit!(AdminAccepted {
+        previous_admin,
+        new_admin: pending_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Admin handover complete. New admin: {}", pending_admin);
+    Ok(())
+}
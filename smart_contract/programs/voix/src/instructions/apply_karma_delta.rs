@@ -0,0 +1,119 @@
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_KARMA, USER_SEED};
+use crate::errors::VoixError;
+use crate::events::KarmaUpdated;
+use crate::state::{GlobalConfig, KarmaChange, KarmaHistory, KarmaReason, UserAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApplyKarmaDelta<'info> {
+    pub karma_oracle: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = karma_oracle @ VoixError::MissingKarmaOracleRole
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user_to_update.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user_to_update.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// CHECK: This is the pubkey of the user we are targeting.
+    /// It's not a signer, just an address used to find the `user_account` PDA.
+    pub user_to_update: UncheckedAccount<'info>,
+}
+
+/// This is a karma-oracle-only function to add `delta` to a user's karma.
+/// `expected_seq` must equal `UserAccount.karma_seq`, so two backend workers
+/// can't overwrite each other and a retried transaction can't apply twice.
+/// Karma stops at 0 rather than failing a downvote.
+pub fn handler(
+    ctx: Context<ApplyKarmaDelta>,
+    delta: i32,
+    reason: KarmaReason,
+    expected_seq: u64,
+) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+
+    // `Decay` and `Gift` entries must come from the program, not the oracle.
+    require!(
+        !matches!(reason, KarmaReason::Decay | KarmaReason::Gift),
+        VoixError::ReservedKarmaReason
+    );
+
+    let user_account = &mut ctx.accounts.user_account;
+    require!(
+        user_account.karma_seq == expected_seq,
+        VoixError::KarmaSequenceMismatch
+    );
+
+    // The delta applies to the decayed karma.
+    let timestamp = Clock::get()?.unix_timestamp;
+    let old_karma = ctx
+        .accounts
+        .global_config
+        .effective_karma(user_account, timestamp);
+    let new_karma = if delta >= 0 {
+        old_karma
+            .checked_add(delta.unsigned_abs())
+            .ok_or(VoixError::MathOverflow)?
+    } else {
+        old_karma.saturating_sub(delta.unsigned_abs())
+    };
+
+    ctx.accounts.global_config.track_karma_increase(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        new_karma,
+        timestamp,
+    )?;
+
+    // --- Update State ---
+    user_account.karma = new_karma;
+    user_account.karma_seq = expected_seq.checked_add(1).ok_or(VoixError::MathOverflow)?;
+    user_account.supersede_karma_claims(ctx.accounts.global_config.epoch)?;
+    KarmaHistory::record(
+        ctx.accounts.karma_history.as_deref_mut(),
+        user_account,
+        KarmaChange {
+            old_karma,
+            new_karma,
+            reason: Some(reason),
+            signer: ctx.accounts.karma_oracle.key(),
+            timestamp,
+        },
+    )?;
+
+    // --- Emit Event ---
+    emit!(KarmaUpdated {
+        user: user_account.user_pubkey,
+        old_karma,
+        new_karma,
+        reason: Some(reason),
+        oracle: ctx.accounts.karma_oracle.key(),
+        timestamp,
+    });
+
+    msg!(
+        "Karma delta {} ({:?}) applied for user: {}. New karma: {}",
+        delta,
+        reason,
+        user_account.user_pubkey,
+        new_karma
+    );
+    Ok(())
+}
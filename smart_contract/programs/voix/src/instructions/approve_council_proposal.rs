@@ -0,0 +1,69 @@
+use crate::constants::{COUNCIL_PROPOSAL_SEED, COUNCIL_SEED, CONFIG_SEED};
+use crate::errors::VoixError;
+use crate::events::CouncilProposalApproved;
+use crate::state::{Council, CouncilProposal, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ApproveCouncilProposal<'info> {
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = global_config.council_enabled @ VoixError::CouncilModeInactive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [COUNCIL_SEED],
+        bump,
+        constraint = council.is_member(&member.key()) @ VoixError::NotCouncilMember
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = !proposal.executed @ VoixError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+}
+
+/// Instruction for a council member to approve a pending proposal.
+pub fn approve_council_proposal_handler(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let proposal = &mut ctx.accounts.proposal;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // --- Security Check ---
+    proposal.require_current(&ctx.accounts.council, timestamp)?;
+    require!(
+        !proposal.approvals.contains(&member),
+        VoixError::ProposalAlreadyApproved
+    );
+
+    // --- Update State ---
+    // A member leaving the council frees a slot, so drop stale approvals before pushing.
+    let council = &ctx.accounts.council;
+    proposal.approvals.retain(|approver| council.is_member(approver));
+    proposal.approvals.push(member);
+
+    // --- Emit Event ---
+    emit!(CouncilProposalApproved {
+        proposal_id: proposal.id,
+        member,
+        approvals: proposal.approvals.len() as u8,
+        timestamp,
+    });
+
+    msg!(
+        "Council proposal {} approved by {} ({}/{})",
+        proposal.id,
+        member,
+        proposal.approvals.len(),
+        council.threshold
+    );
+    Ok(())
+}
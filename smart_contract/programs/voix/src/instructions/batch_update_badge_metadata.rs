@@ -0,0 +1,113 @@
+use crate::constants::{CONFIG_SEED, MINT_AUTHORITY_SEED};
+use crate::errors::VoixError;
+use crate::events::BadgeMetadataUpdated;
+use crate::state::GlobalConfig;
+use crate::utils::{rewrite_badge_metadata, rewrite_badge_metadata_2022};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+
+/// Remaining accounts: the (writable) metadata accounts of the badges to rewrite,
+/// i.e. the Metaplex metadata account or, for Token-2022 badges, the mint itself.
+#[derive(Accounts)]
+pub struct BatchUpdateBadgeMetadata<'info> {
+    // Pays for the extra rent when a Token-2022 badge's metadata grows.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Required when a Token-2022 badge is passed.
+    pub token_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// This is an admin-only function to apply the same name / symbol / URI change
+/// to every badge metadata account passed in `remaining_accounts`.
+/// Metaplex and Token-2022 badges can be mixed.
+pub fn batch_update_badge_metadata_handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BatchUpdateBadgeMetadata<'info>>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    // --- Security Check ---
+    require!(
+        name.is_some() || symbol.is_some() || uri.is_some(),
+        VoixError::EmptyMetadataUpdate
+    );
+
+    // --- Rewrite Metadata ---
+    // Each account is checked in `rewrite_badge_metadata(_2022)` (owner + update authority).
+    let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
+    let mint_authority = ctx.accounts.mint_authority.to_account_info();
+    let admin = ctx.accounts.admin.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let token_program = ctx
+        .accounts
+        .token_program
+        .as_ref()
+        .map(|p| p.to_account_info());
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    for metadata_account in ctx.remaining_accounts {
+        let (mint, name, symbol, uri) = if *metadata_account.owner == Token2022::id() {
+            let data = rewrite_badge_metadata_2022(
+                metadata_account,
+                &mint_authority,
+                &admin,
+                token_program
+                    .as_ref()
+                    .ok_or(VoixError::InvalidBadgeMetadata)?,
+                &system_program,
+                authority_seeds,
+                name.as_ref(),
+                symbol.as_ref(),
+                uri.as_ref(),
+            )?;
+            (metadata_account.key(), data.name, data.symbol, data.uri)
+        } else {
+            let (mint, data) = rewrite_badge_metadata(
+                metadata_account,
+                &mint_authority,
+                &ctx.accounts.token_metadata_program,
+                authority_seeds,
+                name.as_ref(),
+                symbol.as_ref(),
+                uri.as_ref(),
+            )?;
+            (mint, data.name, data.symbol, data.uri)
+        };
+
+        emit!(BadgeMetadataUpdated {
+            mint,
+            name,
+            symbol,
+            uri,
+            admin: ctx.accounts.admin.key(),
+            timestamp,
+        });
+    }
+
+    msg!(
+        "Badge metadata updated for {} badges",
+        ctx.remaining_accounts.len()
+    );
+    Ok(())
+}
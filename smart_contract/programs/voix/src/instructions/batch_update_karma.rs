@@ -0,0 +1,111 @@
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_KARMA, USER_SEED};
+use crate::errors::VoixError;
+use crate::events::KarmaBatchUpdated;
+use crate::state::{GlobalConfig, KarmaChange, KarmaEntry, KarmaHistory, UserAccount};
+use anchor_lang::prelude::*;
+
+/// Remaining accounts: the (writable) `UserAccount` PDA of each entry, in the same order,
+/// each followed by that user's `KarmaHistory` PDA if `has_karma_history` is set.
+#[derive(Accounts)]
+pub struct BatchUpdateKarma<'info> {
+    pub karma_oracle: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = karma_oracle @ VoixError::MissingKarmaOracleRole
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is a karma-oracle-only function to set the karma of many users in one
+/// transaction. A bad account fails the whole batch, and the error names it
+/// as `remaining_accounts[i]`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchUpdateKarma<'info>>,
+    entries: Vec<KarmaEntry>,
+) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+
+    // --- Update State ---
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mut remaining = ctx.remaining_accounts.iter().enumerate();
+    for entry in entries.iter() {
+        let (index, account_info) = remaining
+            .next()
+            .ok_or(VoixError::KarmaBatchLengthMismatch)?;
+        let bad_account =
+            |error: Error| error.with_account_name(format!("remaining_accounts[{index}]"));
+
+        let (expected_pda, _) =
+            Pubkey::find_program_address(&[USER_SEED, entry.user.as_ref()], ctx.program_id);
+        if account_info.key() != expected_pda || !account_info.is_writable {
+            return Err(bad_account(error!(VoixError::InvalidBatchUserAccount)));
+        }
+
+        // Checks the owner and the account discriminator.
+        let mut user_account =
+            Account::<UserAccount>::try_from(account_info).map_err(bad_account)?;
+        let mut karma_history = if user_account.has_karma_history {
+            let (index, history_info) = remaining
+                .next()
+                .ok_or(VoixError::KarmaBatchLengthMismatch)?;
+            let bad_history =
+                |error: Error| error.with_account_name(format!("remaining_accounts[{index}]"));
+
+            let (expected_pda, _) = Pubkey::find_program_address(
+                &[KARMA_HISTORY_SEED, entry.user.as_ref()],
+                ctx.program_id,
+            );
+            if history_info.key() != expected_pda || !history_info.is_writable {
+                return Err(bad_history(error!(VoixError::InvalidBatchUserAccount)));
+            }
+            Some(Account::<KarmaHistory>::try_from(history_info).map_err(bad_history)?)
+        } else {
+            None
+        };
+
+        ctx.accounts.global_config.track_karma_increase(
+            &mut user_account,
+            karma_history.as_deref_mut(),
+            entry.karma,
+            timestamp,
+        )?;
+        // Read after `track_karma_increase` settled any decay.
+        let old_karma = user_account.karma;
+        user_account.karma = entry.karma;
+        user_account.supersede_karma_claims(ctx.accounts.global_config.epoch)?;
+        KarmaHistory::record(
+            karma_history.as_deref_mut(),
+            &user_account,
+            KarmaChange {
+                old_karma,
+                new_karma: entry.karma,
+                reason: None,
+                signer: ctx.accounts.karma_oracle.key(),
+                timestamp,
+            },
+        )?;
+
+        if let Some(karma_history) = karma_history {
+            karma_history.exit(ctx.program_id)?;
+        }
+        user_account.exit(ctx.program_id)?;
+    }
+    require!(
+        remaining.next().is_none(),
+        VoixError::KarmaBatchLengthMismatch
+    );
+
+    // --- Emit Event ---
+    emit!(KarmaBatchUpdated {
+        entries: entries.clone(),
+        oracle: ctx.accounts.karma_oracle.key(),
+        timestamp,
+    });
+
+    msg!("Karma updated for {} users", entries.len());
+    Ok(())
+}
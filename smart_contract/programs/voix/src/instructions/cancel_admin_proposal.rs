@@ -0,0 +1,40 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::AdminProposalCancelled;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to withdraw a pending admin nomination.
+pub fn cancel_admin_proposal_handler(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // --- Security Check ---
+    let cancelled_admin = global_config
+        .pending_admin
+        .take()
+        .ok_or(VoixError::NoPendingAdmin)?;
+
+    // --- Emit Event ---
+    emit!(AdminProposalCancelled {
+        admin: global_config.admin,
+        cancelled_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Admin proposal for {} cancelled", cancelled_admin);
+    Ok(())
+}
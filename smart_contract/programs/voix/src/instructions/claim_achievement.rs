@@ -0,0 +1,216 @@
+use crate::constants::{
+    ACHIEVEMENT_BADGE_SEED, ACHIEVEMENT_SEED, CONFIG_SEED, KARMA_HISTORY_SEED, MINT_AUTHORITY_SEED,
+    PAUSE_KARMA, PAUSE_MINTS, USER_SEED,
+};
+use crate::errors::VoixError;
+use crate::events::AchievementClaimed;
+use crate::state::{Achievement, BadgeStandard, GlobalConfig, KarmaHistory, UserAccount};
+use crate::utils::{mint_badge_nft, BadgeNftAccounts};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+#[instruction(achievement_id: u8)]
+pub struct ClaimAchievement<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Circuit breakers: checked as constraints so they run before any account below is created.
+    // Minting saves the user's decayed karma, so it also stops while karma writes are paused.
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !global_config.is_paused(PAUSE_MINTS) @ VoixError::ProgramPaused,
+        constraint = !global_config.is_paused(PAUSE_KARMA) @ VoixError::ProgramPaused,
+        constraint = global_config.badge_standard == BadgeStandard::MetaplexNft @ VoixError::WrongBadgeStandard,
+        constraint = global_config.badge_collection != Pubkey::default() @ VoixError::BadgeCollectionNotCreated
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [ACHIEVEMENT_SEED, &[achievement_id]],
+        bump
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump,
+        constraint = user_account.achievements & achievement.flag() == 0
+            @ VoixError::AchievementAlreadyClaimed
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// The karma oracle, co-signing claims of `Attestation` achievements.
+    #[account(
+        constraint = karma_oracle.key() == global_config.karma_oracle
+            @ VoixError::MissingKarmaOracleRole
+    )]
+    pub karma_oracle: Option<Signer<'info>>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        seeds = [ACHIEVEMENT_BADGE_SEED, user.key().as_ref(), &[achievement_id]],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = user,
+        associated_token::mint = mint,
+        associated_token::authority = user,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: This account is created via CPI, so we use UncheckedAccount.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is created via CPI.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the collection mint stored in `GlobalConfig`.
+    #[account(address = global_config.badge_collection @ VoixError::BadgeCollectionNotCreated)]
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: The collection's metadata; its size counter is bumped by the verify CPI.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: The collection's master edition, checked by Token Metadata.
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Checks the achievement's criterion, mints its badge into the Voix collection
+/// (frozen for soulbound achievements) and sets the achievement's bit on the user's account.
+pub fn claim_achievement_handler(ctx: Context<ClaimAchievement>, achievement_id: u8) -> Result<()> {
+    // --- Security Check ---
+    // The pause flags and the "already claimed?" bit are checked as account constraints.
+    let clock = Clock::get()?;
+    ctx.accounts.global_config.settle_karma(
+        &mut ctx.accounts.user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        clock.unix_timestamp,
+    )?;
+    let achievement = &ctx.accounts.achievement;
+    achievement.require_met(
+        &ctx.accounts.user_account,
+        ctx.accounts.karma_oracle.is_some(),
+        clock.unix_timestamp,
+    )?;
+
+    let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
+
+    // Mint the badge into the Voix collection, frozen for soulbound achievements
+    // with the PDA as delegate, so `thaw_badge` still works for support cases.
+    mint_badge_nft(
+        &BadgeNftAccounts {
+            user: ctx.accounts.user.to_account_info(),
+            mint_authority: ctx.accounts.mint_authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            metadata_account: ctx.accounts.metadata_account.to_account_info(),
+            master_edition_account: ctx.accounts.master_edition_account.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        authority_seeds,
+        achievement.name.clone(),
+        achievement.symbol.clone(),
+        achievement.uri.clone(),
+        achievement.soulbound,
+    )?;
+
+    // --- Update State ---
+    ctx.accounts.user_account.achievements |= ctx.accounts.achievement.flag();
+
+    // --- Emit Event ---
+    emit!(AchievementClaimed {
+        user: ctx.accounts.user.key(),
+        achievement_id,
+        nft_mint: ctx.accounts.mint.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Achievement {} claimed by {}",
+        achievement_id,
+        ctx.accounts.user.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_KARMA, USER_SEED};
+use crate::errors::VoixError;
+use crate::events::KarmaUpdated;
+use crate::state::{GlobalConfig, KarmaChange, KarmaHistory, KarmaVoucher, UserAccount};
+use crate::utils::verify_ed25519_signature;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimKarma<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// CHECK: The instructions sysvar, used to read the ed25519 precompile
+    /// instruction that must come right before this one.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+/// Lets a user redeem a karma voucher signed off-chain by the karma oracle,
+/// so the backend doesn't need to send (and pay for) one transaction per user.
+/// `update_user_karma` stays available for admin overrides, and any karma oracle
+/// write moves `karma_nonce` on, so vouchers signed before it are rejected.
+pub fn handler(ctx: Context<ClaimKarma>, voucher: KarmaVoucher) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+
+    let user_account = &mut ctx.accounts.user_account;
+    require_keys_eq!(voucher.program_id, crate::ID, VoixError::InvalidVoucher);
+    require_keys_eq!(
+        voucher.user,
+        ctx.accounts.user.key(),
+        VoixError::InvalidVoucher
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    require!(voucher.expires_at >= timestamp, VoixError::VoucherExpired);
+    require!(
+        voucher.nonce > user_account.karma_nonce,
+        VoixError::VoucherAlreadyUsed
+    );
+
+    let karma_oracle = ctx.accounts.global_config.karma_oracle;
+    verify_ed25519_signature(
+        &ctx.accounts.instructions_sysvar,
+        &karma_oracle,
+        &voucher.message()?,
+    )?;
+
+    ctx.accounts.global_config.track_karma_increase(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        voucher.karma,
+        timestamp,
+    )?;
+
+    // --- Update State ---
+    let old_karma = user_account.karma;
+    user_account.karma = voucher.karma;
+    user_account.karma_nonce = voucher.nonce;
+    KarmaHistory::record(
+        ctx.accounts.karma_history.as_deref_mut(),
+        user_account,
+        KarmaChange {
+            old_karma,
+            new_karma: voucher.karma,
+            reason: None,
+            signer: karma_oracle,
+            timestamp,
+        },
+    )?;
+
+    // --- Emit Event ---
+    emit!(KarmaUpdated {
+        user: user_account.user_pubkey,
+        old_karma,
+        new_karma: voucher.karma,
+        reason: None,
+        oracle: karma_oracle,
+        timestamp,
+    });
+
+    msg!(
+        "Karma voucher {} claimed by {}. New karma: {}",
+        voucher.nonce,
+        user_account.user_pubkey,
+        voucher.karma
+    );
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use crate::constants::{ACHIEVEMENT_SEED, ACHIEVEMENT_VERSION, CONFIG_SEED, MAX_ACHIEVEMENT_ID};
+use crate::errors::VoixError;
+use crate::events::AchievementCreated;
+use crate::state::{Achievement, AchievementCriterion, GlobalConfig, MilestoneTier};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateAchievement<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub new_achievement: NewAchievementTarget<'info>,
+}
+
+/// The achievement `create_achievement` defines. `CouncilAction::CreateAchievement`
+/// reads the same accounts from the `remaining_accounts` of `execute_council_proposal`.
+#[derive(Accounts)]
+#[instruction(achievement_id: u8)]
+pub struct NewAchievementTarget<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Achievement::INIT_SPACE,
+        seeds = [ACHIEVEMENT_SEED, &[achievement_id]],
+        bump
+    )]
+    pub achievement: Box<Account<'info, Achievement>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> NewAchievementTarget<'info> {
+    /// Writes the new achievement. `authority` is the admin or the council, for the event.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        &mut self,
+        achievement_id: u8,
+        criterion: AchievementCriterion,
+        threshold: u64,
+        name: String,
+        symbol: String,
+        uri: String,
+        soulbound: bool,
+        authority: Pubkey,
+    ) -> Result<()> {
+        // --- Security Check ---
+        require!(
+            achievement_id <= MAX_ACHIEVEMENT_ID,
+            VoixError::InvalidAchievementId
+        );
+        MilestoneTier::validate_metadata(&name, &symbol, &uri)?;
+
+        // --- Update State ---
+        self.achievement.set_inner(Achievement {
+            version: ACHIEVEMENT_VERSION,
+            id: achievement_id,
+            criterion,
+            threshold,
+            name,
+            symbol,
+            uri,
+            soulbound,
+            reserved: [0; 31],
+        });
+
+        // --- Emit Event ---
+        emit!(AchievementCreated {
+            achievement_id,
+            criterion,
+            threshold,
+            soulbound,
+            admin: authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Achievement {} created", achievement_id);
+        Ok(())
+    }
+}
+
+/// This is an admin-only function to define a new achievement badge.
+/// Achievements can't be redefined, so a user's claim bit always means the same thing.
+/// `soulbound` badges are frozen in the user's wallet when claimed.
+#[allow(clippy::too_many_arguments)]
+pub fn create_achievement_handler(
+    ctx: Context<CreateAchievement>,
+    achievement_id: u8,
+    criterion: AchievementCriterion,
+    threshold: u64,
+    name: String,
+    symbol: String,
+    uri: String,
+    soulbound: bool,
+) -> Result<()> {
+    ctx.accounts.new_achievement.create(
+        achievement_id,
+        criterion,
+        threshold,
+        name,
+        symbol,
+        uri,
+        soulbound,
+        ctx.accounts.admin.key(),
+    )
+}
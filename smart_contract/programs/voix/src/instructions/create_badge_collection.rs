@@ -0,0 +1,192 @@
+use crate::constants::{BADGE_COLLECTION_SEED, CONFIG_SEED, MINT_AUTHORITY_SEED};
+use crate::errors::VoixError;
+use crate::events::BadgeCollectionCreated;
+use crate::state::{GlobalConfig, MilestoneTier};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, CreateMasterEditionV3,
+    CreateMetadataAccountsV3,
+};
+use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
+use mpl_token_metadata::types::{CollectionDetails, Creator, DataV2};
+
+#[derive(Accounts)]
+#[instruction(name: String, symbol: String, uri: String)]
+pub struct CreateBadgeCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // One-time setup (the collection can't be replaced), so it stays available
+    // in council mode (see the policy on `CouncilAction`).
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = global_config.badge_collection == Pubkey::default() @ VoixError::BadgeCollectionExists
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [BADGE_COLLECTION_SEED],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub collection_mint: Box<Account<'info, Mint>>,
+
+    // The collection NFT itself is held by the mint authority PDA, so no
+    // outside wallet ever owns it.
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = collection_mint,
+        associated_token::authority = mint_authority,
+    )]
+    pub collection_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: This account is created via CPI, so we use UncheckedAccount.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: This account is created via CPI.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// This is an admin-only function to create the sized Voix collection NFT.
+/// Every milestone badge minted afterwards is verified as a member of it.
+pub fn create_badge_collection_handler(
+    ctx: Context<CreateBadgeCollection>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    // --- Security Check ---
+    MilestoneTier::validate_metadata(&name, &symbol, &uri)?;
+
+    let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
+
+    // 1. Mint the single collection token to the PDA-owned ATA
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                to: ctx.accounts.collection_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        1,
+    )?;
+
+    // 2. Create the collection metadata, with the PDA as the verified creator
+    let data_v2 = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: Some(vec![Creator {
+            address: ctx.accounts.mint_authority.key(),
+            verified: true,
+            share: 100,
+        }]),
+        collection: None,
+        uses: None,
+    };
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMetadataAccountsV3 {
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                payer: ctx.accounts.admin.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        data_v2,
+        true,
+        true,
+        // A sized collection: Token Metadata keeps the member count for us.
+        Some(CollectionDetails::V1 { size: 0 }),
+    )?;
+
+    // 3. Create the master edition so the collection is a proper 1/1 NFT
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            CreateMasterEditionV3 {
+                edition: ctx.accounts.master_edition_account.to_account_info(),
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                payer: ctx.accounts.admin.to_account_info(),
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        Some(0),
+    )?;
+
+    // --- Update State ---
+    let collection_mint = ctx.accounts.collection_mint.key();
+    ctx.accounts.global_config.badge_collection = collection_mint;
+
+    // --- Emit Event ---
+    emit!(BadgeCollectionCreated {
+        collection_mint,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Badge collection created: {}", collection_mint);
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use crate::constants::{
+    COUNCIL_PROPOSAL_LIFETIME_SECS, COUNCIL_PROPOSAL_SEED, COUNCIL_SEED, CONFIG_SEED,
+    MAX_ACHIEVEMENT_ID, MAX_BADGE_NAME_LEN, MAX_BADGE_SYMBOL_LEN, MAX_BADGE_URI_LEN,
+};
+use crate::errors::VoixError;
+use crate::events::CouncilProposalCreated;
+use crate::state::{Council, CouncilAction, CouncilProposal, GlobalConfig, MilestoneTier};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(action: CouncilAction)]
+pub struct CreateCouncilProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = global_config.council_enabled @ VoixError::CouncilModeInactive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_SEED],
+        bump,
+        constraint = council.is_member(&proposer.key()) @ VoixError::NotCouncilMember
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + CouncilProposal::INIT_SPACE,
+        seeds = [COUNCIL_PROPOSAL_SEED, council.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction for a council member to propose a configuration change.
+/// The proposer's approval is recorded straight away.
+pub fn create_council_proposal_handler(
+    ctx: Context<CreateCouncilProposal>,
+    action: CouncilAction,
+) -> Result<()> {
+    // --- Security Check ---
+    // Reject actions that could never be executed safely.
+    match &action {
+        CouncilAction::SetAdmin { new_admin } => {
+            require!(
+                *new_admin != ctx.accounts.global_config.admin
+                    && *new_admin != Pubkey::default(),
+                VoixError::InvalidAdminProposal
+            );
+        }
+        CouncilAction::GrantRole { authority, .. } => {
+            require!(
+                *authority != Pubkey::default(),
+                VoixError::InvalidRoleAuthority
+            );
+        }
+        CouncilAction::UpdateCouncil { members, threshold } => {
+            Council::validate_members(members, *threshold)?;
+        }
+        CouncilAction::SetKarmaDecay { decay } => {
+            decay.validate()?;
+        }
+        // Checked here too, so the proposal account can always hold the strings.
+        CouncilAction::UpdateMilestoneMetadata {
+            name, symbol, uri, ..
+        } => {
+            MilestoneTier::validate_metadata(name, symbol, uri)?;
+        }
+        CouncilAction::CreateAchievement {
+            achievement_id,
+            name,
+            symbol,
+            uri,
+            ..
+        } => {
+            require!(
+                *achievement_id <= MAX_ACHIEVEMENT_ID,
+                VoixError::InvalidAchievementId
+            );
+            MilestoneTier::validate_metadata(name, symbol, uri)?;
+        }
+        // The merged metadata is validated again when executed.
+        CouncilAction::UpdateBadgeMetadata {
+            name, symbol, uri, ..
+        } => {
+            require!(
+                name.is_some() || symbol.is_some() || uri.is_some(),
+                VoixError::EmptyMetadataUpdate
+            );
+            require!(
+                name.as_ref().map_or(0, String::len) <= MAX_BADGE_NAME_LEN,
+                VoixError::BadgeNameTooLong
+            );
+            require!(
+                symbol.as_ref().map_or(0, String::len) <= MAX_BADGE_SYMBOL_LEN,
+                VoixError::BadgeSymbolTooLong
+            );
+            require!(
+                uri.as_ref().map_or(0, String::len) <= MAX_BADGE_URI_LEN,
+                VoixError::BadgeUriTooLong
+            );
+        }
+        // Milestone tiers are validated against the registry when executed.
+        CouncilAction::RevokeRole { .. }
+        | CouncilAction::DisableCouncil
+        | CouncilAction::AddMilestoneTier { .. }
+        | CouncilAction::UpdateMilestoneTier { .. }
+        | CouncilAction::RetireMilestoneTier { .. }
+        | CouncilAction::SetTierTransferable { .. }
+        | CouncilAction::SetBadgeMode { .. }
+        | CouncilAction::SetBadgeStandard { .. }
+        | CouncilAction::SetKarmaLimits { .. }
+        | CouncilAction::SetKarmaGiftRules { .. }
+        | CouncilAction::SetTipKarmaRule { .. }
+        | CouncilAction::ThawBadge { .. }
+        | CouncilAction::RevokeMilestone { .. } => {}
+    }
+
+    // --- Update State ---
+    let council = &mut ctx.accounts.council;
+    let proposal_id = council.proposal_count;
+    council.proposal_count = proposal_id
+        .checked_add(1)
+        .ok_or(VoixError::MathOverflow)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.proposal.set_inner(CouncilProposal {
+        id: proposal_id,
+        proposer: ctx.accounts.proposer.key(),
+        action: action.clone(),
+        approvals: vec![ctx.accounts.proposer.key()],
+        executed: false,
+        created_at: timestamp,
+        generation: council.generation,
+        expires_at: timestamp.saturating_add(COUNCIL_PROPOSAL_LIFETIME_SECS),
+    });
+
+    // --- Emit Event ---
+    emit!(CouncilProposalCreated {
+        proposal_id,
+        proposer: ctx.accounts.proposer.key(),
+        action,
+        timestamp,
+    });
+
+    msg!("Council proposal {} created", proposal_id);
+    Ok(())
+}
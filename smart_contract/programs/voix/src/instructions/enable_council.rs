@@ -0,0 +1,69 @@
+use crate::constants::{COUNCIL_SEED, CONFIG_SEED};
+use crate::errors::VoixError;
+use crate::events::CouncilEnabled;
+use crate::state::{Council, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(members: Vec<Pubkey>, threshold: u8)]
+pub struct EnableCouncil<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    // The council PDA survives `DisableCouncil`, so re-enabling reuses it.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + Council::INIT_SPACE,
+        seeds = [COUNCIL_SEED],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// This is an admin-only function to switch `GlobalConfig` into council mode.
+/// From now on admin changes and role changes need `threshold` member approvals.
+/// Each (re-)enable starts a new council generation, so proposals left over
+/// from an earlier council can't run.
+pub fn enable_council_handler(
+    ctx: Context<EnableCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    // --- Security Check ---
+    Council::validate_members(&members, threshold)?;
+
+    // --- Update State ---
+    // `proposal_count` is kept on purpose so old proposal PDAs are never reused.
+    let council = &mut ctx.accounts.council;
+    council.members = members.clone();
+    council.threshold = threshold;
+    council.next_generation()?;
+
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.council_enabled = true;
+    // A half-finished handover can't be completed in council mode anyway.
+    global_config.pending_admin = None;
+
+    // --- Emit Event ---
+    emit!(CouncilEnabled {
+        members,
+        threshold,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Council mode enabled with threshold {}", threshold);
+    Ok(())
+}
@@ -0,0 +1,347 @@
+use crate::constants::{COUNCIL_PROPOSAL_SEED, COUNCIL_SEED, CONFIG_SEED, MILESTONE_REGISTRY_SEED};
+use crate::errors::VoixError;
+use crate::events::{
+    AdminAccepted, BadgeModeChanged, BadgeStandardChanged, CouncilProposalExecuted,
+    KarmaDecayChanged, KarmaGiftRulesChanged, KarmaLimitsChanged, MilestoneMetadataUpdated,
+    MilestoneTierChanged, MilestoneTransferableChanged, RoleGranted, RoleRevoked,
+    TipKarmaRuleChanged,
+};
+use crate::instructions::{
+    BadgeMetadataTarget, NewAchievementTarget, RevokeMilestoneTarget, ThawBadgeTarget,
+};
+use crate::state::{Council, CouncilAction, CouncilProposal, GlobalConfig, MilestoneRegistry};
+use anchor_lang::prelude::*;
+use std::collections::BTreeSet;
+
+#[derive(Accounts)]
+pub struct ExecuteCouncilProposal<'info> {
+    /// Anyone may execute a proposal once it has enough approvals.
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = global_config.council_enabled @ VoixError::CouncilModeInactive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_SEED],
+        bump
+    )]
+    pub council: Account<'info, Council>,
+
+    #[account(
+        mut,
+        seeds = [COUNCIL_PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump,
+        constraint = !proposal.executed @ VoixError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, CouncilProposal>,
+
+    /// Only needed for the milestone tier and metadata actions.
+    #[account(
+        mut,
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Option<Box<Account<'info, MilestoneRegistry>>>,
+}
+
+/// Reads the accounts a badge action works on from `remaining_accounts`, checked the
+/// same way as in the admin instruction that nests `T`. `args` stands in for that
+/// instruction's arguments, for accounts seeded with `#[instruction(..)]` values.
+fn action_accounts<'info, T>(
+    program_id: &Pubkey,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    args: &[u8],
+) -> Result<(T, T::Bumps)>
+where
+    T: Accounts<'info, T::Bumps> + anchor_lang::Bumps,
+    T::Bumps: Default,
+{
+    let mut accounts = remaining_accounts;
+    let mut bumps = T::Bumps::default();
+    let target = T::try_accounts(
+        program_id,
+        &mut accounts,
+        args,
+        &mut bumps,
+        &mut BTreeSet::new(),
+    )?;
+    Ok((target, bumps))
+}
+
+/// Applies a council proposal that reached the approval threshold.
+pub fn execute_council_proposal_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExecuteCouncilProposal<'info>>,
+) -> Result<()> {
+    let council = &mut ctx.accounts.council;
+    let proposal = &mut ctx.accounts.proposal;
+    let global_config = &mut ctx.accounts.global_config;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // --- Security Check ---
+    proposal.require_current(council, timestamp)?;
+    require!(
+        proposal.valid_approvals(council) >= council.threshold as usize,
+        VoixError::ProposalThresholdNotMet
+    );
+
+    // --- Update State ---
+    match proposal.action.clone() {
+        CouncilAction::SetAdmin { new_admin } => {
+            let previous_admin = global_config.admin;
+            global_config.admin = new_admin;
+            global_config.pending_admin = None;
+            emit!(AdminAccepted {
+                previous_admin,
+                new_admin,
+                timestamp,
+            });
+        }
+        CouncilAction::GrantRole { role, authority } => {
+            global_config.set_role(role, authority);
+            emit!(RoleGranted {
+                role,
+                authority,
+                admin: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::RevokeRole { role } => {
+            let previous_authority = global_config.role_authority(role);
+            global_config.set_role(role, Pubkey::default());
+            emit!(RoleRevoked {
+                role,
+                previous_authority,
+                admin: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::UpdateCouncil { members, threshold } => {
+            council.members = members;
+            council.threshold = threshold;
+            council.next_generation()?;
+        }
+        CouncilAction::DisableCouncil => {
+            global_config.council_enabled = false;
+        }
+        CouncilAction::AddMilestoneTier { tier } | CouncilAction::UpdateMilestoneTier { tier } => {
+            let registry = ctx
+                .accounts
+                .milestone_registry
+                .as_mut()
+                .ok_or(VoixError::MissingMilestoneRegistry)?;
+            let event = MilestoneTierChanged {
+                level: tier.level,
+                karma_required: tier.karma_required,
+                flag_index: tier.flag_index,
+                retired: tier.retired,
+                authority: council.key(),
+                timestamp,
+            };
+            if matches!(proposal.action, CouncilAction::AddMilestoneTier { .. }) {
+                registry.add_tier(tier)?;
+            } else {
+                registry.update_tier(tier)?;
+            }
+            emit!(event);
+        }
+        CouncilAction::RetireMilestoneTier { level } => {
+            let registry = ctx
+                .accounts
+                .milestone_registry
+                .as_mut()
+                .ok_or(VoixError::MissingMilestoneRegistry)?;
+            let tier = registry.retire_tier(level)?;
+            emit!(MilestoneTierChanged {
+                level,
+                karma_required: tier.karma_required,
+                flag_index: tier.flag_index,
+                retired: true,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetTierTransferable {
+            level,
+            transferable,
+        } => {
+            let registry = ctx
+                .accounts
+                .milestone_registry
+                .as_mut()
+                .ok_or(VoixError::MissingMilestoneRegistry)?;
+            registry.set_transferable(level, transferable)?;
+            emit!(MilestoneTransferableChanged {
+                level,
+                transferable,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::UpdateMilestoneMetadata {
+            level,
+            name,
+            symbol,
+            uri,
+        } => {
+            let registry = ctx
+                .accounts
+                .milestone_registry
+                .as_mut()
+                .ok_or(VoixError::MissingMilestoneRegistry)?;
+            registry.set_metadata(level, name.clone(), symbol.clone(), uri.clone())?;
+            emit!(MilestoneMetadataUpdated {
+                level,
+                name,
+                symbol,
+                uri,
+                admin: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetBadgeMode { mode } => {
+            let previous_mode = global_config.badge_mode;
+            global_config.badge_mode = mode;
+            emit!(BadgeModeChanged {
+                previous_mode,
+                badge_mode: mode,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetBadgeStandard { standard } => {
+            let previous_standard = global_config.badge_standard;
+            global_config.badge_standard = standard;
+            emit!(BadgeStandardChanged {
+                previous_standard,
+                badge_standard: standard,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetKarmaLimits { limits } => {
+            let previous_limits = global_config.karma_limits;
+            global_config.karma_limits = limits;
+            emit!(KarmaLimitsChanged {
+                previous_limits,
+                karma_limits: limits,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetKarmaDecay { decay } => {
+            let previous_decay = global_config.karma_decay;
+            global_config.karma_decay = decay;
+            emit!(KarmaDecayChanged {
+                previous_decay,
+                karma_decay: decay,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetKarmaGiftRules { rules } => {
+            let previous_rules = global_config.karma_gift_rules;
+            global_config.karma_gift_rules = rules;
+            emit!(KarmaGiftRulesChanged {
+                previous_rules,
+                karma_gift_rules: rules,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::SetTipKarmaRule { rule } => {
+            let previous_rule = global_config.tip_karma_rule;
+            global_config.tip_karma_rule = rule;
+            emit!(TipKarmaRuleChanged {
+                previous_rule,
+                tip_karma_rule: rule,
+                authority: council.key(),
+                timestamp,
+            });
+        }
+        CouncilAction::ThawBadge { mint } => {
+            let (badge, bumps) =
+                action_accounts::<ThawBadgeTarget>(ctx.program_id, ctx.remaining_accounts, &[])?;
+            require_keys_eq!(badge.mint.key(), mint, VoixError::CouncilAccountsMismatch);
+            badge.thaw(&bumps, council.key())?;
+            badge.exit(ctx.program_id)?;
+        }
+        CouncilAction::RevokeMilestone {
+            user,
+            milestone_level,
+            reason_code,
+        } => {
+            let (mut milestone, bumps) = action_accounts::<RevokeMilestoneTarget>(
+                ctx.program_id,
+                ctx.remaining_accounts,
+                &[milestone_level],
+            )?;
+            require_keys_eq!(
+                milestone.user.key(),
+                user,
+                VoixError::CouncilAccountsMismatch
+            );
+            milestone.revoke(&bumps, milestone_level, reason_code, council.key())?;
+            milestone.exit(ctx.program_id)?;
+        }
+        CouncilAction::CreateAchievement {
+            achievement_id,
+            criterion,
+            threshold,
+            name,
+            symbol,
+            uri,
+            soulbound,
+        } => {
+            // The achievement PDA is seeded with the id, so the accounts can't name another one.
+            let (mut new_achievement, _) = action_accounts::<NewAchievementTarget>(
+                ctx.program_id,
+                ctx.remaining_accounts,
+                &[achievement_id],
+            )?;
+            new_achievement.create(
+                achievement_id,
+                criterion,
+                threshold,
+                name,
+                symbol,
+                uri,
+                soulbound,
+                council.key(),
+            )?;
+            new_achievement.exit(ctx.program_id)?;
+        }
+        CouncilAction::UpdateBadgeMetadata {
+            mint,
+            name,
+            symbol,
+            uri,
+        } => {
+            let (badge, bumps) = action_accounts::<BadgeMetadataTarget>(
+                ctx.program_id,
+                ctx.remaining_accounts,
+                &[],
+            )?;
+            require_keys_eq!(badge.mint.key(), mint, VoixError::CouncilAccountsMismatch);
+            badge.rewrite(&bumps, name, symbol, uri, council.key())?;
+            badge.exit(ctx.program_id)?;
+        }
+    }
+    proposal.executed = true;
+
+    // --- Emit Event ---
+    emit!(CouncilProposalExecuted {
+        proposal_id: proposal.id,
+        executor: ctx.accounts.executor.key(),
+        action: proposal.action.clone(),
+        timestamp,
+    });
+
+    msg!("Council proposal {} executed", proposal.id);
+    Ok(())
+}
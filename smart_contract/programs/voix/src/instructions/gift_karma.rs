@@ -0,0 +1,156 @@
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_KARMA, SECONDS_PER_DAY, USER_SEED};
+use crate::errors::VoixError;
+use crate::events::KarmaGifted;
+use crate::state::{GlobalConfig, KarmaChange, KarmaHistory, KarmaReason, UserAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GiftKarma<'info> {
+    pub giver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, giver.key().as_ref()],
+        bump
+    )]
+    pub giver_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, receiver.key().as_ref()],
+        bump
+    )]
+    pub receiver_account: Account<'info, UserAccount>,
+
+    /// Required once the giver has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, giver.key().as_ref()],
+        bump
+    )]
+    pub giver_karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// Required once the receiver has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, receiver.key().as_ref()],
+        bump
+    )]
+    pub receiver_karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// CHECK: This is the pubkey of the user receiving the karma.
+    /// It's not a signer, just an address used to find the `receiver_account` PDA.
+    /// Must differ from the giver, or both accounts would be the same PDA.
+    #[account(constraint = receiver.key() != giver.key() @ VoixError::SelfGiftNotAllowed)]
+    pub receiver: UncheckedAccount<'info>,
+}
+
+/// Lets a user move `amount` of their own karma to another user (a "super upvote").
+/// Limited by `GlobalConfig.karma_gift_rules`, and the receiver's side counts
+/// as a karma increase against `karma_limits`.
+pub fn gift_karma_handler(ctx: Context<GiftKarma>, amount: u32) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+    let rules = ctx.accounts.global_config.karma_gift_rules;
+    require!(rules.daily_cap > 0, VoixError::KarmaGiftingDisabled);
+    require!(amount > 0, VoixError::InvalidGiftAmount);
+
+    let global_config = &mut ctx.accounts.global_config;
+    let giver_account = &mut ctx.accounts.giver_account;
+    let receiver_account = &mut ctx.accounts.receiver_account;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // The daily window restarts lazily on the first gift of a new UTC day.
+    let day = u32::try_from(u64::try_from(timestamp).unwrap_or(0) / SECONDS_PER_DAY)
+        .map_err(|_| VoixError::MathOverflow)?;
+    if giver_account.gift_day != day {
+        giver_account.gift_day = day;
+        giver_account.karma_gifted_today = 0;
+    }
+    let gifted_today = giver_account
+        .karma_gifted_today
+        .checked_add(amount)
+        .ok_or(VoixError::MathOverflow)?;
+    require!(
+        gifted_today <= rules.daily_cap,
+        VoixError::GiftDailyCapExceeded
+    );
+
+    // Both sides move from their decayed karma.
+    global_config.settle_karma(
+        giver_account,
+        ctx.accounts.giver_karma_history.as_deref_mut(),
+        timestamp,
+    )?;
+    let giver_old_karma = giver_account.karma;
+    let giver_new_karma = giver_old_karma
+        .checked_sub(amount)
+        .filter(|karma| *karma >= rules.min_balance)
+        .ok_or(VoixError::GiftBelowMinBalance)?;
+
+    let receiver_old_karma = global_config.effective_karma(receiver_account, timestamp);
+    let receiver_new_karma = receiver_old_karma
+        .checked_add(amount)
+        .ok_or(VoixError::MathOverflow)?;
+    global_config.track_karma_increase(
+        receiver_account,
+        ctx.accounts.receiver_karma_history.as_deref_mut(),
+        receiver_new_karma,
+        timestamp,
+    )?;
+
+    // --- Update State ---
+    giver_account.karma = giver_new_karma;
+    giver_account.karma_gifted_today = gifted_today;
+    receiver_account.karma = receiver_new_karma;
+
+    let giver = ctx.accounts.giver.key();
+    KarmaHistory::record(
+        ctx.accounts.giver_karma_history.as_deref_mut(),
+        giver_account,
+        KarmaChange {
+            old_karma: giver_old_karma,
+            new_karma: giver_new_karma,
+            reason: Some(KarmaReason::Gift),
+            signer: giver,
+            timestamp,
+        },
+    )?;
+    KarmaHistory::record(
+        ctx.accounts.receiver_karma_history.as_deref_mut(),
+        receiver_account,
+        KarmaChange {
+            old_karma: receiver_old_karma,
+            new_karma: receiver_new_karma,
+            reason: Some(KarmaReason::Gift),
+            signer: giver,
+            timestamp,
+        },
+    )?;
+
+    // --- Emit Event ---
+    emit!(KarmaGifted {
+        giver,
+        receiver: receiver_account.user_pubkey,
+        amount,
+        giver_karma: giver_new_karma,
+        receiver_karma: receiver_new_karma,
+        gifted_today,
+        timestamp,
+    });
+
+    msg!(
+        "User {} gifted {} karma to {}",
+        giver,
+        amount,
+        receiver_account.user_pubkey
+    );
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::RoleGranted;
+use crate::state::{GlobalConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(role: Role, authority: Pubkey)]
+pub struct GrantRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to hand a role to a (new) key.
+/// The previous holder, if any, loses the role immediately.
+pub fn grant_role_handler(ctx: Context<GrantRole>, role: Role, authority: Pubkey) -> Result<()> {
+    // --- Security Check ---
+    // Granting to the default key would silently revoke the role; use `revoke_role` instead.
+    require!(authority != Pubkey::default(), VoixError::InvalidRoleAuthority);
+
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.set_role(role, authority);
+
+    // --- Emit Event ---
+    emit!(RoleGranted {
+        role,
+        authority,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Role {:?} granted to {}", role, authority);
+    Ok(())
+}
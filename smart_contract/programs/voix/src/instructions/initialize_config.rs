@@ -1,5 +1,8 @@
-use crate::constants::CONFIG_SEED;
-use crate::state::GlobalConfig;
+use crate::constants::{CONFIG_SEED, CONFIG_VERSION};
+use crate::state::{
+    BadgeMode, BadgeStandard, GlobalConfig, KarmaDecay, KarmaGiftRules, KarmaLimits,
+    TipKarmaRule,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -22,10 +25,32 @@ pub struct InitializeConfig<'info> {
 pub fn handler(ctx: Context<InitializeConfig>) -> Result<()> {
     let global_config = &mut ctx.accounts.global_config;
 
+    let admin = ctx.accounts.admin.key();
+
+    // Every role starts out with the admin, so a single backend key keeps
+    // working until the roles are split up with `grant_role`.
     global_config.set_inner(GlobalConfig {
-        admin: ctx.accounts.admin.key(),
+        version: CONFIG_VERSION,
+        admin,
         merkle_root: [0; 32], // Initialize the Merkle root (an empty 32-byte array)
         epoch: 0,
+        pending_admin: None,
+        karma_oracle: admin,
+        merkle_publisher: admin,
+        user_sponsor: admin,
+        pauser: admin,
+        council_enabled: false,
+        paused_flags: 0,
+        badge_collection: Pubkey::default(),
+        badge_mode: BadgeMode::PerMilestone,
+        badge_standard: BadgeStandard::MetaplexNft,
+        karma_limits: KarmaLimits::default(),
+        karma_limit_epoch: 0,
+        epoch_karma_increase: 0,
+        karma_decay: KarmaDecay::None,
+        karma_gift_rules: KarmaGiftRules::default(),
+        tip_karma_rule: TipKarmaRule::default(),
+        reserved: [0; 27],
     });
 
     msg!(
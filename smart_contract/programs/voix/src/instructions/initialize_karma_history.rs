@@ -0,0 +1,49 @@
+use crate::constants::{KARMA_HISTORY_SEED, USER_SEED};
+use crate::events::KarmaHistoryInitialized;
+use crate::state::{KarmaHistory, UserAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeKarmaHistory<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + KarmaHistory::INIT_SPACE,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Account<'info, KarmaHistory>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Lets a user opt in to an on-chain log of their recent karma changes. From
+/// then on every karma write must pass the history account.
+pub fn initialize_karma_history_handler(ctx: Context<InitializeKarmaHistory>) -> Result<()> {
+    let user = ctx.accounts.user.key();
+
+    // --- Update State ---
+    ctx.accounts
+        .karma_history
+        .set_inner(KarmaHistory::new(user));
+    ctx.accounts.user_account.has_karma_history = true;
+
+    // --- Emit Event ---
+    emit!(KarmaHistoryInitialized {
+        user,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Karma history initialized for user: {}", user);
+    Ok(())
+}
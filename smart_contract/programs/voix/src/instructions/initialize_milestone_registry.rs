@@ -0,0 +1,69 @@
+use crate::constants::{
+    BRONZE_FLAG_INDEX, BRONZE_KARMA_REQ, CONFIG_SEED, GOLD_FLAG_INDEX, GOLD_KARMA_REQ,
+    MILESTONE_REGISTRY_SEED, MILESTONE_REGISTRY_VERSION, SILVER_FLAG_INDEX, SILVER_KARMA_REQ,
+};
+use crate::errors::VoixError;
+use crate::state::{GlobalConfig, MilestoneRegistry, MilestoneTier};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeMilestoneRegistry<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    // One-time setup with fixed tiers, so it stays available in council mode
+    // (see the policy on `CouncilAction`).
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + MilestoneRegistry::INIT_SPACE,
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The Bronze / Silver / Gold tiers the program used to hard-code.
+fn default_tiers() -> Vec<MilestoneTier> {
+    [
+        (1, BRONZE_KARMA_REQ, "Bronze", "bronze", BRONZE_FLAG_INDEX),
+        (2, SILVER_KARMA_REQ, "Silver", "silver", SILVER_FLAG_INDEX),
+        (3, GOLD_KARMA_REQ, "Gold", "gold", GOLD_FLAG_INDEX),
+    ]
+    .into_iter()
+    .map(|(level, karma_required, name, slug, flag_index)| MilestoneTier {
+        level,
+        karma_required,
+        name: format!("Voix Milestone {}", name),
+        symbol: "VOIX".to_string(),
+        uri: format!("https://voix.com/metadata/{}", slug),
+        flag_index,
+        retired: false,
+    })
+    .collect()
+}
+
+/// This is an admin-only function to create the milestone registry,
+/// seeded with the default Bronze / Silver / Gold tiers.
+pub fn initialize_milestone_registry_handler(
+    ctx: Context<InitializeMilestoneRegistry>,
+) -> Result<()> {
+    ctx.accounts.milestone_registry.set_inner(MilestoneRegistry {
+        version: MILESTONE_REGISTRY_VERSION,
+        tiers: default_tiers(),
+        transferable_flags: 0,
+        reserved: [0; 56],
+    });
+
+    msg!("Milestone registry initialized with the default tiers");
+    Ok(())
+}
@@ -1,17 +1,24 @@
-use crate::constants::USER_SEED;
+use crate::constants::{CONFIG_SEED, PAUSE_ONBOARDING, USER_ACCOUNT_VERSION, USER_SEED};
+use crate::errors::VoixError;
 use crate::events::UserInitialized;
-use crate::state::UserAccount;
+use crate::state::{GlobalConfig, UserAccount};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct InitializeUser<'info> {
     #[account(mut)]
-    pub admin : Signer<'info> , 
+    pub user_sponsor : Signer<'info> , 
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = user_sponsor @ VoixError::MissingUserSponsorRole
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
     #[account(mut)]
     pub user: SystemAccount<'info>,
     #[account(
         init,
-        payer = admin,
+        payer = user_sponsor,
         space = 8 + UserAccount::INIT_SPACE,
         seeds = [USER_SEED, user.key().as_ref()],
         bump
@@ -21,19 +28,40 @@ pub struct InitializeUser<'info> {
 }
 
 pub fn handler(ctx: Context<InitializeUser>) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_ONBOARDING)?;
+
     let user_account = &mut ctx.accounts.user_account;
+    let timestamp = Clock::get()?.unix_timestamp;
 
     user_account.set_inner(UserAccount {
+        version: USER_ACCOUNT_VERSION,
         user_pubkey: ctx.accounts.user.key(),
         karma: 0,
         minted_milestones: 0,
         total_sol_tipped: 0,
+        badge_mint: Pubkey::default(),
+        badge_level: 0,
+        tips_sent: 0,
+        created_at: timestamp,
+        achievements: 0,
+        karma_nonce: 0,
+        karma_synced_epoch: 0,
+        karma_seq: 0,
+        karma_limit_epoch: 0,
+        epoch_karma_increase: 0,
+        last_karma_increase_at: 0,
+        last_karma_update: 0,
+        has_karma_history: false,
+        gift_day: 0,
+        karma_gifted_today: 0,
+        reserved: [0; 14],
     });
 
     // Emit an event to log that a new user has joined
     emit!(UserInitialized {
         user: ctx.accounts.user.key(),
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp,
     });
 
     msg!("User account initialized for: {}", user_account.user_pubkey);
@@ -0,0 +1,73 @@
+use crate::constants::{CONFIG_SEED, CONFIG_VERSION};
+use crate::errors::VoixError;
+use crate::events::AccountMigrated;
+use crate::state::{GlobalConfig, GlobalConfigV0};
+use crate::utils::resize_account;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Must be the admin recorded in the old layout. Pays the extra rent.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: The old layout can't be deserialized as `GlobalConfig`,
+    /// so the PDA seeds, owner and discriminator are checked by hand.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        owner = crate::ID
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Reallocs a pre-versioning `GlobalConfig` to the current layout.
+/// Existing values are kept; every new field gets its `initialize_config` default.
+pub fn migrate_config_handler(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.global_config.to_account_info();
+    let admin = ctx.accounts.admin.key();
+
+    // --- Security Check ---
+    // Read the old layout and make sure the caller is its admin.
+    let legacy = {
+        let data = config_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && &data[..8] == GlobalConfig::DISCRIMINATOR,
+            VoixError::InvalidAccountData
+        );
+        require!(
+            data.len() == 8 + GlobalConfigV0::INIT_SPACE,
+            VoixError::AccountAlreadyMigrated
+        );
+        GlobalConfigV0::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.admin, admin, VoixError::Unauthorized);
+
+    // --- Realloc ---
+    resize_account(
+        &config_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + GlobalConfig::INIT_SPACE,
+    )?;
+
+    // --- Update State ---
+    let migrated = GlobalConfig::from(legacy);
+    let mut data = config_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    // --- Emit Event ---
+    emit!(AccountMigrated {
+        account: config_info.key(),
+        version: CONFIG_VERSION,
+        payer: admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Global config migrated to version {}", CONFIG_VERSION);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use crate::constants::{CONFIG_SEED, USER_ACCOUNT_VERSION, USER_SEED};
+use crate::errors::VoixError;
+use crate::events::AccountMigrated;
+use crate::state::{GlobalConfig, UserAccount, UserAccountV0};
+use crate::utils::resize_account;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+#[derive(Accounts)]
+pub struct MigrateUserAccount<'info> {
+    /// Either the user themselves or the admin. Pays the extra rent.
+    #[account(
+        mut,
+        constraint = payer.key() == user.key() || payer.key() == global_config.admin
+            @ VoixError::Unauthorized
+    )]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// CHECK: The wallet that owns `user_account`; only used for the PDA seeds.
+    pub user: UncheckedAccount<'info>,
+
+    /// CHECK: The old layout can't be deserialized as `UserAccount`,
+    /// so the PDA seeds, owner and discriminator are checked by hand.
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub user_account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Reallocs a version 0 `UserAccount` to the current layout.
+/// Karma, milestones and tip totals are kept; new fields start at zero.
+pub fn migrate_user_account_handler(ctx: Context<MigrateUserAccount>) -> Result<()> {
+    let user_info = ctx.accounts.user_account.to_account_info();
+
+    // --- Security Check ---
+    // Every layout change also changes the account size (new fields that fit in
+    // `reserved` don't need a migration), so the size identifies the old version.
+    let migrated = {
+        let data = user_info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && &data[..8] == UserAccount::DISCRIMINATOR,
+            VoixError::InvalidAccountData
+        );
+        if data.len() == 8 + UserAccountV0::INIT_SPACE {
+            UserAccount::from(UserAccountV0::deserialize(&mut &data[8..])?)
+        } else {
+            return err!(VoixError::AccountAlreadyMigrated);
+        }
+    };
+    require_keys_eq!(
+        migrated.user_pubkey,
+        ctx.accounts.user.key(),
+        VoixError::InvalidAccountData
+    );
+
+    // --- Realloc ---
+    resize_account(
+        &user_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + UserAccount::INIT_SPACE,
+    )?;
+
+    // --- Update State ---
+    let mut data = user_info.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    // --- Emit Event ---
+    emit!(AccountMigrated {
+        account: user_info.key(),
+        version: USER_ACCOUNT_VERSION,
+        payer: ctx.accounts.payer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "User account for {} migrated to version {}",
+        migrated.user_pubkey,
+        USER_ACCOUNT_VERSION
+    );
+    Ok(())
+}
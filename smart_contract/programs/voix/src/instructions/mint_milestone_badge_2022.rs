@@ -0,0 +1,316 @@
+use crate::constants::{
+    BADGE_SEED, CONFIG_SEED, KARMA_HISTORY_SEED, MILESTONE_RECORD_SEED, MILESTONE_REGISTRY_SEED,
+    MINT_AUTHORITY_SEED, PAUSE_KARMA, PAUSE_MINTS, USER_SEED,
+};
+use crate::errors::VoixError;
+use crate::events::MilestoneNftMinted;
+use crate::state::{
+    BadgeMode, BadgeStandard, GlobalConfig, KarmaHistory, MilestoneRecord, MilestoneRegistry,
+    UserAccount,
+};
+use crate::utils::create_pda_account;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{create, AssociatedToken, Create};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{
+    initialize_mint2, mint_to, set_authority, InitializeMint2, MintTo, SetAuthority, Token2022,
+};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
+use anchor_spl::token_2022_extensions::{
+    metadata_pointer_initialize, non_transferable_mint_initialize, permanent_delegate_initialize,
+    token_metadata_initialize, MetadataPointerInitialize, NonTransferableMintInitialize,
+    PermanentDelegateInitialize, TokenMetadataInitialize,
+};
+
+#[derive(Accounts)]
+#[instruction(milestone_level: u8)]
+pub struct MintMilestoneBadge2022<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    // Circuit breakers: checked as constraints so they run before any account below is created.
+    // Minting saves the user's decayed karma, so it also stops while karma writes are paused.
+    // The single reputation badge is only available as a Metaplex NFT.
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !global_config.is_paused(PAUSE_MINTS) @ VoixError::ProgramPaused,
+        constraint = !global_config.is_paused(PAUSE_KARMA) @ VoixError::ProgramPaused,
+        constraint = global_config.badge_standard == BadgeStandard::Token2022 @ VoixError::WrongBadgeStandard,
+        constraint = global_config.badge_mode == BadgeMode::PerMilestone @ VoixError::WrongBadgeMode
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump,
+        constraint = milestone_registry
+            .tier(milestone_level)
+            .filter(|tier| user_account.minted_milestones & tier.flag() != 0)
+            .is_none()
+            @ VoixError::MilestoneAlreadyMinted
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    // Created on the first mint; a revoked record is reused when the milestone is reached again.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + MilestoneRecord::INIT_SPACE,
+        seeds = [MILESTONE_RECORD_SEED, user.key().as_ref(), &[milestone_level]],
+        bump,
+        constraint = milestone_record.version == 0 || milestone_record.revoked
+            @ VoixError::MilestoneAlreadyMinted
+    )]
+    pub milestone_record: Box<Account<'info, MilestoneRecord>>,
+
+    /// CHECK: Created in the handler, because the extension list (and so the
+    /// account size) depends on whether the tier is soulbound.
+    #[account(
+        mut,
+        seeds = [
+            BADGE_SEED,
+            user.key().as_ref(),
+            &[milestone_level],
+            milestone_record.badge_seed()
+        ],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Created (and its address checked) by the Associated Token program.
+    #[account(mut)]
+    pub token_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mints a milestone badge as a Token-2022 mint that carries its own metadata
+/// (metadata-pointer + token-metadata extensions). Soulbound tiers also get the
+/// non-transferable extension, so no freeze is needed. The PDA is the permanent
+/// delegate, so `revoke_milestone` can burn the badge wherever it is.
+pub fn mint_milestone_badge_2022_handler(
+    ctx: Context<MintMilestoneBadge2022>,
+    milestone_level: u8,
+) -> Result<()> {
+    let user_account = &mut ctx.accounts.user_account;
+
+    // 1. Look up the tier and check the user's karma
+    let tier = ctx
+        .accounts
+        .milestone_registry
+        .mintable_tier(milestone_level)?
+        .clone();
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.global_config.settle_karma(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        timestamp,
+    )?;
+    require!(
+        user_account.karma >= tier.karma_required,
+        VoixError::InsufficientKarma
+    );
+    ctx.accounts
+        .global_config
+        .require_mint_cooldown(user_account, timestamp)?;
+    let soulbound = ctx.accounts.milestone_registry.is_soulbound(&tier);
+
+    let user_key = ctx.accounts.user.key();
+    let mint_key = ctx.accounts.mint.key();
+    let mint_authority_key = ctx.accounts.mint_authority.key();
+    let level_seed = [milestone_level];
+    let mint_seeds: &[&[&[u8]]] = &[&[
+        BADGE_SEED,
+        user_key.as_ref(),
+        &level_seed,
+        ctx.accounts.milestone_record.badge_seed(),
+        &[ctx.bumps.mint],
+    ]];
+    let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
+
+    // 2. Create the mint account, sized for its fixed extensions. The token-metadata
+    // extension is variable-length and reallocated by Token-2022 itself, so we
+    // only pre-fund its rent here.
+    let mut extensions = vec![
+        ExtensionType::MetadataPointer,
+        ExtensionType::PermanentDelegate,
+    ];
+    if soulbound {
+        extensions.push(ExtensionType::NonTransferable);
+    }
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey::try_from(Some(mint_authority_key))?,
+        mint: mint_key,
+        name: tier.name.clone(),
+        symbol: tier.symbol.clone(),
+        uri: tier.uri.clone(),
+        additional_metadata: vec![],
+    };
+    let lamports = Rent::get()?.minimum_balance(space + metadata.tlv_size_of()?);
+
+    create_pda_account(
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.key(),
+        space,
+        lamports,
+        mint_seeds,
+    )?;
+
+    // 3. Initialize the extensions, then the mint itself
+    metadata_pointer_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            MetadataPointerInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        Some(mint_authority_key),
+        Some(mint_key),
+    )?;
+
+    permanent_delegate_initialize(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            PermanentDelegateInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        &mint_authority_key,
+    )?;
+
+    if soulbound {
+        non_transferable_mint_initialize(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            NonTransferableMintInitialize {
+                token_program_id: ctx.accounts.token_program.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ))?;
+    }
+
+    initialize_mint2(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeMint2 {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        0,
+        &mint_authority_key,
+        Some(&mint_authority_key),
+    )?;
+
+    // 4. Write the canonical tier metadata into the mint
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.mint.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+                mint_authority: ctx.accounts.mint_authority.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        metadata.name,
+        metadata.symbol,
+        metadata.uri,
+    )?;
+
+    // 5. Create the user's token account and mint the single badge token
+    create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.user.to_account_info(),
+            associated_token: ctx.accounts.token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        1,
+    )?;
+
+    // 6. Drop the mint authority so the supply stays at one (the master edition
+    // does this for Metaplex badges). The PDA keeps the freeze authority.
+    set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.mint_authority.to_account_info(),
+                account_or_mint: ctx.accounts.mint.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // 7. Update State
+    user_account.minted_milestones |= tier.flag();
+
+    let clock = Clock::get()?;
+    ctx.accounts.milestone_record.record_mint(
+        user_key,
+        milestone_level,
+        mint_key,
+        clock.slot,
+        user_account.karma,
+    );
+
+    // 8. Emit Event
+    emit!(MilestoneNftMinted {
+        user: user_key,
+        nft_mint: mint_key,
+        milestone_level,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,32 +1,65 @@
 use crate::constants::{
-    BRONZE_KARMA_REQ, BRONZE_MILESTONE_FLAG, GOLD_KARMA_REQ, GOLD_MILESTONE_FLAG,
-    MINT_AUTHORITY_SEED, SILVER_KARMA_REQ, SILVER_MILESTONE_FLAG, USER_SEED,
+    BADGE_SEED, CONFIG_SEED, KARMA_HISTORY_SEED, MILESTONE_RECORD_SEED, MILESTONE_REGISTRY_SEED,
+    MINT_AUTHORITY_SEED, PAUSE_KARMA, PAUSE_MINTS, USER_SEED,
 };
 use crate::errors::VoixError;
 use crate::events::MilestoneNftMinted;
-use crate::state::UserAccount;
+use crate::state::{
+    BadgeMode, BadgeStandard, GlobalConfig, KarmaHistory, MilestoneRecord, MilestoneRegistry,
+    UserAccount,
+};
+use crate::utils::{mint_badge_nft, BadgeNftAccounts};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::metadata::{
-    create_master_edition_v3, create_metadata_accounts_v3, CreateMasterEditionV3,
-    CreateMetadataAccountsV3, Metadata,
-};
-use anchor_spl::token::{mint_to, Mint, MintTo, Token, TokenAccount};
-use mpl_token_metadata::types::DataV2;
+use anchor_spl::token::{Mint, Token, TokenAccount};
 
 #[derive(Accounts)]
-#[instruction(milestone_level: u8, name: String, symbol: String, uri: String)]
+#[instruction(milestone_level: u8)]
 pub struct MintMilestoneNft<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    // Circuit breakers: checked as constraints so they run before any account below is created.
+    // Minting saves the user's decayed karma, so it also stops while karma writes are paused.
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !global_config.is_paused(PAUSE_MINTS) @ VoixError::ProgramPaused,
+        constraint = !global_config.is_paused(PAUSE_KARMA) @ VoixError::ProgramPaused,
+        constraint = global_config.badge_standard == BadgeStandard::MetaplexNft @ VoixError::WrongBadgeStandard,
+        constraint = global_config.badge_collection != Pubkey::default() @ VoixError::BadgeCollectionNotCreated
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+
+    // Security Check: Has the user already minted this milestone?
+    // Checked here rather than in the handler so it fails with a clear error
+    // before the (deterministic) badge mint below is initialized.
     #[account(
         mut,
         seeds = [USER_SEED, user.key().as_ref()],
-        bump
+        bump,
+        constraint = milestone_registry
+            .tier(milestone_level)
+            .filter(|tier| user_account.minted_milestones & tier.flag() != 0)
+            .is_none()
+            @ VoixError::MilestoneAlreadyMinted
     )]
     pub user_account: Account<'info, UserAccount>,
 
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
     /// CHECK: This is a PDA, not a traditional account.
     #[account(
         seeds = [MINT_AUTHORITY_SEED],
@@ -34,9 +67,28 @@ pub struct MintMilestoneNft<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
 
+    // Created on the first mint; a revoked record is reused when the milestone is reached again.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + MilestoneRecord::INIT_SPACE,
+        seeds = [MILESTONE_RECORD_SEED, user.key().as_ref(), &[milestone_level]],
+        bump,
+        constraint = milestone_record.version == 0 || milestone_record.revoked
+            @ VoixError::MilestoneAlreadyMinted
+    )]
+    pub milestone_record: Box<Account<'info, MilestoneRecord>>,
+
     #[account(
         init,
         payer = user,
+        seeds = [
+            BADGE_SEED,
+            user.key().as_ref(),
+            &[milestone_level],
+            milestone_record.badge_seed()
+        ],
+        bump,
         mint::decimals = 0,
         mint::authority = mint_authority,
         mint::freeze_authority = mint_authority,
@@ -78,6 +130,36 @@ pub struct MintMilestoneNft<'info> {
     )]
     pub master_edition_account: UncheckedAccount<'info>,
 
+    /// CHECK: Must be the collection mint stored in `GlobalConfig`.
+    #[account(address = global_config.badge_collection @ VoixError::BadgeCollectionNotCreated)]
+    pub collection_mint: UncheckedAccount<'info>,
+
+    /// CHECK: The collection's metadata; its size counter is bumped by the verify CPI.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_metadata: UncheckedAccount<'info>,
+
+    /// CHECK: The collection's master edition, checked by Token Metadata.
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            collection_mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub collection_master_edition: UncheckedAccount<'info>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -88,35 +170,47 @@ pub struct MintMilestoneNft<'info> {
     pub token_metadata_program: AccountInfo<'info>,
 }
 
-pub fn handler(
-    ctx: Context<MintMilestoneNft>,
-    milestone_level: u8,
-    name: String,
-    symbol: String,
-    uri: String,
-) -> Result<()> {
+pub fn handler(ctx: Context<MintMilestoneNft>, milestone_level: u8) -> Result<()> {
+    // 0. The circuit breakers (PAUSE_MINTS, PAUSE_KARMA) are checked as account constraints.
+
     let user_account = &mut ctx.accounts.user_account;
 
-    // 1. Determine which milestone we are checking based on the input level
-    let (required_karma, milestone_flag) = match milestone_level {
-        1 => (BRONZE_KARMA_REQ, BRONZE_MILESTONE_FLAG),
-        2 => (SILVER_KARMA_REQ, SILVER_MILESTONE_FLAG),
-        3 => (GOLD_KARMA_REQ, GOLD_MILESTONE_FLAG),
-        _ => return err!(VoixError::InvalidMilestoneLevel),
-    };
+    // 1. Look up the milestone tier for the requested level in the on-chain registry
+    let tier = ctx
+        .accounts
+        .milestone_registry
+        .mintable_tier(milestone_level)?
+        .clone();
+    let (required_karma, milestone_flag) = (tier.karma_required, tier.flag());
+    let soulbound = ctx.accounts.milestone_registry.is_soulbound(&tier);
 
-    // 2. Security Check 1: Does the user have enough karma?
+    // 2. Security Check 1: Does the user have enough karma (after decay), held for the cooldown?
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.global_config.settle_karma(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        timestamp,
+    )?;
     require!(
         user_account.karma >= required_karma,
         VoixError::InsufficientKarma
     );
+    ctx.accounts
+        .global_config
+        .require_mint_cooldown(user_account, timestamp)?;
 
-    // 3. Security Check 2: Has the user already minted this milestone?
-    // We use a bitwise AND to check if the flag is already set.
-    require!(
-        (user_account.minted_milestones & milestone_flag) == 0,
-        VoixError::MilestoneAlreadyMinted
-    );
+    // 3. Security Check 2 ("already minted?") runs as an account constraint.
+
+    // 3b. Reputation mode: each user gets exactly one badge, later levels (and
+    // revoked lower ones) are reached with `upgrade_reputation_badge` instead of a new mint.
+    let reputation_mode = ctx.accounts.global_config.badge_mode == BadgeMode::Reputation;
+    if reputation_mode {
+        require_keys_eq!(
+            user_account.badge_mint,
+            Pubkey::default(),
+            VoixError::ReputationBadgeExists
+        );
+    }
 
     // --- All checks passed, let's mint! ---
     msg!("Checks passed. Minting NFT...");
@@ -124,83 +218,55 @@ pub fn handler(
     // 4. Define PDA signer seeds
     let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
 
-    // 5. CPI to Token Program: Mint 1 token to the user's ATA
-    mint_to(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            MintTo {
-                mint: ctx.accounts.mint.to_account_info(),
-                to: ctx.accounts.token_account.to_account_info(),
-                authority: ctx.accounts.mint_authority.to_account_info(),
-            },
-            authority_seeds,
-        ),
-        1, // Mint 1 token
-    )?;
-    msg!("Token minted.");
-
-    // 6. CPI to Metaplex: Create the Metadata Account
-    let data_v2 = DataV2 {
-        name,
-        symbol,
-        uri,
-        seller_fee_basis_points: 0,
-        creators: None,
-        collection: None,
-        uses: None,
-    };
-
-    create_metadata_accounts_v3(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_metadata_program.to_account_info(),
-            CreateMetadataAccountsV3 {
-                metadata: ctx.accounts.metadata_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                mint_authority: ctx.accounts.mint_authority.to_account_info(),
-                payer: ctx.accounts.user.to_account_info(),
-                update_authority: ctx.accounts.mint_authority.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-            },
-            authority_seeds,
-        ),
-        data_v2,
-        true,
-        true,
-        None,
-    )?;
-    msg!("Metadata created.");
-
-    // 7. CPI to Metaplex: Create the Master Edition Account
-    create_master_edition_v3(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_metadata_program.to_account_info(),
-            CreateMasterEditionV3 {
-                edition: ctx.accounts.master_edition_account.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-                update_authority: ctx.accounts.mint_authority.to_account_info(),
-                mint_authority: ctx.accounts.mint_authority.to_account_info(),
-                payer: ctx.accounts.user.to_account_info(),
-                metadata: ctx.accounts.metadata_account.to_account_info(),
-                token_program: ctx.accounts.token_program.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
-            },
-            authority_seeds,
-        ),
-        Some(0),
+    // 5. Mint the badge into the Voix collection (frozen for soulbound tiers).
+    // The metadata always comes from the registry, so nobody can mint a
+    // "Voix Gold" badge that points at an arbitrary image.
+    mint_badge_nft(
+        &BadgeNftAccounts {
+            user: ctx.accounts.user.to_account_info(),
+            mint_authority: ctx.accounts.mint_authority.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            metadata_account: ctx.accounts.metadata_account.to_account_info(),
+            master_edition_account: ctx.accounts.master_edition_account.to_account_info(),
+            collection_mint: ctx.accounts.collection_mint.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+            token_metadata_program: ctx.accounts.token_metadata_program.to_account_info(),
+        },
+        authority_seeds,
+        tier.name,
+        tier.symbol,
+        tier.uri,
+        soulbound,
     )?;
-    msg!("Master Edition created.");
 
-    // 8. Update State: Set the bit-flag for this milestone
+    // 6. Update State: Set the bit-flag for this milestone
     user_account.minted_milestones |= milestone_flag;
+    if reputation_mode {
+        user_account.badge_mint = ctx.accounts.mint.key();
+        user_account.badge_level = milestone_level;
+    }
+
+    // 6b. Record the milestone in its own PDA so it can be looked up directly
+    let clock = Clock::get()?;
+    ctx.accounts.milestone_record.record_mint(
+        ctx.accounts.user.key(),
+        milestone_level,
+        ctx.accounts.mint.key(),
+        clock.slot,
+        user_account.karma,
+    );
 
-    // 9. Emit Event
+    // 7. Emit Event
     emit!(MilestoneNftMinted {
         user: ctx.accounts.user.key(),
         nft_mint: ctx.accounts.mint.key(),
         milestone_level,
-        timestamp: Clock::get()?.unix_timestamp,
+        timestamp: clock.unix_timestamp,
     });
 
     Ok(())
@@ -18,3 +18,123 @@ pub use update_user_karma::*;
 
 pub mod mint_milestone_nft;
 pub use mint_milestone_nft::*;
+
+pub mod propose_admin;
+pub use propose_admin::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
+pub mod cancel_admin_proposal;
+pub use cancel_admin_proposal::*;
+
+pub mod grant_role;
+pub use grant_role::*;
+
+pub mod revoke_role;
+pub use revoke_role::*;
+
+pub mod enable_council;
+pub use enable_council::*;
+
+pub mod create_council_proposal;
+pub use create_council_proposal::*;
+
+pub mod approve_council_proposal;
+pub use approve_council_proposal::*;
+
+pub mod execute_council_proposal;
+pub use execute_council_proposal::*;
+
+pub mod set_paused;
+pub use set_paused::*;
+
+pub mod migrate_config;
+pub use migrate_config::*;
+
+pub mod migrate_user_account;
+pub use migrate_user_account::*;
+
+pub mod initialize_milestone_registry;
+pub use initialize_milestone_registry::*;
+
+pub mod add_milestone_tier;
+pub use add_milestone_tier::*;
+
+pub mod update_milestone_tier;
+pub use update_milestone_tier::*;
+
+pub mod retire_milestone_tier;
+pub use retire_milestone_tier::*;
+
+pub mod update_milestone_metadata;
+pub use update_milestone_metadata::*;
+
+pub mod create_badge_collection;
+pub use create_badge_collection::*;
+
+pub mod set_tier_transferable;
+pub use set_tier_transferable::*;
+
+pub mod thaw_badge;
+pub use thaw_badge::*;
+
+pub mod set_badge_mode;
+pub use set_badge_mode::*;
+
+pub mod upgrade_reputation_badge;
+pub use upgrade_reputation_badge::*;
+
+pub mod update_badge_metadata;
+pub use update_badge_metadata::*;
+
+pub mod batch_update_badge_metadata;
+pub use batch_update_badge_metadata::*;
+
+pub mod revoke_milestone;
+pub use revoke_milestone::*;
+
+pub mod set_badge_standard;
+pub use set_badge_standard::*;
+
+pub mod mint_milestone_badge_2022;
+pub use mint_milestone_badge_2022::*;
+
+pub mod create_achievement;
+pub use create_achievement::*;
+
+pub mod claim_achievement;
+pub use claim_achievement::*;
+
+pub mod claim_karma;
+pub use claim_karma::*;
+
+pub mod sync_karma_from_root;
+pub use sync_karma_from_root::*;
+
+pub mod apply_karma_delta;
+pub use apply_karma_delta::*;
+
+pub mod batch_update_karma;
+pub use batch_update_karma::*;
+
+pub mod set_karma_limits;
+pub use set_karma_limits::*;
+
+pub mod set_karma_decay;
+pub use set_karma_decay::*;
+
+pub mod settle_karma;
+pub use settle_karma::*;
+
+pub mod initialize_karma_history;
+pub use initialize_karma_history::*;
+
+pub mod set_karma_gift_rules;
+pub use set_karma_gift_rules::*;
+
+pub mod gift_karma;
+pub use gift_karma::*;
+
+pub mod set_tip_karma_rule;
+pub use set_tip_karma_rule::*;
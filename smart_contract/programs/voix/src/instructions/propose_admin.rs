@@ -0,0 +1,51 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::AdminProposed;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(new_admin: Pubkey)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to nominate a new admin.
+/// The nominee only takes over once they call `accept_admin`.
+pub fn propose_admin_handler(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // --- Security Check ---
+    // Proposing the current admin (or the default key) would be a no-op handover.
+    require!(
+        new_admin != global_config.admin && new_admin != Pubkey::default(),
+        VoixError::InvalidAdminProposal
+    );
+
+    // --- Update State ---
+    // A new proposal simply replaces any previous one.
+    global_config.pending_admin = Some(new_admin);
+
+    // --- Emit Event ---
+    emit!(AdminProposed {
+        admin: global_config.admin,
+        pending_admin: new_admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Admin handover proposed from {} to {}",
+        global_config.admin,
+        new_admin
+    );
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::constants::{CONFIG_SEED, MILESTONE_REGISTRY_SEED};
+use crate::errors::VoixError;
+use crate::events::MilestoneTierChanged;
+use crate::state::{GlobalConfig, MilestoneRegistry};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(level: u8)]
+pub struct RetireMilestoneTier<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+}
+
+/// This is an admin-only function to stop new mints of a tier.
+/// Badges that were already minted are not affected.
+pub fn retire_milestone_tier_handler(ctx: Context<RetireMilestoneTier>, level: u8) -> Result<()> {
+    // --- Update State ---
+    let tier = ctx.accounts.milestone_registry.retire_tier(level)?;
+
+    // --- Emit Event ---
+    emit!(MilestoneTierChanged {
+        level,
+        karma_required: tier.karma_required,
+        flag_index: tier.flag_index,
+        retired: true,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Milestone tier {} retired", level);
+    Ok(())
+}
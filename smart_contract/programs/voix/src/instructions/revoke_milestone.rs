@@ -0,0 +1,261 @@
+use crate::constants::{
+    CONFIG_SEED, MILESTONE_RECORD_SEED, MILESTONE_REGISTRY_SEED, MINT_AUTHORITY_SEED, USER_SEED,
+};
+use crate::errors::VoixError;
+use crate::events::MilestoneRevoked;
+use crate::state::{GlobalConfig, MilestoneRecord, MilestoneRegistry, UserAccount};
+use crate::utils::rewrite_badge_metadata;
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{thaw_delegated_account, ThawDelegatedAccount};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{burn, Burn, Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct RevokeMilestone<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub milestone: RevokeMilestoneTarget<'info>,
+}
+
+/// The milestone `revoke_milestone` takes back. `CouncilAction::RevokeMilestone`
+/// reads the same accounts from the `remaining_accounts` of `execute_council_proposal`.
+#[derive(Accounts)]
+#[instruction(milestone_level: u8)]
+pub struct RevokeMilestoneTarget<'info> {
+    #[account(
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+
+    /// CHECK: The wallet whose milestone is revoked; only used for PDA seeds.
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_RECORD_SEED, user.key().as_ref(), &[milestone_level]],
+        bump,
+        constraint = !milestone_record.revoked @ VoixError::MilestoneAlreadyRevoked
+    )]
+    pub milestone_record: Box<Account<'info, MilestoneRecord>>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = milestone_record.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    // The account currently holding the badge. A Token-2022 badge can always be
+    // burned (the PDA is its permanent delegate); a Metaplex badge only while the
+    // PDA is its delegate (soulbound badges). Otherwise the record is just marked revoked.
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: The badge's master edition, checked by Token Metadata (Metaplex badges only).
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition_account: UncheckedAccount<'info>,
+
+    /// CHECK: The badge's metadata account. Needed when a reputation badge that
+    /// can't be burned drops back to a lower tier; checked in `rewrite_badge_metadata`.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+
+    /// The badge's token program: Token for Metaplex badges, Token-2022 otherwise.
+    pub token_program: Interface<'info, TokenInterface>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> RevokeMilestoneTarget<'info> {
+    /// Clears the milestone bit, marks the record revoked and, where the program
+    /// still controls the badge, thaws and burns it. A reputation badge that still
+    /// shows other levels is never burned: it is rewritten to the highest tier the
+    /// user keeps, so those levels' records keep pointing at a live badge.
+    /// `authority` is the admin or the council, for the event.
+    pub fn revoke(
+        &mut self,
+        bumps: &RevokeMilestoneTargetBumps,
+        milestone_level: u8,
+        reason_code: u16,
+        authority: Pubkey,
+    ) -> Result<()> {
+        let flag = self
+            .milestone_registry
+            .tier(milestone_level)
+            .ok_or(VoixError::InvalidMilestoneLevel)?
+            .flag();
+        let mint_key = self.mint.key();
+        let token_2022 = self.token_program.key() == Token2022::id();
+        let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[bumps.mint_authority]]];
+        // A reputation badge that still shows other levels is kept and rewritten below.
+        let user_account = &self.user_account;
+        let remaining_tier = self
+            .milestone_registry
+            .highest_minted_tier(user_account.minted_milestones & !flag);
+        let shared_badge = user_account.badge_mint == mint_key && remaining_tier.is_some();
+
+        // --- Burn the Badge ---
+        let mut burned = false;
+        if let Some(token_account) = self.token_account.as_ref().filter(|_| !shared_badge) {
+            let delegated =
+                token_2022 || token_account.delegate == Some(self.mint_authority.key()).into();
+            if delegated && token_account.amount > 0 {
+                // Only Metaplex badges are frozen; Token-2022 soulbound badges are non-transferable.
+                if token_account.is_frozen() && !token_2022 {
+                    thaw_delegated_account(CpiContext::new_with_signer(
+                        self.token_metadata_program.to_account_info(),
+                        ThawDelegatedAccount {
+                            metadata: self.token_metadata_program.to_account_info(),
+                            delegate: self.mint_authority.to_account_info(),
+                            token_account: token_account.to_account_info(),
+                            edition: self.master_edition_account.to_account_info(),
+                            mint: self.mint.to_account_info(),
+                            token_program: self.token_program.to_account_info(),
+                        },
+                        authority_seeds,
+                    ))?;
+                }
+
+                burn(
+                    CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        Burn {
+                            mint: self.mint.to_account_info(),
+                            from: token_account.to_account_info(),
+                            authority: self.mint_authority.to_account_info(),
+                        },
+                        authority_seeds,
+                    ),
+                    token_account.amount,
+                )?;
+                burned = true;
+            }
+        }
+
+        // --- Update State ---
+        let user_account = &mut self.user_account;
+        user_account.minted_milestones &= !flag;
+        if user_account.badge_mint == mint_key {
+            match remaining_tier {
+                // The reputation badge drops back to the highest tier kept.
+                Some(tier) => {
+                    if user_account.badge_level == milestone_level {
+                        let metadata_account = self
+                            .metadata_account
+                            .as_ref()
+                            .ok_or(VoixError::MissingBadgeMetadata)?;
+                        rewrite_badge_metadata(
+                            &metadata_account.to_account_info(),
+                            &self.mint_authority.to_account_info(),
+                            &self.token_metadata_program,
+                            authority_seeds,
+                            Some(&tier.name),
+                            Some(&tier.symbol),
+                            Some(&tier.uri),
+                        )?;
+                        user_account.badge_level = tier.level;
+                    }
+                }
+                // A badge with no tier left to show no longer counts as the user's badge.
+                None => {
+                    user_account.badge_mint = Pubkey::default();
+                    user_account.badge_level = 0;
+                }
+            }
+        }
+        let badge_level = user_account.badge_level;
+
+        let timestamp = Clock::get()?.unix_timestamp;
+        let record = &mut self.milestone_record;
+        record.revoked = true;
+        record.reason_code = reason_code;
+        record.revoked_at = timestamp;
+        record.revocations = record
+            .revocations
+            .checked_add(1)
+            .ok_or(VoixError::MathOverflow)?;
+
+        // --- Emit Event ---
+        emit!(MilestoneRevoked {
+            user: self.user.key(),
+            milestone_level,
+            nft_mint: mint_key,
+            reason_code,
+            burned,
+            badge_level,
+            admin: authority,
+            timestamp,
+        });
+
+        msg!(
+            "Milestone {} revoked for {} (reason {})",
+            milestone_level,
+            self.user.key(),
+            reason_code
+        );
+        Ok(())
+    }
+}
+
+/// This is an admin-only function to take a milestone back from a user.
+/// The milestone can be reached again later; the re-mint gets a fresh badge mint,
+/// or goes through `upgrade_reputation_badge` while the reputation badge is kept.
+pub fn revoke_milestone_handler(
+    ctx: Context<RevokeMilestone>,
+    milestone_level: u8,
+    reason_code: u16,
+) -> Result<()> {
+    ctx.accounts.milestone.revoke(
+        &ctx.bumps.milestone,
+        milestone_level,
+        reason_code,
+        ctx.accounts.admin.key(),
+    )
+}
@@ -0,0 +1,41 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::RoleRevoked;
+use crate::state::{GlobalConfig, Role};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(role: Role)]
+pub struct RevokeRole<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to leave a role unassigned.
+/// Instructions gated by that role fail until it is granted again.
+pub fn revoke_role_handler(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+    let global_config = &mut ctx.accounts.global_config;
+
+    // --- Update State ---
+    let previous_authority = global_config.role_authority(role);
+    global_config.set_role(role, Pubkey::default());
+
+    // --- Emit Event ---
+    emit!(RoleRevoked {
+        role,
+        previous_authority,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Role {:?} revoked from {}", role, previous_authority);
+    Ok(())
+}
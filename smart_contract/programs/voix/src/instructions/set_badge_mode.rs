@@ -0,0 +1,39 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::BadgeModeChanged;
+use crate::state::{BadgeMode, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetBadgeMode<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to choose how milestone badges are issued.
+/// Badges minted under the previous mode are left as they are.
+pub fn set_badge_mode_handler(ctx: Context<SetBadgeMode>, mode: BadgeMode) -> Result<()> {
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_mode = global_config.badge_mode;
+    global_config.badge_mode = mode;
+
+    // --- Emit Event ---
+    emit!(BadgeModeChanged {
+        previous_mode,
+        badge_mode: mode,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Badge mode changed to {:?}", mode);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::BadgeStandardChanged;
+use crate::state::{BadgeStandard, GlobalConfig};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetBadgeStandard<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to choose which token program new badges use.
+/// Badges minted under the previous standard are left as they are.
+pub fn set_badge_standard_handler(
+    ctx: Context<SetBadgeStandard>,
+    standard: BadgeStandard,
+) -> Result<()> {
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_standard = global_config.badge_standard;
+    global_config.badge_standard = standard;
+
+    // --- Emit Event ---
+    emit!(BadgeStandardChanged {
+        previous_standard,
+        badge_standard: standard,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Badge standard changed to {:?}", standard);
+    Ok(())
+}
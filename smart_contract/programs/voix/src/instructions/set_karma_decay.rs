@@ -0,0 +1,42 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::KarmaDecayChanged;
+use crate::state::{GlobalConfig, KarmaDecay};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetKarmaDecay<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to choose how karma decays over time.
+/// Unsettled accounts decay at the new rate for the whole time since their last update.
+pub fn set_karma_decay_handler(ctx: Context<SetKarmaDecay>, decay: KarmaDecay) -> Result<()> {
+    // --- Security Check ---
+    decay.validate()?;
+
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_decay = global_config.karma_decay;
+    global_config.karma_decay = decay;
+
+    // --- Emit Event ---
+    emit!(KarmaDecayChanged {
+        previous_decay,
+        karma_decay: decay,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Karma decay changed to {:?}", decay);
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::KarmaGiftRulesChanged;
+use crate::state::{GlobalConfig, KarmaGiftRules};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetKarmaGiftRules<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to set the daily cap and minimum balance for
+/// `gift_karma`. Karma already gifted today keeps counting against the new cap.
+pub fn set_karma_gift_rules_handler(
+    ctx: Context<SetKarmaGiftRules>,
+    rules: KarmaGiftRules,
+) -> Result<()> {
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_rules = global_config.karma_gift_rules;
+    global_config.karma_gift_rules = rules;
+
+    // --- Emit Event ---
+    emit!(KarmaGiftRulesChanged {
+        previous_rules,
+        karma_gift_rules: rules,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Karma gift rules changed to {:?}", rules);
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::KarmaLimitsChanged;
+use crate::state::{GlobalConfig, KarmaLimits};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetKarmaLimits<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to set the guard rails on karma writes.
+/// Karma already gained this epoch keeps counting against the new limits.
+pub fn set_karma_limits_handler(ctx: Context<SetKarmaLimits>, limits: KarmaLimits) -> Result<()> {
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_limits = global_config.karma_limits;
+    global_config.karma_limits = limits;
+
+    // --- Emit Event ---
+    emit!(KarmaLimitsChanged {
+        previous_limits,
+        karma_limits: limits,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Karma limits changed to {:?}", limits);
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use crate::constants::{CONFIG_SEED, PAUSE_ALL};
+use crate::errors::VoixError;
+use crate::events::PauseChanged;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(paused_flags: u8)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+
+    // Deliberately not blocked by council mode: pausing must stay a one-signature action.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = pauser @ VoixError::MissingPauserRole
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is a pauser-only function that replaces the whole pause bitmask.
+/// Pass 0 to resume everything.
+pub fn set_paused_handler(ctx: Context<SetPaused>, paused_flags: u8) -> Result<()> {
+    // --- Security Check ---
+    require!(paused_flags & !PAUSE_ALL == 0, VoixError::InvalidPauseFlags);
+
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_flags = global_config.paused_flags;
+    global_config.paused_flags = paused_flags;
+
+    // --- Emit Event ---
+    emit!(PauseChanged {
+        previous_flags,
+        paused_flags,
+        pauser: ctx.accounts.pauser.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Pause flags changed from {:#06b} to {:#06b}",
+        previous_flags,
+        paused_flags
+    );
+    Ok(())
+}
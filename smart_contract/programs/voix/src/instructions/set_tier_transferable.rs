@@ -0,0 +1,53 @@
+use crate::constants::{CONFIG_SEED, MILESTONE_REGISTRY_SEED};
+use crate::errors::VoixError;
+use crate::events::MilestoneTransferableChanged;
+use crate::state::{GlobalConfig, MilestoneRegistry};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTierTransferable<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+}
+
+/// This is an admin-only function to choose whether future badges of a tier
+/// are frozen (soulbound) or left transferable. Already-minted badges keep their state.
+pub fn set_tier_transferable_handler(
+    ctx: Context<SetTierTransferable>,
+    level: u8,
+    transferable: bool,
+) -> Result<()> {
+    // --- Update State ---
+    ctx.accounts
+        .milestone_registry
+        .set_transferable(level, transferable)?;
+
+    // --- Emit Event ---
+    emit!(MilestoneTransferableChanged {
+        level,
+        transferable,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Milestone tier {} is now {}",
+        level,
+        if transferable { "transferable" } else { "soulbound" }
+    );
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use crate::constants::CONFIG_SEED;
+use crate::errors::VoixError;
+use crate::events::TipKarmaRuleChanged;
+use crate::state::{GlobalConfig, TipKarmaRule};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetTipKarmaRule<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+}
+
+/// This is an admin-only function to set when tips earn the receiver karma.
+/// Credits already used this epoch keep counting against the new per-pair cap.
+pub fn set_tip_karma_rule_handler(ctx: Context<SetTipKarmaRule>, rule: TipKarmaRule) -> Result<()> {
+    // --- Update State ---
+    let global_config = &mut ctx.accounts.global_config;
+    let previous_rule = global_config.tip_karma_rule;
+    global_config.tip_karma_rule = rule;
+
+    // --- Emit Event ---
+    emit!(TipKarmaRuleChanged {
+        previous_rule,
+        tip_karma_rule: rule,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Tip karma rule changed to {:?}", rule);
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_KARMA, USER_SEED};
+use crate::events::KarmaSettled;
+use crate::state::{GlobalConfig, KarmaHistory, UserAccount};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleKarma<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// CHECK: This is the pubkey of the user we are settling.
+    /// It's not a signer, just an address used to find the `user_account` PDA.
+    pub user: UncheckedAccount<'info>,
+}
+
+/// A permissionless crank that writes a user's decayed karma to their account,
+/// so the stored value (and anything indexing it) catches up with the decay.
+pub fn settle_karma_handler(ctx: Context<SettleKarma>) -> Result<()> {
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+
+    let user_account = &mut ctx.accounts.user_account;
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // --- Update State ---
+    let old_karma = ctx.accounts.global_config.settle_karma(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        timestamp,
+    )?;
+
+    // --- Emit Event ---
+    emit!(KarmaSettled {
+        user: user_account.user_pubkey,
+        old_karma,
+        new_karma: user_account.karma,
+        timestamp,
+    });
+
+    msg!(
+        "Karma settled for user: {}. Karma: {} -> {}",
+        user_account.user_pubkey,
+        old_karma,
+        user_account.karma
+    );
+    Ok(())
+}
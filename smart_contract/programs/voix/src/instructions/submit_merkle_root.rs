@@ -1,42 +1,82 @@
-use crate::constants::CONFIG_SEED;
+use crate::constants::{CONFIG_SEED, MAX_TREE_URI_LEN, MERKLE_EPOCH_SEED, MERKLE_EPOCH_VERSION};
 use crate::errors::VoixError;
 use crate::events::MerkleRootSubmitted;
-use crate::state::GlobalConfig;
+use crate::state::{GlobalConfig, MerkleEpoch};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 #[instruction(merkle_root: [u8; 32], epoch: u64)] // Make instruction args available
 pub struct SubmitMerkleRoot<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub merkle_publisher: Signer<'info>,
 
     #[account(
         mut,
         seeds = [CONFIG_SEED],
         bump,
-        has_one = admin @ VoixError::Unauthorized
+        has_one = merkle_publisher @ VoixError::MissingMerklePublisherRole,
+        // Ensure the new epoch is strictly greater than the current one.
+        // Checked here so a replayed epoch fails before `merkle_epoch` is created.
+        constraint = epoch > global_config.epoch @ VoixError::InvalidEpoch
     )]
     pub global_config: Account<'info, GlobalConfig>,
+
+    // A permanent copy of this epoch's root. `init` also stops an epoch
+    // from being submitted twice.
+    #[account(
+        init,
+        payer = merkle_publisher,
+        space = 8 + MerkleEpoch::INIT_SPACE,
+        seeds = [MERKLE_EPOCH_SEED, epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub merkle_epoch: Account<'info, MerkleEpoch>,
+
+    pub system_program: Program<'info, System>,
 }
 
-/// This is an admin-only function to update the on-chain Merkle root.
-pub fn handler(ctx: Context<SubmitMerkleRoot>, merkle_root: [u8; 32], epoch: u64) -> Result<()> {
+/// This is a Merkle-publisher-only function to update the on-chain Merkle root.
+/// The root is also kept in a `MerkleEpoch` account for that epoch.
+pub fn handler(
+    ctx: Context<SubmitMerkleRoot>,
+    merkle_root: [u8; 32],
+    epoch: u64,
+    leaf_count: u64,
+    tree_uri: Option<String>,
+) -> Result<()> {
     let global_config = &mut ctx.accounts.global_config;
 
     // --- Security Check ---
-    // Ensure the new epoch is strictly greater than the current one.
-    require!(epoch > global_config.epoch, VoixError::InvalidEpoch);
+    if let Some(tree_uri) = &tree_uri {
+        require!(
+            tree_uri.len() <= MAX_TREE_URI_LEN,
+            VoixError::TreeUriTooLong
+        );
+    }
 
     // --- Update State ---
     global_config.merkle_root = merkle_root;
     global_config.epoch = epoch;
 
+    let clock = Clock::get()?;
+    ctx.accounts.merkle_epoch.set_inner(MerkleEpoch {
+        version: MERKLE_EPOCH_VERSION,
+        epoch,
+        merkle_root,
+        leaf_count,
+        publisher: ctx.accounts.merkle_publisher.key(),
+        submitted_slot: clock.slot,
+        submitted_at: clock.unix_timestamp,
+        tree_uri,
+    });
+
     // --- Emit Event ---
     emit!(MerkleRootSubmitted {
-        admin: ctx.accounts.admin.key(),
+        publisher: ctx.accounts.merkle_publisher.key(),
         merkle_root,
         epoch,
-        timestamp: Clock::get()?.unix_timestamp,
+        leaf_count,
+        timestamp: clock.unix_timestamp,
     });
 
     msg!(
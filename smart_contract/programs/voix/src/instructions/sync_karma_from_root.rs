@@ -0,0 +1,107 @@
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, KARMA_LEAF_PREFIX, MAX_MERKLE_PROOF_LEN, PAUSE_KARMA, USER_SEED};
+use crate::errors::VoixError;
+use crate::events::KarmaUpdated;
+use crate::state::{GlobalConfig, KarmaChange, KarmaHistory, UserAccount};
+use crate::utils::verify_merkle_proof;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+#[derive(Accounts)]
+pub struct SyncKarmaFromRoot<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+}
+
+/// Lets a user set their karma from the `(user, karma, epoch)` leaf the Merkle
+/// publisher committed to in the current root. Each epoch can be synced once.
+pub fn handler(ctx: Context<SyncKarmaFromRoot>, karma: u32, proof: Vec<[u8; 32]>) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+    require!(
+        proof.len() <= usize::from(MAX_MERKLE_PROOF_LEN),
+        VoixError::MerkleProofTooLong
+    );
+
+    let global_config = &mut ctx.accounts.global_config;
+    let user_account = &mut ctx.accounts.user_account;
+    let epoch = global_config.epoch;
+    require!(
+        epoch > user_account.karma_synced_epoch,
+        VoixError::KarmaAlreadySynced
+    );
+
+    let leaf = hashv(&[
+        KARMA_LEAF_PREFIX,
+        ctx.accounts.user.key().as_ref(),
+        &karma.to_le_bytes(),
+        &epoch.to_le_bytes(),
+    ])
+    .to_bytes();
+    require!(
+        verify_merkle_proof(&proof, &global_config.merkle_root, leaf),
+        VoixError::InvalidMerkleProof
+    );
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    global_config.track_karma_increase(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        karma,
+        timestamp,
+    )?;
+
+    // --- Update State ---
+    let old_karma = user_account.karma;
+    user_account.karma = karma;
+    user_account.karma_synced_epoch = epoch;
+    KarmaHistory::record(
+        ctx.accounts.karma_history.as_deref_mut(),
+        user_account,
+        KarmaChange {
+            old_karma,
+            new_karma: karma,
+            reason: None,
+            signer: global_config.merkle_publisher,
+            timestamp,
+        },
+    )?;
+
+    // --- Emit Event ---
+    emit!(KarmaUpdated {
+        user: user_account.user_pubkey,
+        old_karma,
+        new_karma: karma,
+        reason: None,
+        oracle: global_config.merkle_publisher,
+        timestamp,
+    });
+
+    msg!(
+        "Karma synced from epoch {} root for user: {}. New karma: {}",
+        epoch,
+        user_account.user_pubkey,
+        karma
+    );
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::constants::{CONFIG_SEED, MINT_AUTHORITY_SEED};
+use crate::errors::VoixError;
+use crate::events::BadgeThawed;
+use crate::state::GlobalConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{thaw_delegated_account, ThawDelegatedAccount};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(Accounts)]
+pub struct ThawBadge<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub badge: ThawBadgeTarget<'info>,
+}
+
+/// The badge `thaw_badge` works on. `CouncilAction::ThawBadge` reads the same
+/// accounts from the `remaining_accounts` of `execute_council_proposal`.
+#[derive(Accounts)]
+pub struct ThawBadgeTarget<'info> {
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    // Only badges frozen by `mint_milestone_nft` have the PDA as delegate.
+    #[account(
+        mut,
+        token::mint = mint,
+        constraint = token_account.is_frozen() @ VoixError::BadgeNotFrozen,
+        constraint = token_account.delegate == Some(mint_authority.key()).into() @ VoixError::BadgeNotFrozen
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: The badge's master edition, checked by Token Metadata.
+    #[account(
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref(),
+            b"edition"
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub master_edition_account: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> ThawBadgeTarget<'info> {
+    /// Thaws the badge through its delegate, the mint authority PDA.
+    /// `authority` is the admin or the council, for the event.
+    pub fn thaw(&self, bumps: &ThawBadgeTargetBumps, authority: Pubkey) -> Result<()> {
+        let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[bumps.mint_authority]]];
+
+        thaw_delegated_account(CpiContext::new_with_signer(
+            self.token_metadata_program.to_account_info(),
+            ThawDelegatedAccount {
+                metadata: self.token_metadata_program.to_account_info(),
+                delegate: self.mint_authority.to_account_info(),
+                token_account: self.token_account.to_account_info(),
+                edition: self.master_edition_account.to_account_info(),
+                mint: self.mint.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+            authority_seeds,
+        ))?;
+
+        // --- Emit Event ---
+        emit!(BadgeThawed {
+            mint: self.mint.key(),
+            token_account: self.token_account.key(),
+            admin: authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Badge thawed: {}", self.mint.key());
+        Ok(())
+    }
+}
+
+/// This is an admin-only function to unfreeze a soulbound milestone badge,
+/// e.g. when a user needs to move it to a new wallet.
+pub fn thaw_badge_handler(ctx: Context<ThawBadge>) -> Result<()> {
+    ctx.accounts
+        .badge
+        .thaw(&ctx.bumps.badge, ctx.accounts.admin.key())
+}
@@ -1,7 +1,9 @@
-use crate::constants::USER_SEED;
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_TIPS, TIP_KARMA_SEED, USER_SEED};
 use crate::errors::VoixError;
 use crate::events::UserTipped;
-use crate::state::UserAccount;
+use crate::state::{
+    GlobalConfig, KarmaChange, KarmaHistory, KarmaReason, TipKarmaCredit, UserAccount,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
@@ -11,6 +13,13 @@ pub struct TipUserSpl<'info> {
     #[account(mut)]
     pub tipper: Signer<'info>,
 
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     /// CHECK: This is a raw Pubkey. We validate it against the `receiver_account`.
     #[account(
         address = receiver_account.user_pubkey
@@ -18,11 +27,41 @@ pub struct TipUserSpl<'info> {
     pub receiver: UncheckedAccount<'info>,
 
     #[account(
+        mut,
         seeds = [USER_SEED, receiver.key().as_ref()],
         bump
     )]
     pub receiver_account: Account<'info, UserAccount>,
 
+    // Optional: the tipper's own account, to count the tip towards `tips_sent`.
+    // Left out for self-tips, where it would be the same account as `receiver_account`.
+    #[account(
+        mut,
+        seeds = [USER_SEED, tipper.key().as_ref()],
+        bump,
+        constraint = tipper.key() != receiver.key() @ VoixError::SelfTipNotCounted
+    )]
+    pub tipper_account: Option<Account<'info, UserAccount>>,
+
+    // Optional: the pair's tip karma credit, needed when the tip earns the receiver
+    // karma (see `GlobalConfig.tip_karma_rule`). Created by the first such tip.
+    #[account(
+        init_if_needed,
+        payer = tipper,
+        space = 8 + TipKarmaCredit::INIT_SPACE,
+        seeds = [TIP_KARMA_SEED, tipper.key().as_ref(), receiver.key().as_ref()],
+        bump
+    )]
+    pub tip_karma_credit: Option<Account<'info, TipKarmaCredit>>,
+
+    /// Required when the tip earns karma and the receiver has a `KarmaHistory`.
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, receiver.key().as_ref()],
+        bump
+    )]
+    pub receiver_karma_history: Option<Account<'info, KarmaHistory>>,
+
     pub mint: Account<'info, Mint>,
 
     #[account(
@@ -47,6 +86,7 @@ pub struct TipUserSpl<'info> {
 
 pub fn handler(ctx: Context<TipUserSpl>, amount: u64) -> Result<()> {
     // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_TIPS)?;
     require!(amount > 0, VoixError::InvalidTipAmount);
 
     // --- Create and Make the CPI Call ---
@@ -62,13 +102,65 @@ pub fn handler(ctx: Context<TipUserSpl>, amount: u64) -> Result<()> {
         amount,
     )?;
 
+    // --- Update State ---
+    if let Some(tipper_account) = &mut ctx.accounts.tipper_account {
+        tipper_account.tips_sent = tipper_account
+            .tips_sent
+            .checked_add(1)
+            .ok_or(VoixError::MathOverflow)?;
+    }
+
+    // Tips from another registered user may earn the receiver karma.
+    let tipper = ctx.accounts.tipper.key();
+    let timestamp = Clock::get()?.unix_timestamp;
+    let mut karma_credited = 0;
+    if ctx.accounts.tipper_account.is_some()
+        && ctx
+            .accounts
+            .global_config
+            .tip_karma_rule
+            .qualifies(amount, Some(ctx.accounts.mint.key()))
+    {
+        let global_config = &mut ctx.accounts.global_config;
+        let receiver_account = &mut ctx.accounts.receiver_account;
+        let tip_karma_credit = ctx
+            .accounts
+            .tip_karma_credit
+            .as_deref_mut()
+            .ok_or(VoixError::MissingTipKarmaCredit)?;
+        tip_karma_credit.tipper = tipper;
+        tip_karma_credit.receiver = receiver_account.user_pubkey;
+
+        let old_karma = global_config.effective_karma(receiver_account, timestamp);
+        karma_credited = global_config.credit_tip_karma(
+            receiver_account,
+            ctx.accounts.receiver_karma_history.as_deref_mut(),
+            tip_karma_credit,
+            timestamp,
+        )?;
+        if karma_credited > 0 {
+            KarmaHistory::record(
+                ctx.accounts.receiver_karma_history.as_deref_mut(),
+                receiver_account,
+                KarmaChange {
+                    old_karma,
+                    new_karma: receiver_account.karma,
+                    reason: Some(KarmaReason::Tip),
+                    signer: tipper,
+                    timestamp,
+                },
+            )?;
+        }
+    }
+
     // --- Emit Event ---
     emit!(UserTipped {
-        tipper: ctx.accounts.tipper.key(),
+        tipper,
         receiver: ctx.accounts.receiver.key(),
         amount,
         mint: Some(ctx.accounts.mint.key()), // `Some` with the mint Pubkey
-        timestamp: Clock::get()?.unix_timestamp,
+        karma_credited,
+        timestamp,
     });
 
     msg!(
@@ -0,0 +1,150 @@
+use crate::constants::{CONFIG_SEED, MINT_AUTHORITY_SEED};
+use crate::errors::VoixError;
+use crate::events::BadgeMetadataUpdated;
+use crate::state::GlobalConfig;
+use crate::utils::{rewrite_badge_metadata, rewrite_badge_metadata_2022};
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+
+#[derive(Accounts)]
+pub struct UpdateBadgeMetadata<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    pub badge: BadgeMetadataTarget<'info>,
+}
+
+/// The badge `update_badge_metadata` rewrites. `CouncilAction::UpdateBadgeMetadata`
+/// reads the same accounts from the `remaining_accounts` of `execute_council_proposal`.
+#[derive(Accounts)]
+pub struct BadgeMetadataTarget<'info> {
+    // Pays for the extra rent when a Token-2022 badge's metadata grows.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The badge mint. A Token-2022 badge keeps its metadata in here and
+    /// is checked in `rewrite_badge_metadata_2022`; otherwise only used for seeds.
+    #[account(mut)]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Deserialized and checked in `rewrite_badge_metadata` (Metaplex badges only).
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata_account: Option<UncheckedAccount<'info>>,
+
+    /// Required for Token-2022 badges.
+    pub token_program: Option<Program<'info, Token2022>>,
+    pub system_program: Program<'info, System>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+impl<'info> BadgeMetadataTarget<'info> {
+    /// Rewrites the badge's metadata; `None` keeps the current value.
+    /// `authority` is the admin or the council, for the event.
+    pub fn rewrite(
+        &self,
+        bumps: &BadgeMetadataTargetBumps,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        // Token-2022 badges carry their metadata in the mint, Metaplex badges in a separate account.
+        let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[bumps.mint_authority]]];
+        let mint = self.mint.key();
+        let (name, symbol, uri) = if *self.mint.owner == Token2022::id() {
+            let token_program = self
+                .token_program
+                .as_ref()
+                .ok_or(VoixError::InvalidBadgeMetadata)?;
+            let data = rewrite_badge_metadata_2022(
+                &self.mint.to_account_info(),
+                &self.mint_authority.to_account_info(),
+                &self.payer.to_account_info(),
+                &token_program.to_account_info(),
+                &self.system_program.to_account_info(),
+                authority_seeds,
+                name.as_ref(),
+                symbol.as_ref(),
+                uri.as_ref(),
+            )?;
+            (data.name, data.symbol, data.uri)
+        } else {
+            let metadata_account = self
+                .metadata_account
+                .as_ref()
+                .ok_or(VoixError::InvalidBadgeMetadata)?;
+            let (_, data) = rewrite_badge_metadata(
+                &metadata_account.to_account_info(),
+                &self.mint_authority.to_account_info(),
+                &self.token_metadata_program,
+                authority_seeds,
+                name.as_ref(),
+                symbol.as_ref(),
+                uri.as_ref(),
+            )?;
+            (data.name, data.symbol, data.uri)
+        };
+
+        emit!(BadgeMetadataUpdated {
+            mint,
+            name,
+            symbol,
+            uri,
+            admin: authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Badge metadata updated: {}", mint);
+        Ok(())
+    }
+}
+
+/// This is an admin-only function to rewrite the metadata of one minted badge
+/// (e.g. after moving the metadata host). `None` keeps the current value.
+pub fn update_badge_metadata_handler(
+    ctx: Context<UpdateBadgeMetadata>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    // --- Security Check ---
+    require!(
+        name.is_some() || symbol.is_some() || uri.is_some(),
+        VoixError::EmptyMetadataUpdate
+    );
+
+    // --- Rewrite Metadata ---
+    ctx.accounts.badge.rewrite(
+        &ctx.bumps.badge,
+        name,
+        symbol,
+        uri,
+        ctx.accounts.admin.key(),
+    )
+}
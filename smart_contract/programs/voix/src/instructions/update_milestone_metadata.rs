@@ -0,0 +1,54 @@
+use crate::constants::{CONFIG_SEED, MILESTONE_REGISTRY_SEED};
+use crate::errors::VoixError;
+use crate::events::MilestoneMetadataUpdated;
+use crate::state::{GlobalConfig, MilestoneRegistry};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(level: u8, name: String, symbol: String, uri: String)]
+pub struct UpdateMilestoneMetadata<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+}
+
+/// This is an admin-only function to change the canonical name, symbol and URI
+/// used for future mints of a milestone tier.
+pub fn update_milestone_metadata_handler(
+    ctx: Context<UpdateMilestoneMetadata>,
+    level: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    // --- Update State ---
+    ctx.accounts
+        .milestone_registry
+        .set_metadata(level, name.clone(), symbol.clone(), uri.clone())?;
+
+    // --- Emit Event ---
+    emit!(MilestoneMetadataUpdated {
+        level,
+        name,
+        symbol,
+        uri,
+        admin: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Badge metadata updated for milestone tier {}", level);
+    Ok(())
+}
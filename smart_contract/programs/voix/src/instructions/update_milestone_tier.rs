@@ -0,0 +1,49 @@
+use crate::constants::{CONFIG_SEED, MILESTONE_REGISTRY_SEED};
+use crate::errors::VoixError;
+use crate::events::MilestoneTierChanged;
+use crate::state::{GlobalConfig, MilestoneRegistry, MilestoneTier};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(tier: MilestoneTier)]
+pub struct UpdateMilestoneTier<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        has_one = admin @ VoixError::Unauthorized,
+        constraint = !global_config.council_enabled @ VoixError::CouncilModeActive
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+}
+
+/// This is an admin-only function to edit an existing milestone tier.
+pub fn update_milestone_tier_handler(
+    ctx: Context<UpdateMilestoneTier>,
+    tier: MilestoneTier,
+) -> Result<()> {
+    // --- Update State ---
+    let event = MilestoneTierChanged {
+        level: tier.level,
+        karma_required: tier.karma_required,
+        flag_index: tier.flag_index,
+        retired: tier.retired,
+        authority: ctx.accounts.admin.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+    ctx.accounts.milestone_registry.update_tier(tier)?;
+
+    // --- Emit Event ---
+    emit!(event);
+
+    msg!("Milestone tier {} saved", event.level);
+    Ok(())
+}
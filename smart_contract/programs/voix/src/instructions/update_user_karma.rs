@@ -1,19 +1,20 @@
-use crate::constants::{CONFIG_SEED, USER_SEED};
+use crate::constants::{CONFIG_SEED, KARMA_HISTORY_SEED, PAUSE_KARMA, USER_SEED};
 use crate::errors::VoixError;
 use crate::events::KarmaUpdated;
-use crate::state::{GlobalConfig, UserAccount};
+use crate::state::{GlobalConfig, KarmaChange, KarmaHistory, UserAccount};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 #[instruction(new_karma: u32)]
 pub struct UpdateUserKarma<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub karma_oracle: Signer<'info>,
    
     #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump,
-        has_one = admin @ VoixError::Unauthorized
+        has_one = karma_oracle @ VoixError::MissingKarmaOracleRole
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
@@ -24,25 +25,58 @@ pub struct UpdateUserKarma<'info> {
     )]
     pub user_account: Account<'info, UserAccount>,
 
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user_to_update.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
     /// CHECK: This is the pubkey of the user we are targeting.
     /// It's not a signer, just an address used to find the `user_account` PDA.
     pub user_to_update: UncheckedAccount<'info>,
 }
 
-/// This is an admin-only function to update a specific user's on-chain karma
+/// This is a karma-oracle-only function to update a specific user's on-chain karma
 pub fn handler(ctx: Context<UpdateUserKarma>, new_karma: u32) -> Result<()> {
+    // --- Security Check ---
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+
     let user_account = &mut ctx.accounts.user_account;
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.global_config.track_karma_increase(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        new_karma,
+        timestamp,
+    )?;
+    let old_karma = user_account.karma; // After decay
 
     // --- Update State ---
     user_account.karma = new_karma;
+    user_account.supersede_karma_claims(ctx.accounts.global_config.epoch)?;
+    KarmaHistory::record(
+        ctx.accounts.karma_history.as_deref_mut(),
+        user_account,
+        KarmaChange {
+            old_karma,
+            new_karma,
+            reason: None,
+            signer: ctx.accounts.karma_oracle.key(),
+            timestamp,
+        },
+    )?;
 
     // --- Emit Event ---
     // This logs the change, so your backend can confirm the sync
     emit!(KarmaUpdated {
         user: user_account.user_pubkey, // The user who was updated
+        old_karma,
         new_karma,
-        admin: ctx.accounts.admin.key(), // The admin who did the update
-        timestamp: Clock::get()?.unix_timestamp,
+        reason: None,
+        oracle: ctx.accounts.karma_oracle.key(), // The oracle who did the update
+        timestamp,
     });
 
     msg!(
@@ -0,0 +1,240 @@
+use crate::constants::{
+    CONFIG_SEED, KARMA_HISTORY_SEED, MILESTONE_RECORD_SEED, MILESTONE_REGISTRY_SEED,
+    MINT_AUTHORITY_SEED, PAUSE_KARMA, PAUSE_MINTS, USER_SEED,
+};
+use crate::errors::VoixError;
+use crate::events::{MilestoneNftMinted, ReputationBadgeUpgraded};
+use crate::state::{
+    BadgeMode, GlobalConfig, KarmaHistory, MilestoneRecord, MilestoneRegistry, UserAccount,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{update_metadata_accounts_v2, UpdateMetadataAccountsV2};
+use anchor_spl::token::TokenAccount;
+use mpl_token_metadata::types::{Collection, Creator, DataV2};
+
+#[derive(Accounts)]
+#[instruction(milestone_level: u8)]
+pub struct UpgradeReputationBadge<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = global_config.badge_mode == BadgeMode::Reputation @ VoixError::WrongBadgeMode
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [MILESTONE_REGISTRY_SEED],
+        bump
+    )]
+    pub milestone_registry: Box<Account<'info, MilestoneRegistry>>,
+
+    #[account(
+        mut,
+        seeds = [USER_SEED, user.key().as_ref()],
+        bump,
+        has_one = badge_mint @ VoixError::ReputationBadgeMismatch,
+        // Checked before the level's `milestone_record` is initialized below.
+        constraint = milestone_registry
+            .tier(milestone_level)
+            .filter(|tier| user_account.minted_milestones & tier.flag() != 0)
+            .is_none()
+            @ VoixError::MilestoneAlreadyMinted
+    )]
+    pub user_account: Account<'info, UserAccount>,
+
+    /// Required once the user has created one (`UserAccount.has_karma_history`).
+    #[account(
+        mut,
+        seeds = [KARMA_HISTORY_SEED, user.key().as_ref()],
+        bump
+    )]
+    pub karma_history: Option<Account<'info, KarmaHistory>>,
+
+    /// CHECK: This is a PDA, not a traditional account.
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the reputation badge recorded on the user's account.
+    pub badge_mint: UncheckedAccount<'info>,
+
+    // The user must still hold the badge they are upgrading.
+    #[account(
+        token::mint = badge_mint,
+        token::authority = user,
+        constraint = badge_token_account.amount == 1 @ VoixError::ReputationBadgeMismatch
+    )]
+    pub badge_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: The badge's metadata account, rewritten via CPI.
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            token_metadata_program.key().as_ref(),
+            badge_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    // Each level reached gets its own record, all pointing at the one badge mint.
+    // A revoked record is reused when the level is reached again.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + MilestoneRecord::INIT_SPACE,
+        seeds = [MILESTONE_RECORD_SEED, user.key().as_ref(), &[milestone_level]],
+        bump,
+        constraint = milestone_record.version == 0 || milestone_record.revoked
+            @ VoixError::MilestoneAlreadyMinted
+    )]
+    pub milestone_record: Box<Account<'info, MilestoneRecord>>,
+
+    pub system_program: Program<'info, System>,
+    /// The Metaplex Token Metadata Program.
+    /// CHECK: We provide this as an UncheckedAccount or Program.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Rewrites the user's single reputation badge to show a higher milestone tier.
+/// No new mint is created, so the user pays rent only once. A lower level taken
+/// back by `revoke_milestone` is restored the same way, without rewriting the badge.
+pub fn upgrade_reputation_badge_handler(
+    ctx: Context<UpgradeReputationBadge>,
+    milestone_level: u8,
+) -> Result<()> {
+    // --- Security Check ---
+    // Upgrading saves the user's decayed karma, so it also stops while karma writes are paused.
+    ctx.accounts.global_config.require_not_paused(PAUSE_MINTS)?;
+    ctx.accounts.global_config.require_not_paused(PAUSE_KARMA)?;
+
+    let registry = &ctx.accounts.milestone_registry;
+    let user_account = &mut ctx.accounts.user_account;
+    let tier = registry.mintable_tier(milestone_level)?.clone();
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    ctx.accounts.global_config.settle_karma(
+        user_account,
+        ctx.accounts.karma_history.as_deref_mut(),
+        timestamp,
+    )?;
+    require!(
+        user_account.karma >= tier.karma_required,
+        VoixError::InsufficientKarma
+    );
+    ctx.accounts
+        .global_config
+        .require_mint_cooldown(user_account, timestamp)?;
+
+    // Only move up: the new tier must need more karma than the one shown now,
+    // unless it's a revoked level below it (the badge keeps showing the higher tier).
+    let previous_level = user_account.badge_level;
+    let current_karma_required = registry
+        .tier(previous_level)
+        .map_or(0, |current| current.karma_required);
+    let restored = tier.karma_required <= current_karma_required;
+    require!(
+        !restored || ctx.accounts.milestone_record.revoked,
+        VoixError::InvalidBadgeUpgrade
+    );
+
+    if restored {
+        user_account.minted_milestones |= tier.flag();
+
+        let clock = Clock::get()?;
+        ctx.accounts.milestone_record.record_mint(
+            ctx.accounts.user.key(),
+            milestone_level,
+            ctx.accounts.badge_mint.key(),
+            clock.slot,
+            user_account.karma,
+        );
+
+        emit!(MilestoneNftMinted {
+            user: ctx.accounts.user.key(),
+            nft_mint: ctx.accounts.badge_mint.key(),
+            milestone_level,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Milestone {} restored on the level {} reputation badge",
+            milestone_level,
+            previous_level
+        );
+        return Ok(());
+    }
+
+    // --- Rewrite Metadata ---
+    // Creators and collection must be passed back unchanged (and still verified),
+    // otherwise Token Metadata rejects the update.
+    let authority_seeds: &[&[&[u8]]] = &[&[MINT_AUTHORITY_SEED, &[ctx.bumps.mint_authority]]];
+    let data_v2 = DataV2 {
+        name: tier.name.clone(),
+        symbol: tier.symbol.clone(),
+        uri: tier.uri.clone(),
+        seller_fee_basis_points: 0,
+        creators: Some(vec![Creator {
+            address: ctx.accounts.mint_authority.key(),
+            verified: true,
+            share: 100,
+        }]),
+        collection: Some(Collection {
+            verified: true,
+            key: ctx.accounts.global_config.badge_collection,
+        }),
+        uses: None,
+    };
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_metadata_program.to_account_info(),
+            UpdateMetadataAccountsV2 {
+                metadata: ctx.accounts.metadata_account.to_account_info(),
+                update_authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            authority_seeds,
+        ),
+        None,
+        Some(data_v2),
+        None,
+        None,
+    )?;
+
+    // --- Update State ---
+    user_account.minted_milestones |= tier.flag();
+    user_account.badge_level = milestone_level;
+
+    let clock = Clock::get()?;
+    ctx.accounts.milestone_record.record_mint(
+        ctx.accounts.user.key(),
+        milestone_level,
+        ctx.accounts.badge_mint.key(),
+        clock.slot,
+        user_account.karma,
+    );
+
+    // --- Emit Event ---
+    emit!(ReputationBadgeUpgraded {
+        user: ctx.accounts.user.key(),
+        nft_mint: ctx.accounts.badge_mint.key(),
+        previous_level,
+        new_level: milestone_level,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Reputation badge upgraded from level {} to level {}",
+        previous_level,
+        milestone_level
+    );
+    Ok(())
+}
@@ -5,6 +5,7 @@ pub mod errors;
 pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use constants::*;
 use errors::*;
@@ -23,21 +24,23 @@ pub mod voix {
         instructions::initialize_config::handler(ctx)
     }
 
-    /// Instruction for a new user to create their on-chain UserAccount PDA
+    /// Instruction for the user sponsor to create a new user's on-chain UserAccount PDA
     pub fn initialize_user(ctx: Context<InitializeUser>) -> Result<()> {
         instructions::initialize_user::handler(ctx)
     }
 
-    /// Instruction for the admin (backend) to submit the latest Merkle root of all off-chain content (posts, comments, etc.)
+    /// Instruction for the Merkle publisher (backend) to submit the latest Merkle root of all off-chain content (posts, comments, etc.)
     pub fn submit_merkle_root(
         ctx: Context<SubmitMerkleRoot>,
         merkle_root: [u8; 32],
         epoch: u64,
+        leaf_count: u64,
+        tree_uri: Option<String>,
     ) -> Result<()> {
-        instructions::submit_merkle_root::handler(ctx, merkle_root, epoch)
+        instructions::submit_merkle_root::handler(ctx, merkle_root, epoch, leaf_count, tree_uri)
     }
 
-    /// Instruction for the admin (backend) to update a user's on-chain karma score.
+    /// Instruction for the karma oracle (backend) to update a user's on-chain karma score.
     pub fn update_user_karma(ctx: Context<UpdateUserKarma>, new_karma: u32) -> Result<()> {
         instructions::update_user_karma::handler(ctx, new_karma)
     }
@@ -53,13 +56,300 @@ pub mod voix {
     }
 
     /// Instruction for a user to mint a milestone NFT (e.g., "Bronze Badge")
-    pub fn mint_milestone_nft(
-        ctx: Context<MintMilestoneNft>,
+    /// The badge metadata is taken from the milestone registry.
+    pub fn mint_milestone_nft(ctx: Context<MintMilestoneNft>, milestone_level: u8) -> Result<()> {
+        instructions::mint_milestone_nft::handler(ctx, milestone_level)
+    }
+
+    /// Instruction for the admin to nominate a new admin (step 1 of the handover).
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin::propose_admin_handler(ctx, new_admin)
+    }
+
+    /// Instruction for the nominated admin to accept the role (step 2 of the handover).
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin::accept_admin_handler(ctx)
+    }
+
+    /// Instruction for the admin to withdraw a pending admin nomination.
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        instructions::cancel_admin_proposal::cancel_admin_proposal_handler(ctx)
+    }
+
+    /// Instruction for the admin to assign a role (karma oracle, Merkle publisher, ...) to a key.
+    pub fn grant_role(ctx: Context<GrantRole>, role: Role, authority: Pubkey) -> Result<()> {
+        instructions::grant_role::grant_role_handler(ctx, role, authority)
+    }
+
+    /// Instruction for the admin to take a role away from its current holder.
+    pub fn revoke_role(ctx: Context<RevokeRole>, role: Role) -> Result<()> {
+        instructions::revoke_role::revoke_role_handler(ctx, role)
+    }
+
+    /// Instruction for the admin to hand configuration changes over to an M-of-N council.
+    pub fn enable_council(
+        ctx: Context<EnableCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::enable_council::enable_council_handler(ctx, members, threshold)
+    }
+
+    /// Instruction for a council member to propose a configuration change.
+    pub fn create_council_proposal(
+        ctx: Context<CreateCouncilProposal>,
+        action: CouncilAction,
+    ) -> Result<()> {
+        instructions::create_council_proposal::create_council_proposal_handler(ctx, action)
+    }
+
+    /// Instruction for a council member to approve a pending proposal.
+    pub fn approve_council_proposal(ctx: Context<ApproveCouncilProposal>) -> Result<()> {
+        instructions::approve_council_proposal::approve_council_proposal_handler(ctx)
+    }
+
+    /// Instruction to run a council proposal once it has enough approvals.
+    /// Badge actions take the badge's accounts in `remaining_accounts`.
+    pub fn execute_council_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteCouncilProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_council_proposal::execute_council_proposal_handler(ctx)
+    }
+
+    /// Instruction for the pauser to stop or resume tips, mints, onboarding or karma writes.
+    pub fn set_paused(ctx: Context<SetPaused>, paused_flags: u8) -> Result<()> {
+        instructions::set_paused::set_paused_handler(ctx, paused_flags)
+    }
+
+    /// Instruction for the admin to upgrade a pre-versioning GlobalConfig to the current layout.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config::migrate_config_handler(ctx)
+    }
+
+    /// Instruction for the admin or the user to upgrade an old UserAccount to the current layout.
+    pub fn migrate_user_account(ctx: Context<MigrateUserAccount>) -> Result<()> {
+        instructions::migrate_user_account::migrate_user_account_handler(ctx)
+    }
+
+    /// Instruction for the admin to create the milestone registry with the default tiers.
+    pub fn initialize_milestone_registry(ctx: Context<InitializeMilestoneRegistry>) -> Result<()> {
+        instructions::initialize_milestone_registry::initialize_milestone_registry_handler(ctx)
+    }
+
+    /// Instruction for the admin to add a new milestone tier.
+    pub fn add_milestone_tier(ctx: Context<AddMilestoneTier>, tier: MilestoneTier) -> Result<()> {
+        instructions::add_milestone_tier::add_milestone_tier_handler(ctx, tier)
+    }
+
+    /// Instruction for the admin to edit an existing milestone tier.
+    pub fn update_milestone_tier(
+        ctx: Context<UpdateMilestoneTier>,
+        tier: MilestoneTier,
+    ) -> Result<()> {
+        instructions::update_milestone_tier::update_milestone_tier_handler(ctx, tier)
+    }
+
+    /// Instruction for the admin to retire a milestone tier so it can no longer be minted.
+    pub fn retire_milestone_tier(ctx: Context<RetireMilestoneTier>, level: u8) -> Result<()> {
+        instructions::retire_milestone_tier::retire_milestone_tier_handler(ctx, level)
+    }
+
+    /// Instruction for the admin to change the canonical badge metadata of a milestone tier.
+    pub fn update_milestone_metadata(
+        ctx: Context<UpdateMilestoneMetadata>,
+        level: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::update_milestone_metadata::update_milestone_metadata_handler(
+            ctx, level, name, symbol, uri,
+        )
+    }
+
+    /// Instruction for the admin to create the Voix collection NFT that badges are verified into.
+    pub fn create_badge_collection(
+        ctx: Context<CreateBadgeCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::create_badge_collection::create_badge_collection_handler(
+            ctx, name, symbol, uri,
+        )
+    }
+
+    /// Instruction for the admin to choose whether a tier's badges are soulbound or transferable.
+    pub fn set_tier_transferable(
+        ctx: Context<SetTierTransferable>,
+        level: u8,
+        transferable: bool,
+    ) -> Result<()> {
+        instructions::set_tier_transferable::set_tier_transferable_handler(ctx, level, transferable)
+    }
+
+    /// Instruction for the admin to unfreeze a soulbound badge (support cases).
+    pub fn thaw_badge(ctx: Context<ThawBadge>) -> Result<()> {
+        instructions::thaw_badge::thaw_badge_handler(ctx)
+    }
+
+    /// Instruction for the admin to switch between per-milestone NFTs and a single reputation badge.
+    pub fn set_badge_mode(ctx: Context<SetBadgeMode>, mode: BadgeMode) -> Result<()> {
+        instructions::set_badge_mode::set_badge_mode_handler(ctx, mode)
+    }
+
+    /// Instruction for a user to move their reputation badge up to a higher milestone tier.
+    pub fn upgrade_reputation_badge(
+        ctx: Context<UpgradeReputationBadge>,
+        milestone_level: u8,
+    ) -> Result<()> {
+        instructions::upgrade_reputation_badge::upgrade_reputation_badge_handler(
+            ctx, milestone_level,
+        )
+    }
+
+    /// Instruction for the admin to rewrite the name, symbol or URI of an already-minted badge.
+    pub fn update_badge_metadata(
+        ctx: Context<UpdateBadgeMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::update_badge_metadata::update_badge_metadata_handler(ctx, name, symbol, uri)
+    }
+
+    /// Same as `update_badge_metadata`, for every badge metadata account in `remaining_accounts`.
+    pub fn batch_update_badge_metadata<'info>(
+        ctx: Context<'_, '_, '_, 'info, BatchUpdateBadgeMetadata<'info>>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::batch_update_badge_metadata::batch_update_badge_metadata_handler(
+            ctx, name, symbol, uri,
+        )
+    }
+
+    /// Instruction for the admin to take back a milestone (e.g. after a karma clawback).
+    pub fn revoke_milestone(
+        ctx: Context<RevokeMilestone>,
         milestone_level: u8,
+        reason_code: u16,
+    ) -> Result<()> {
+        instructions::revoke_milestone::revoke_milestone_handler(ctx, milestone_level, reason_code)
+    }
+
+    /// Instruction for the admin to choose between Metaplex NFTs and Token-2022 badges.
+    pub fn set_badge_standard(ctx: Context<SetBadgeStandard>, standard: BadgeStandard) -> Result<()> {
+        instructions::set_badge_standard::set_badge_standard_handler(ctx, standard)
+    }
+
+    /// Instruction for a user to mint a milestone badge as a Token-2022 mint with on-mint metadata.
+    pub fn mint_milestone_badge_2022(
+        ctx: Context<MintMilestoneBadge2022>,
+        milestone_level: u8,
+    ) -> Result<()> {
+        instructions::mint_milestone_badge_2022::mint_milestone_badge_2022_handler(
+            ctx, milestone_level,
+        )
+    }
+
+    /// Instruction for the admin to define a new achievement badge.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_achievement(
+        ctx: Context<CreateAchievement>,
+        achievement_id: u8,
+        criterion: AchievementCriterion,
+        threshold: u64,
         name: String,
         symbol: String,
         uri: String,
+        soulbound: bool,
+    ) -> Result<()> {
+        instructions::create_achievement::create_achievement_handler(
+            ctx,
+            achievement_id,
+            criterion,
+            threshold,
+            name,
+            symbol,
+            uri,
+            soulbound,
+        )
+    }
+
+    /// Instruction for a user to claim an achievement badge once its criterion is met.
+    pub fn claim_achievement(ctx: Context<ClaimAchievement>, achievement_id: u8) -> Result<()> {
+        instructions::claim_achievement::claim_achievement_handler(ctx, achievement_id)
+    }
+
+    /// Instruction for a user to redeem a karma voucher signed by the karma oracle.
+    pub fn claim_karma(ctx: Context<ClaimKarma>, voucher: KarmaVoucher) -> Result<()> {
+        instructions::claim_karma::handler(ctx, voucher)
+    }
+
+    /// Instruction for a user to set their karma from a Merkle proof against the published root.
+    pub fn sync_karma_from_root(
+        ctx: Context<SyncKarmaFromRoot>,
+        karma: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::sync_karma_from_root::handler(ctx, karma, proof)
+    }
+
+    /// Instruction for the karma oracle to add a signed delta to a user's karma.
+    pub fn apply_karma_delta(
+        ctx: Context<ApplyKarmaDelta>,
+        delta: i32,
+        reason: KarmaReason,
+        expected_seq: u64,
+    ) -> Result<()> {
+        instructions::apply_karma_delta::handler(ctx, delta, reason, expected_seq)
+    }
+
+    /// Same as `update_user_karma`, for every `UserAccount` passed in `remaining_accounts`.
+    pub fn batch_update_karma<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchUpdateKarma<'info>>,
+        entries: Vec<KarmaEntry>,
+    ) -> Result<()> {
+        instructions::batch_update_karma::handler(ctx, entries)
+    }
+
+    /// Instruction for the admin to set the per-epoch karma limits and the mint cooldown.
+    pub fn set_karma_limits(ctx: Context<SetKarmaLimits>, limits: KarmaLimits) -> Result<()> {
+        instructions::set_karma_limits::set_karma_limits_handler(ctx, limits)
+    }
+
+    /// Instruction for the admin to set how karma decays over time.
+    pub fn set_karma_decay(ctx: Context<SetKarmaDecay>, decay: KarmaDecay) -> Result<()> {
+        instructions::set_karma_decay::set_karma_decay_handler(ctx, decay)
+    }
+
+    /// Permissionless crank that stores a user's decayed karma.
+    pub fn settle_karma(ctx: Context<SettleKarma>) -> Result<()> {
+        instructions::settle_karma::settle_karma_handler(ctx)
+    }
+
+    /// Instruction for a user to start keeping their recent karma changes on-chain.
+    pub fn initialize_karma_history(ctx: Context<InitializeKarmaHistory>) -> Result<()> {
+        instructions::initialize_karma_history::initialize_karma_history_handler(ctx)
+    }
+
+    /// Instruction for the admin to set the daily cap and minimum balance for karma gifts.
+    pub fn set_karma_gift_rules(
+        ctx: Context<SetKarmaGiftRules>,
+        rules: KarmaGiftRules,
     ) -> Result<()> {
-        instructions::mint_milestone_nft::handler(ctx, milestone_level, name, symbol, uri)
+        instructions::set_karma_gift_rules::set_karma_gift_rules_handler(ctx, rules)
+    }
+
+    /// Instruction for a user to give part of their karma to another user.
+    pub fn gift_karma(ctx: Context<GiftKarma>, amount: u32) -> Result<()> {
+        instructions::gift_karma::gift_karma_handler(ctx, amount)
+    }
+
+    /// Instruction for the admin to set when tips earn the receiver karma.
+    pub fn set_tip_karma_rule(ctx: Context<SetTipKarmaRule>, rule: TipKarmaRule) -> Result<()> {
+        instructions::set_tip_karma_rule::set_tip_karma_rule_handler(ctx, rule)
     }
 }
@@ -0,0 +1,80 @@
+use crate::constants::{MAX_BADGE_NAME_LEN, MAX_BADGE_SYMBOL_LEN, MAX_BADGE_URI_LEN};
+use crate::errors::VoixError;
+use crate::state::UserAccount;
+use anchor_lang::prelude::*;
+
+/// An admin-defined badge that is earned by something other than a karma tier,
+/// e.g. "first tip sent" or "1-year member".
+/// PDA seeds: `[ACHIEVEMENT_SEED, achievement_id]`.
+#[account]
+#[derive(InitSpace)]
+pub struct Achievement {
+    /// The layout version of this account (see `ACHIEVEMENT_VERSION`).
+    pub version: u8, // 1 byte
+
+    /// The achievement's id, also its bit in `UserAccount.achievements` (0-63).
+    pub id: u8, // 1 byte
+
+    /// What the user must have done to claim it.
+    pub criterion: AchievementCriterion, // 1 byte
+
+    /// The value the criterion's counter must reach (unused for `Attestation`).
+    pub threshold: u64, // 8 bytes
+
+    /// Canonical badge metadata, used for every badge of this achievement.
+    #[max_len(MAX_BADGE_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_BADGE_SYMBOL_LEN)]
+    pub symbol: String,
+    #[max_len(MAX_BADGE_URI_LEN)]
+    pub uri: String,
+
+    /// Whether claimed badges are frozen in the user's wallet.
+    pub soulbound: bool, // 1 byte (taken from `reserved`)
+
+    /// Zeroed space kept for future fields.
+    pub reserved: [u8; 31], // 31 bytes
+}
+
+/// How an achievement is earned. The on-chain counters are compared against
+/// `Achievement.threshold`; anything tracked off-chain (e.g. "100 buzzes")
+/// uses `Attestation` instead.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AchievementCriterion {
+    /// `UserAccount.karma >= threshold`.
+    Karma,
+    /// `UserAccount.total_sol_tipped >= threshold` (lamports received).
+    SolReceived,
+    /// `UserAccount.tips_sent >= threshold`.
+    TipsSent,
+    /// The account is at least `threshold` seconds old.
+    AccountAge,
+    /// The karma oracle co-signs the claim.
+    Attestation,
+}
+
+impl Achievement {
+    /// The bit for this achievement in `UserAccount.achievements`.
+    pub fn flag(&self) -> u64 {
+        1u64 << self.id
+    }
+
+    /// Checks the on-chain criterion for `user`. `attested` is whether the
+    /// karma oracle signed the claim; it only matters for `Attestation`.
+    pub fn require_met(&self, user: &UserAccount, attested: bool, now: i64) -> Result<()> {
+        let met = match self.criterion {
+            AchievementCriterion::Karma => u64::from(user.karma) >= self.threshold,
+            AchievementCriterion::SolReceived => user.total_sol_tipped >= self.threshold,
+            AchievementCriterion::TipsSent => u64::from(user.tips_sent) >= self.threshold,
+            // Accounts created before `created_at` existed have no known age.
+            AchievementCriterion::AccountAge => {
+                user.created_at > 0
+                    && u64::try_from(now.saturating_sub(user.created_at))
+                        .is_ok_and(|age| age >= self.threshold)
+            }
+            AchievementCriterion::Attestation => attested,
+        };
+        require!(met, VoixError::AchievementCriterionNotMet);
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use crate::constants::{
+    MAX_BADGE_NAME_LEN, MAX_BADGE_SYMBOL_LEN, MAX_BADGE_URI_LEN, MAX_COUNCIL_MEMBERS,
+};
+use crate::errors::VoixError;
+use crate::state::{
+    AchievementCriterion, BadgeMode, BadgeStandard, KarmaDecay, KarmaGiftRules, KarmaLimits,
+    MilestoneTier, Role, TipKarmaRule,
+};
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Council {
+    /// The keys allowed to create and approve council proposals.
+    /// SET BY ADMIN using: `enable_council` instruction.
+    /// UPDATED BY COUNCIL using: `CouncilAction::UpdateCouncil`.
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub members: Vec<Pubkey>, // 4 + 32 * MAX_COUNCIL_MEMBERS bytes
+
+    /// How many member approvals a proposal needs before it can be executed.
+    pub threshold: u8, // 1 byte
+
+    /// A counter used to derive the next `CouncilProposal` PDA.
+    /// UPDATED BY PROGRAM during: `create_council_proposal` instruction.
+    pub proposal_count: u64, // 8 bytes
+
+    /// Bumped whenever the council is (re-)enabled or its members change,
+    /// so proposals made for an earlier council can't be approved or executed.
+    /// UPDATED BY PROGRAM during: `enable_council` and `CouncilAction::UpdateCouncil`.
+    pub generation: u64, // 8 bytes
+}
+
+impl Council {
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+
+    /// Starts a new council generation, invalidating every open proposal.
+    pub fn next_generation(&mut self) -> Result<()> {
+        self.generation = self
+            .generation
+            .checked_add(1)
+            .ok_or(VoixError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Checks a proposed member set: non-empty, bounded, no duplicates,
+    /// and a threshold between 1 and the number of members.
+    pub fn validate_members(members: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !members.is_empty() && members.len() <= MAX_COUNCIL_MEMBERS,
+            VoixError::InvalidCouncilConfig
+        );
+        require!(
+            threshold > 0 && threshold as usize <= members.len(),
+            VoixError::InvalidCouncilConfig
+        );
+        for (i, member) in members.iter().enumerate() {
+            require!(
+                *member != Pubkey::default() && !members[..i].contains(member),
+                VoixError::InvalidCouncilConfig
+            );
+        }
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CouncilProposal {
+    /// The sequential id this proposal was created with (also part of its PDA seeds).
+    pub id: u64, // 8 bytes
+
+    /// The council member who created the proposal.
+    pub proposer: Pubkey, // 32 bytes
+
+    /// The configuration change to run once enough members approve.
+    pub action: CouncilAction,
+
+    /// The members who approved so far (the proposer approves on creation).
+    /// UPDATED BY PROGRAM during: `approve_council_proposal` instruction.
+    #[max_len(MAX_COUNCIL_MEMBERS)]
+    pub approvals: Vec<Pubkey>, // 4 + 32 * MAX_COUNCIL_MEMBERS bytes
+
+    /// Whether the action has already been applied.
+    /// SET BY PROGRAM during: `execute_council_proposal` instruction.
+    pub executed: bool, // 1 byte
+
+    pub created_at: i64, // 8 bytes
+
+    /// The `Council.generation` the proposal was made for.
+    pub generation: u64, // 8 bytes
+
+    /// After this time the proposal can no longer be approved or executed
+    /// (`created_at + COUNCIL_PROPOSAL_LIFETIME_SECS`).
+    pub expires_at: i64, // 8 bytes
+}
+
+impl CouncilProposal {
+    /// Counts the approvals that still belong to current council members,
+    /// so removed members can't push a stale proposal over the threshold.
+    pub fn valid_approvals(&self, council: &Council) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approver| council.is_member(approver))
+            .count()
+    }
+
+    /// Fails if the proposal has expired or was made for an earlier council.
+    pub fn require_current(&self, council: &Council, now: i64) -> Result<()> {
+        require!(
+            self.generation == council.generation,
+            VoixError::ProposalStale
+        );
+        require!(now < self.expires_at, VoixError::ProposalExpired);
+        Ok(())
+    }
+}
+
+/// The sensitive configuration changes that go through the council
+/// while `GlobalConfig.council_enabled` is set.
+///
+/// Council mode policy: the admin key alone can no longer change the
+/// configuration, the badge definitions or anyone's karma or badges. Every
+/// admin instruction that does fails with `CouncilModeActive`, and the changes
+/// the council still needs are a variant here. The only admin instructions
+/// left to the single key are one-time setup that can't be redone once done.
+/// Pausing belongs to the pauser role and always stays a single signature.
+///
+/// Actions on a badge, a milestone or a new achievement take its accounts in
+/// the `remaining_accounts` of `execute_council_proposal`, in the order of the
+/// target accounts the admin instruction nests. `batch_update_badge_metadata`
+/// has no variant and stays unavailable in council mode: each badge takes its
+/// own `UpdateBadgeMetadata` proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Debug)]
+pub enum CouncilAction {
+    SetAdmin {
+        new_admin: Pubkey,
+    },
+    GrantRole {
+        role: Role,
+        authority: Pubkey,
+    },
+    RevokeRole {
+        role: Role,
+    },
+    UpdateCouncil {
+        #[max_len(MAX_COUNCIL_MEMBERS)]
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+    DisableCouncil,
+    AddMilestoneTier {
+        tier: MilestoneTier,
+    },
+    UpdateMilestoneTier {
+        tier: MilestoneTier,
+    },
+    RetireMilestoneTier {
+        level: u8,
+    },
+    SetTierTransferable {
+        level: u8,
+        transferable: bool,
+    },
+    UpdateMilestoneMetadata {
+        level: u8,
+        #[max_len(MAX_BADGE_NAME_LEN)]
+        name: String,
+        #[max_len(MAX_BADGE_SYMBOL_LEN)]
+        symbol: String,
+        #[max_len(MAX_BADGE_URI_LEN)]
+        uri: String,
+    },
+    SetBadgeMode {
+        mode: BadgeMode,
+    },
+    SetBadgeStandard {
+        standard: BadgeStandard,
+    },
+    SetKarmaLimits {
+        limits: KarmaLimits,
+    },
+    SetKarmaDecay {
+        decay: KarmaDecay,
+    },
+    SetKarmaGiftRules {
+        rules: KarmaGiftRules,
+    },
+    SetTipKarmaRule {
+        rule: TipKarmaRule,
+    },
+    /// Accounts: `ThawBadgeTarget`.
+    ThawBadge {
+        mint: Pubkey,
+    },
+    /// Accounts: `RevokeMilestoneTarget`.
+    RevokeMilestone {
+        user: Pubkey,
+        milestone_level: u8,
+        reason_code: u16,
+    },
+    /// Accounts: `NewAchievementTarget`, with the executor as payer.
+    CreateAchievement {
+        achievement_id: u8,
+        criterion: AchievementCriterion,
+        threshold: u64,
+        #[max_len(MAX_BADGE_NAME_LEN)]
+        name: String,
+        #[max_len(MAX_BADGE_SYMBOL_LEN)]
+        symbol: String,
+        #[max_len(MAX_BADGE_URI_LEN)]
+        uri: String,
+        soulbound: bool,
+    },
+    /// Accounts: `BadgeMetadataTarget`, with the executor as payer.
+    UpdateBadgeMetadata {
+        mint: Pubkey,
+        #[max_len(MAX_BADGE_NAME_LEN)]
+        name: Option<String>,
+        #[max_len(MAX_BADGE_SYMBOL_LEN)]
+        symbol: Option<String>,
+        #[max_len(MAX_BADGE_URI_LEN)]
+        uri: Option<String>,
+    },
+}
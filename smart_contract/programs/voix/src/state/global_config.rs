@@ -1,18 +1,459 @@
+use crate::constants::{PAUSE_KARMA, SECONDS_PER_DAY};
+use crate::errors::VoixError;
+use crate::state::{KarmaChange, KarmaHistory, KarmaReason, TipKarmaCredit, UserAccount};
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(InitSpace)]
 pub struct GlobalConfig {
+    /// The layout version of this account (see `CONFIG_VERSION`).
+    /// SET BY PROGRAM during: `initialize_config` / `migrate_config` instructions.
+    pub version: u8, // 1 byte
+
     /// The public key of your backend server.
-    /// This is the *only* key allowed to update karma or submit a new Merkle root.
-    /// SET ONCE by: `initialize_config` instruction.
+    /// This key manages the program configuration and hands out the roles below.
+    /// SET by: `initialize_config` instruction.
+    /// ROTATED using: `propose_admin` + `accept_admin` instructions.
     pub admin: Pubkey, // 32 bytes
 
     /// The 32-byte Merkle root hash of all off-chain content (posts/comments).
+    /// It may also commit to karma leaves (see `KARMA_LEAF_PREFIX`),
+    /// which users redeem with `sync_karma_from_root`.
+    /// Every root is also kept in the `MerkleEpoch` account of its epoch.
     /// UPDATED BY BACKEND using: `submit_merkle_root` instruction.
     pub merkle_root: [u8; 32], // 32 bytes
 
     /// A simple counter to track the current Merkle root version.
     /// UPDATED BY BACKEND using: `submit_merkle_root` instruction.
     pub epoch: u64, // 8 bytes
-}
\ No newline at end of file
+
+    /// The admin key nominated by the current admin, waiting to accept the handover.
+    /// `None` when no handover is in progress.
+    /// SET BY ADMIN using: `propose_admin` / `cancel_admin_proposal` instructions.
+    /// CLEARED BY NEW ADMIN using: `accept_admin` instruction.
+    pub pending_admin: Option<Pubkey>, // 1 + 32 bytes
+
+    /// The key allowed to write user karma.
+    /// `Pubkey::default()` means the role is currently unassigned.
+    /// SET BY ADMIN using: `grant_role` / `revoke_role` instructions.
+    pub karma_oracle: Pubkey, // 32 bytes
+
+    /// The key allowed to submit new Merkle roots.
+    /// SET BY ADMIN using: `grant_role` / `revoke_role` instructions.
+    pub merkle_publisher: Pubkey, // 32 bytes
+
+    /// The key that sponsors (pays rent for) new `UserAccount`s.
+    /// SET BY ADMIN using: `grant_role` / `revoke_role` instructions.
+    pub user_sponsor: Pubkey, // 32 bytes
+
+    /// The key allowed to pause and unpause the program.
+    /// SET BY ADMIN using: `grant_role` / `revoke_role` instructions.
+    pub pauser: Pubkey, // 32 bytes
+
+    /// When set, admin-only configuration changes must go through the
+    /// `Council` PDA (M-of-N approvals) instead of the single admin key.
+    /// SET BY ADMIN using: `enable_council` instruction.
+    /// CLEARED BY COUNCIL using: `CouncilAction::DisableCouncil`.
+    pub council_enabled: bool, // 1 byte
+
+    /// A bit-flag of the instruction groups that are currently stopped.
+    /// (e.g., 1 = Tips, 2 = Mints, 4 = Onboarding, 8 = Karma writes)
+    /// UPDATED BY PAUSER using: `set_paused` instruction.
+    pub paused_flags: u8, // 1 byte
+
+    /// The mint of the Voix collection NFT that every milestone badge is verified into.
+    /// `Pubkey::default()` until the collection has been created.
+    /// SET BY ADMIN using: `create_badge_collection` instruction.
+    pub badge_collection: Pubkey, // 32 bytes (taken from `reserved`)
+
+    /// Whether users collect one NFT per milestone or a single upgradeable badge.
+    /// SET BY ADMIN using: `set_badge_mode` instruction.
+    pub badge_mode: BadgeMode, // 1 byte (taken from `reserved`)
+
+    /// Which token program new badges are minted with.
+    /// SET BY ADMIN using: `set_badge_standard` instruction.
+    pub badge_standard: BadgeStandard, // 1 byte (taken from `reserved`)
+
+    /// Guard rails on karma writes, so a stolen oracle key can't max out karma
+    /// and mint every badge at once. All zero (no limits) by default.
+    /// SET BY ADMIN using: `set_karma_limits` instruction.
+    pub karma_limits: KarmaLimits, // 16 bytes (taken from `reserved`)
+
+    /// The Merkle epoch `epoch_karma_increase` is counted for.
+    /// UPDATED BY PROGRAM during: every karma write.
+    pub karma_limit_epoch: u64, // 8 bytes (taken from `reserved`)
+
+    /// Total karma added across all users during `karma_limit_epoch`.
+    /// UPDATED BY PROGRAM during: every karma write.
+    pub epoch_karma_increase: u64, // 8 bytes (taken from `reserved`)
+
+    /// How user karma decays over time. Applied lazily whenever karma is read or written.
+    /// SET BY ADMIN using: `set_karma_decay` instruction.
+    pub karma_decay: KarmaDecay, // 5 bytes (taken from `reserved`)
+
+    /// Limits on users gifting karma to each other with `gift_karma`.
+    /// All zero (gifting off) by default.
+    /// SET BY ADMIN using: `set_karma_gift_rules` instruction.
+    pub karma_gift_rules: KarmaGiftRules, // 8 bytes (taken from `reserved`)
+
+    /// Karma credited to the receiver of a tip, so on-chain karma doesn't drift
+    /// from the backend's tip bonus. All zero (no credit) by default.
+    /// SET BY ADMIN using: `set_tip_karma_rule` instruction.
+    pub tip_karma_rule: TipKarmaRule, // 54 bytes (22 taken from `reserved`)
+
+    /// Zeroed space kept for future fields, so new settings don't need a realloc.
+    /// A new field takes its bytes from here and must treat zero as its default.
+    pub reserved: [u8; 27], // 27 bytes
+}
+
+/// Limits on karma writes. Windows follow the Merkle `epoch`; a value of 0 turns that limit off.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug, Default,
+)]
+pub struct KarmaLimits {
+    /// Most karma a single user can gain per epoch.
+    pub max_increase_per_user: u32,
+
+    /// Most karma all users together can gain per epoch.
+    pub max_increase_per_epoch: u64,
+
+    /// Seconds a user must wait after a karma increase before minting a milestone badge.
+    pub mint_cooldown_secs: u32,
+}
+
+impl KarmaLimits {
+    /// The limit broken once a user and all users together have gained
+    /// `user_increase` and `total_increase` this epoch, if any.
+    pub fn exceeded(&self, user_increase: u32, total_increase: u64) -> Option<VoixError> {
+        if self.max_increase_per_user != 0 && user_increase > self.max_increase_per_user {
+            Some(VoixError::UserKarmaLimitExceeded)
+        } else if self.max_increase_per_epoch != 0 && total_increase > self.max_increase_per_epoch {
+            Some(VoixError::EpochKarmaLimitExceeded)
+        } else {
+            None
+        }
+    }
+}
+
+/// Limits on `gift_karma`. Days are UTC; a `daily_cap` of 0 turns gifting off.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug, Default,
+)]
+pub struct KarmaGiftRules {
+    /// Most karma a single user can give away per day.
+    pub daily_cap: u32,
+
+    /// Karma the giver must still have after a gift.
+    pub min_balance: u32,
+}
+
+/// When a tip earns the receiver karma. Only tips from another registered user
+/// (one that passes its `tipper_account`) count. A `karma_per_tip` of 0 turns
+/// the rule off, and a minimum of 0 means that kind of tip never earns karma.
+/// SPL tips only count in `spl_mint`, so nobody can farm karma with a worthless mint.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug, Default,
+)]
+pub struct TipKarmaRule {
+    /// Karma the receiver gets for each qualifying tip.
+    pub karma_per_tip: u32,
+
+    /// Most tips from one tipper to one receiver that earn karma per Merkle epoch.
+    /// 0 means no cap.
+    pub max_credits_per_pair: u16,
+
+    /// Smallest SOL tip, in lamports, that earns karma.
+    pub min_sol_amount: u64,
+
+    /// Smallest SPL tip, in `spl_mint` base units, that earns karma.
+    pub min_spl_amount: u64,
+
+    /// The only mint whose tips earn karma.
+    /// `Pubkey::default()` means SPL tips never earn karma.
+    pub spl_mint: Pubkey,
+}
+
+impl TipKarmaRule {
+    /// Whether a tip of `amount` earns karma. `mint` is `None` for a SOL tip.
+    pub fn qualifies(&self, amount: u64, mint: Option<Pubkey>) -> bool {
+        let min_amount = match mint {
+            None => self.min_sol_amount,
+            Some(mint) if mint == self.spl_mint => self.min_spl_amount,
+            Some(_) => 0,
+        };
+        self.karma_per_tip > 0 && min_amount > 0 && amount >= min_amount
+    }
+}
+
+/// The delegated authorities stored on `GlobalConfig`.
+/// Each role gates only its own instructions, so losing one backend key
+/// doesn't hand over the whole program.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Role {
+    KarmaOracle,
+    MerklePublisher,
+    UserSponsor,
+    Pauser,
+}
+
+/// How milestone badges are issued. The first variant is the zero default.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BadgeMode {
+    /// A new mint and master edition for every milestone (`mint_milestone_nft`).
+    PerMilestone,
+    /// One "Voix Reputation" NFT per user, minted once with `mint_milestone_nft`
+    /// and rewritten by `upgrade_reputation_badge` as the user climbs tiers.
+    Reputation,
+}
+
+/// The token standard used for new milestone badges. The first variant is the zero default.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BadgeStandard {
+    /// SPL Token mint + Metaplex metadata and master edition (`mint_milestone_nft`).
+    MetaplexNft,
+    /// Token-2022 mint carrying its own metadata (`mint_milestone_badge_2022`).
+    /// Needs no Metaplex program, but badges don't join the Metaplex collection.
+    Token2022,
+}
+
+/// How karma decays between writes. The first variant is the zero default.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KarmaDecay {
+    /// Karma never decays.
+    None,
+    /// Karma halves every `half_life_secs`, falling linearly between halvings.
+    HalfLife { half_life_secs: u32 },
+    /// Karma drops by `points_per_day`, down to 0.
+    Linear { points_per_day: u32 },
+}
+
+impl KarmaDecay {
+    /// Fails with `InvalidKarmaDecay` for a zero half-life or rate.
+    pub fn validate(&self) -> Result<()> {
+        let valid = match *self {
+            KarmaDecay::None => true,
+            KarmaDecay::HalfLife { half_life_secs } => half_life_secs > 0,
+            KarmaDecay::Linear { points_per_day } => points_per_day > 0,
+        };
+        require!(valid, VoixError::InvalidKarmaDecay);
+        Ok(())
+    }
+
+    /// Returns what `karma` has decayed to after `elapsed` seconds.
+    pub fn apply(&self, karma: u32, elapsed: i64) -> u32 {
+        let elapsed = u64::try_from(elapsed).unwrap_or(0);
+        match *self {
+            KarmaDecay::None => karma,
+            KarmaDecay::HalfLife { half_life_secs } => {
+                let half_life = u64::from(half_life_secs.max(1));
+                let halvings = elapsed / half_life;
+                if halvings >= 32 {
+                    return 0;
+                }
+                let halved = u64::from(karma >> halvings);
+                let lost = halved * (elapsed % half_life) / (2 * half_life);
+                // Always fits: `halved - lost <= karma`.
+                (halved - lost) as u32
+            }
+            KarmaDecay::Linear { points_per_day } => {
+                let lost = u64::from(points_per_day).saturating_mul(elapsed) / SECONDS_PER_DAY;
+                u32::try_from(u64::from(karma).saturating_sub(lost)).unwrap_or(0)
+            }
+        }
+    }
+}
+
+impl GlobalConfig {
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused_flags & flag != 0
+    }
+
+    /// Fails with `ProgramPaused` if the instruction group behind `flag` is stopped.
+    pub fn require_not_paused(&self, flag: u8) -> Result<()> {
+        require!(!self.is_paused(flag), VoixError::ProgramPaused);
+        Ok(())
+    }
+
+    /// The user's karma after decay, as of `timestamp`.
+    /// Accounts that were never settled (`last_karma_update == 0`) haven't started decaying.
+    pub fn effective_karma(&self, user_account: &UserAccount, timestamp: i64) -> u32 {
+        if user_account.last_karma_update == 0 {
+            return user_account.karma;
+        }
+        self.karma_decay.apply(
+            user_account.karma,
+            timestamp.saturating_sub(user_account.last_karma_update),
+        )
+    }
+
+    /// Stores the decayed karma on `user_account`, restarts its decay clock and records
+    /// any drop as a `Decay` change. Fails with `MissingKarmaHistory` if the user has a
+    /// history that was left out. Returns the karma before settling.
+    pub fn settle_karma(
+        &self,
+        user_account: &mut UserAccount,
+        karma_history: Option<&mut KarmaHistory>,
+        timestamp: i64,
+    ) -> Result<u32> {
+        require!(
+            karma_history.is_some() || !user_account.has_karma_history,
+            VoixError::MissingKarmaHistory
+        );
+        let stored_karma = user_account.karma;
+        user_account.karma = self.effective_karma(user_account, timestamp);
+        user_account.last_karma_update = timestamp;
+
+        // Only real drops are recorded, so settling can't flood the history.
+        if user_account.karma != stored_karma {
+            KarmaHistory::record(
+                karma_history,
+                user_account,
+                KarmaChange {
+                    old_karma: stored_karma,
+                    new_karma: user_account.karma,
+                    reason: Some(KarmaReason::Decay),
+                    signer: Pubkey::default(), // Nobody authorizes decay
+                    timestamp,
+                },
+            )?;
+        }
+        Ok(stored_karma)
+    }
+
+    /// Settles decay, then checks a karma write against `karma_limits` and records any increase.
+    /// Must run before `user_account.karma` is set to `new_karma`; decreases are never limited.
+    pub fn track_karma_increase(
+        &mut self,
+        user_account: &mut UserAccount,
+        karma_history: Option<&mut KarmaHistory>,
+        new_karma: u32,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.settle_karma(user_account, karma_history, timestamp)?;
+        let increase = new_karma.saturating_sub(user_account.karma);
+        if increase == 0 {
+            return Ok(());
+        }
+
+        let (user_increase, total_increase) = self.epoch_karma_increases(user_account, increase)?;
+        if let Some(error) = self.karma_limits.exceeded(user_increase, total_increase) {
+            return Err(error.into());
+        }
+
+        self.karma_limit_epoch = self.epoch;
+        self.epoch_karma_increase = total_increase;
+        user_account.karma_limit_epoch = self.epoch;
+        user_account.epoch_karma_increase = user_increase;
+        user_account.last_karma_increase_at = timestamp;
+        Ok(())
+    }
+
+    /// The karma `user_account` and all users together will have gained this epoch
+    /// once `increase` is added. Both windows restart lazily when the Merkle epoch moves on.
+    fn epoch_karma_increases(
+        &self,
+        user_account: &UserAccount,
+        increase: u32,
+    ) -> Result<(u32, u64)> {
+        let user_increase = if user_account.karma_limit_epoch == self.epoch {
+            user_account.epoch_karma_increase
+        } else {
+            0
+        };
+        let total_increase = if self.karma_limit_epoch == self.epoch {
+            self.epoch_karma_increase
+        } else {
+            0
+        };
+        Ok((
+            user_increase
+                .checked_add(increase)
+                .ok_or(VoixError::MathOverflow)?,
+            total_increase
+                .checked_add(u64::from(increase))
+                .ok_or(VoixError::MathOverflow)?,
+        ))
+    }
+
+    /// Credits `tip_karma_rule.karma_per_tip` to the receiver of a qualifying tip and
+    /// returns the karma credited. Returns 0 instead of failing the tip when karma
+    /// writes are paused, the pair's cap is used up or a karma limit would be hit;
+    /// those are checked before anything is written. Any other error fails the tip.
+    pub fn credit_tip_karma(
+        &mut self,
+        receiver_account: &mut UserAccount,
+        receiver_karma_history: Option<&mut KarmaHistory>,
+        tip_karma_credit: &mut TipKarmaCredit,
+        timestamp: i64,
+    ) -> Result<u32> {
+        if self.is_paused(PAUSE_KARMA) {
+            return Ok(0);
+        }
+
+        // The pair's window restarts lazily when the Merkle epoch moves on.
+        let credits = if tip_karma_credit.epoch == self.epoch {
+            tip_karma_credit.credits
+        } else {
+            0
+        };
+        let rule = self.tip_karma_rule;
+        if rule.max_credits_per_pair != 0 && credits >= rule.max_credits_per_pair {
+            return Ok(0);
+        }
+        let (user_increase, total_increase) =
+            self.epoch_karma_increases(receiver_account, rule.karma_per_tip)?;
+        if self
+            .karma_limits
+            .exceeded(user_increase, total_increase)
+            .is_some()
+        {
+            return Ok(0);
+        }
+
+        let new_karma = self
+            .effective_karma(receiver_account, timestamp)
+            .checked_add(rule.karma_per_tip)
+            .ok_or(VoixError::MathOverflow)?;
+        self.track_karma_increase(
+            receiver_account,
+            receiver_karma_history,
+            new_karma,
+            timestamp,
+        )?;
+
+        receiver_account.karma = new_karma;
+        tip_karma_credit.epoch = self.epoch;
+        tip_karma_credit.credits = credits.checked_add(1).ok_or(VoixError::MathOverflow)?;
+        Ok(rule.karma_per_tip)
+    }
+
+    /// Fails with `MintCooldownActive` until `mint_cooldown_secs` have passed
+    /// since the user's last karma increase.
+    pub fn require_mint_cooldown(&self, user_account: &UserAccount, timestamp: i64) -> Result<()> {
+        let ready_at = user_account
+            .last_karma_increase_at
+            .saturating_add(i64::from(self.karma_limits.mint_cooldown_secs));
+        require!(timestamp >= ready_at, VoixError::MintCooldownActive);
+        Ok(())
+    }
+
+    /// Returns the key currently holding `role`.
+    pub fn role_authority(&self, role: Role) -> Pubkey {
+        match role {
+            Role::KarmaOracle => self.karma_oracle,
+            Role::MerklePublisher => self.merkle_publisher,
+            Role::UserSponsor => self.user_sponsor,
+            Role::Pauser => self.pauser,
+        }
+    }
+
+    /// Assigns `role` to `authority`. Use `Pubkey::default()` to revoke it.
+    pub fn set_role(&mut self, role: Role, authority: Pubkey) {
+        match role {
+            Role::KarmaOracle => self.karma_oracle = authority,
+            Role::MerklePublisher => self.merkle_publisher = authority,
+            Role::UserSponsor => self.user_sponsor = authority,
+            Role::Pauser => self.pauser = authority,
+        }
+    }
+}
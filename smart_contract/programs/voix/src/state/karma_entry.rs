@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+/// One user's new karma in a `batch_update_karma` call. Entry `i` belongs to
+/// the `UserAccount` passed at `remaining_accounts[i]`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KarmaEntry {
+    /// The wallet whose karma is set.
+    pub user: Pubkey,
+
+    /// The user's new karma score.
+    pub karma: u32,
+}
@@ -0,0 +1,78 @@
+use crate::constants::{KARMA_HISTORY_LEN, KARMA_HISTORY_VERSION};
+use crate::errors::VoixError;
+use crate::state::{KarmaReason, UserAccount};
+use anchor_lang::prelude::*;
+
+/// The last `KARMA_HISTORY_LEN` karma changes of one user, so support can
+/// answer "why did my karma change?" straight from the chain.
+/// PDA seeds: `[KARMA_HISTORY_SEED, user]`. Optional; created with `initialize_karma_history`.
+#[account]
+#[derive(InitSpace)]
+pub struct KarmaHistory {
+    /// The layout version of this account (see `KARMA_HISTORY_VERSION`).
+    pub version: u8, // 1 byte
+
+    /// The wallet whose karma changes are kept.
+    pub user: Pubkey, // 32 bytes
+
+    /// Where the next change goes once `changes` is full; that slot holds the oldest change.
+    /// UPDATED BY PROGRAM during: every karma write.
+    pub next_index: u8, // 1 byte
+
+    /// A ring buffer of changes. Until it is full, they are simply in order.
+    /// UPDATED BY PROGRAM during: every karma write.
+    #[max_len(KARMA_HISTORY_LEN)]
+    pub changes: Vec<KarmaChange>, // 4 + 50 * KARMA_HISTORY_LEN bytes
+}
+
+/// One entry in a `KarmaHistory`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct KarmaChange {
+    /// Karma before the change, after any decay up to that moment.
+    pub old_karma: u32,
+    pub new_karma: u32,
+    /// `None` for absolute writes (`update_user_karma`, vouchers and Merkle syncs).
+    pub reason: Option<KarmaReason>,
+    /// The key that authorized the change (the karma oracle, Merkle publisher or user).
+    /// `Pubkey::default()` for `Decay`.
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+impl KarmaHistory {
+    pub fn new(user: Pubkey) -> Self {
+        Self {
+            version: KARMA_HISTORY_VERSION,
+            user,
+            next_index: 0,
+            changes: Vec::with_capacity(KARMA_HISTORY_LEN),
+        }
+    }
+
+    /// Appends `change`, overwriting the oldest one when full.
+    pub fn push(&mut self, change: KarmaChange) {
+        if self.changes.len() < KARMA_HISTORY_LEN {
+            self.changes.push(change);
+        } else {
+            self.changes[usize::from(self.next_index)] = change;
+        }
+        self.next_index = ((usize::from(self.next_index) + 1) % KARMA_HISTORY_LEN) as u8;
+    }
+
+    /// Records `change` for `user_account` when its history was passed in. Fails with
+    /// `MissingKarmaHistory` if the user has a history that was left out.
+    pub fn record(
+        history: Option<&mut Self>,
+        user_account: &UserAccount,
+        change: KarmaChange,
+    ) -> Result<()> {
+        match history {
+            Some(history) => history.push(change),
+            None => require!(
+                !user_account.has_karma_history,
+                VoixError::MissingKarmaHistory
+            ),
+        }
+        Ok(())
+    }
+}
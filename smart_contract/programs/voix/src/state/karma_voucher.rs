@@ -0,0 +1,32 @@
+use crate::constants::KARMA_VOUCHER_PREFIX;
+use anchor_lang::prelude::*;
+
+/// A karma value signed off-chain by the karma oracle and redeemed by the
+/// user with `claim_karma`. Not an account: the signed message is
+/// `KARMA_VOUCHER_PREFIX || borsh(KarmaVoucher)`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct KarmaVoucher {
+    /// The Voix program the voucher is for, so devnet vouchers can't be used on mainnet.
+    pub program_id: Pubkey,
+
+    /// The wallet whose karma is set.
+    pub user: Pubkey,
+
+    /// The user's new karma score.
+    pub karma: u32,
+
+    /// Must be higher than `UserAccount.karma_nonce`.
+    pub nonce: u64,
+
+    /// Unix timestamp after which the voucher can't be redeemed.
+    pub expires_at: i64,
+}
+
+impl KarmaVoucher {
+    /// The exact bytes the karma oracle signs.
+    pub fn message(&self) -> Result<Vec<u8>> {
+        let mut message = KARMA_VOUCHER_PREFIX.to_vec();
+        self.serialize(&mut message)?;
+        Ok(message)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::constants::{CONFIG_VERSION, USER_ACCOUNT_VERSION};
+use crate::state::{
+    BadgeMode, BadgeStandard, GlobalConfig, KarmaDecay, KarmaGiftRules, KarmaLimits,
+    TipKarmaRule, UserAccount,
+};
+use anchor_lang::prelude::*;
+
+// Account layouts from older program versions.
+// They are only read by the `migrate_*` instructions and never written;
+// the `From` impls fill every newer field with its initialization default.
+
+/// `GlobalConfig` as created by the first release of `initialize_config`.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct GlobalConfigV0 {
+    pub admin: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub epoch: u64,
+}
+
+/// `UserAccount` as created by the first release of `initialize_user`.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct UserAccountV0 {
+    pub user_pubkey: Pubkey,
+    pub karma: u32,
+    pub minted_milestones: u8,
+    pub total_sol_tipped: u64,
+}
+
+impl From<GlobalConfigV0> for GlobalConfig {
+    /// Same defaults as `initialize_config`: every role starts with the admin.
+    fn from(legacy: GlobalConfigV0) -> Self {
+        GlobalConfig {
+            version: CONFIG_VERSION,
+            admin: legacy.admin,
+            merkle_root: legacy.merkle_root,
+            epoch: legacy.epoch,
+            pending_admin: None,
+            karma_oracle: legacy.admin,
+            merkle_publisher: legacy.admin,
+            user_sponsor: legacy.admin,
+            pauser: legacy.admin,
+            council_enabled: false,
+            paused_flags: 0,
+            badge_collection: Pubkey::default(),
+            badge_mode: BadgeMode::PerMilestone,
+            badge_standard: BadgeStandard::MetaplexNft,
+            karma_limits: KarmaLimits::default(),
+            karma_limit_epoch: 0,
+            epoch_karma_increase: 0,
+            karma_decay: KarmaDecay::None,
+            karma_gift_rules: KarmaGiftRules::default(),
+            tip_karma_rule: TipKarmaRule::default(),
+            reserved: [0; 27],
+        }
+    }
+}
+
+impl From<UserAccountV0> for UserAccount {
+    fn from(legacy: UserAccountV0) -> Self {
+        UserAccount {
+            version: USER_ACCOUNT_VERSION,
+            user_pubkey: legacy.user_pubkey,
+            karma: legacy.karma,
+            minted_milestones: legacy.minted_milestones as u64,
+            total_sol_tipped: legacy.total_sol_tipped,
+            badge_mint: Pubkey::default(),
+            badge_level: 0,
+            tips_sent: 0,
+            created_at: 0,
+            achievements: 0,
+            karma_nonce: 0,
+            karma_synced_epoch: 0,
+            karma_seq: 0,
+            karma_limit_epoch: 0,
+            epoch_karma_increase: 0,
+            last_karma_increase_at: 0,
+            last_karma_update: 0,
+            has_karma_history: false,
+            gift_day: 0,
+            karma_gifted_today: 0,
+            reserved: [0; 14],
+        }
+    }
+}
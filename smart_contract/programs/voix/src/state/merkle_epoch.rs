@@ -0,0 +1,30 @@
+use crate::constants::MAX_TREE_URI_LEN;
+use anchor_lang::prelude::*;
+
+/// A Merkle root as it was published for one epoch. Never changed after
+/// `submit_merkle_root` creates it, so proofs against old epochs stay checkable
+/// after `GlobalConfig.merkle_root` has moved on.
+/// PDA seeds: `[MERKLE_EPOCH_SEED, epoch (u64 LE)]`.
+#[account]
+#[derive(InitSpace)]
+pub struct MerkleEpoch {
+    /// The layout version of this account (see `MERKLE_EPOCH_VERSION`).
+    pub version: u8, // 1 byte
+
+    pub epoch: u64,            // 8 bytes
+    pub merkle_root: [u8; 32], // 32 bytes
+
+    /// How many leaves the tree has, so clients can check a proof's length.
+    pub leaf_count: u64, // 8 bytes
+
+    /// The Merkle publisher that submitted the root.
+    pub publisher: Pubkey, // 32 bytes
+
+    pub submitted_slot: u64, // 8 bytes
+    pub submitted_at: i64,   // 8 bytes
+
+    /// A content-addressed link to the full tree dump (e.g. `ipfs://<cid>`),
+    /// so anyone can rebuild proofs for this epoch.
+    #[max_len(MAX_TREE_URI_LEN)]
+    pub tree_uri: Option<String>,
+}
@@ -0,0 +1,72 @@
+use crate::constants::MILESTONE_RECORD_VERSION;
+use anchor_lang::prelude::*;
+
+/// One record per user and milestone level, created when the badge is minted.
+/// PDA seeds: `[MILESTONE_RECORD_SEED, user, milestone_level]`.
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneRecord {
+    /// The layout version of this account (see `MILESTONE_RECORD_VERSION`).
+    pub version: u8, // 1 byte
+
+    /// The wallet that earned the milestone.
+    pub user: Pubkey, // 32 bytes
+
+    /// The milestone level (matches `MilestoneTier.level`).
+    pub level: u8, // 1 byte
+
+    /// The badge mint. In reputation mode this is the user's single badge,
+    /// so several records can point at the same mint.
+    pub mint: Pubkey, // 32 bytes
+
+    /// The slot the milestone was reached in.
+    pub slot: u64, // 8 bytes
+
+    /// The user's karma at that moment.
+    pub karma: u32, // 4 bytes
+
+    /// Set when moderation takes the milestone back.
+    /// SET BY ADMIN using: `revoke_milestone` instruction.
+    pub revoked: bool, // 1 byte (taken from `reserved`)
+
+    /// The off-chain moderation reason code given at revocation (0 while not revoked).
+    pub reason_code: u16, // 2 bytes (taken from `reserved`)
+
+    /// When the milestone was revoked (0 while not revoked).
+    pub revoked_at: i64, // 8 bytes (taken from `reserved`)
+
+    /// How many times this milestone was revoked. A revoked milestone can be
+    /// reached again; its badge is then minted at a fresh address (see `badge_seed`).
+    /// UPDATED BY ADMIN using: `revoke_milestone` instruction.
+    pub revocations: u8, // 1 byte (taken from `reserved`)
+
+    /// Zeroed space kept for future fields.
+    pub reserved: [u8; 52], // 52 bytes
+}
+
+impl MilestoneRecord {
+    /// The extra badge mint seed for this milestone's next mint. It is empty until
+    /// the first revocation, so the first badge stays at `[BADGE_SEED, user, level]`;
+    /// after that it is the revocation count, because a burned mint can't be reused.
+    pub fn badge_seed(&self) -> &[u8] {
+        if self.revocations == 0 {
+            &[]
+        } else {
+            std::slice::from_ref(&self.revocations)
+        }
+    }
+
+    /// Fills in the record for a newly minted (or re-minted) badge.
+    /// A revoked record is reused and keeps its revocation count.
+    pub fn record_mint(&mut self, user: Pubkey, level: u8, mint: Pubkey, slot: u64, karma: u32) {
+        self.version = MILESTONE_RECORD_VERSION;
+        self.user = user;
+        self.level = level;
+        self.mint = mint;
+        self.slot = slot;
+        self.karma = karma;
+        self.revoked = false;
+        self.reason_code = 0;
+        self.revoked_at = 0;
+    }
+}
@@ -0,0 +1,183 @@
+use crate::constants::{
+    MAX_BADGE_NAME_LEN, MAX_BADGE_SYMBOL_LEN, MAX_BADGE_URI_LEN, MAX_MILESTONE_FLAG_INDEX,
+    MAX_MILESTONE_TIERS,
+};
+use crate::errors::VoixError;
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneRegistry {
+    /// The layout version of this account (see `MILESTONE_REGISTRY_VERSION`).
+    pub version: u8, // 1 byte
+
+    /// Every milestone tier ever added, including retired ones.
+    /// UPDATED BY ADMIN using: `add_milestone_tier` / `update_milestone_tier` /
+    /// `retire_milestone_tier` instructions (or the matching council actions).
+    #[max_len(MAX_MILESTONE_TIERS)]
+    pub tiers: Vec<MilestoneTier>,
+
+    /// A bit-flag of the tiers (by `flag_index`) whose badges may be transferred.
+    /// Zero means every tier is soulbound: minting freezes the badge in the user's wallet.
+    /// UPDATED BY ADMIN using: `set_tier_transferable` instruction.
+    pub transferable_flags: u64, // 8 bytes (taken from `reserved`)
+
+    /// Zeroed space kept for future registry-wide settings.
+    pub reserved: [u8; 56], // 56 bytes
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, PartialEq, Eq, Debug)]
+pub struct MilestoneTier {
+    /// The level users pass to `mint_milestone_nft` (e.g., 1 = Bronze). Never 0.
+    pub level: u8, // 1 byte
+
+    /// The minimum `UserAccount.karma` needed to mint this tier.
+    pub karma_required: u32, // 4 bytes
+
+    /// Canonical badge metadata for this tier. `mint_milestone_nft` always uses
+    /// these values; callers can't choose their own name, symbol or URI.
+    #[max_len(MAX_BADGE_NAME_LEN)]
+    pub name: String,
+    #[max_len(MAX_BADGE_SYMBOL_LEN)]
+    pub symbol: String,
+    #[max_len(MAX_BADGE_URI_LEN)]
+    pub uri: String,
+
+    /// The bit in `UserAccount.minted_milestones` that records this tier.
+    /// Never changes once the tier exists, and never reused by another tier.
+    pub flag_index: u8, // 1 byte
+
+    /// Retired tiers stay in the registry (so their flag stays reserved)
+    /// but can no longer be minted.
+    pub retired: bool, // 1 byte
+}
+
+impl MilestoneTier {
+    /// The bit for this tier in `UserAccount.minted_milestones`.
+    pub fn flag(&self) -> u64 {
+        1u64 << self.flag_index
+    }
+
+    /// Rejects badge metadata that the Token Metadata program (or our account space) can't hold.
+    pub fn validate_metadata(name: &str, symbol: &str, uri: &str) -> Result<()> {
+        require!(!name.is_empty(), VoixError::BadgeNameEmpty);
+        require!(
+            name.len() <= MAX_BADGE_NAME_LEN,
+            VoixError::BadgeNameTooLong
+        );
+        require!(
+            symbol.len() <= MAX_BADGE_SYMBOL_LEN,
+            VoixError::BadgeSymbolTooLong
+        );
+        require!(!uri.is_empty(), VoixError::BadgeUriEmpty);
+        require!(
+            uri.len() <= MAX_BADGE_URI_LEN,
+            VoixError::BadgeUriTooLong
+        );
+        Ok(())
+    }
+}
+
+impl MilestoneRegistry {
+    pub fn tier(&self, level: u8) -> Option<&MilestoneTier> {
+        self.tiers.iter().find(|tier| tier.level == level)
+    }
+
+    /// Returns the tier a user may mint at `level`, rejecting unknown and retired levels.
+    pub fn mintable_tier(&self, level: u8) -> Result<&MilestoneTier> {
+        let tier = self
+            .tier(level)
+            .ok_or(VoixError::InvalidMilestoneLevel)?;
+        require!(!tier.retired, VoixError::MilestoneTierRetired);
+        Ok(tier)
+    }
+
+    /// Returns the tier with the highest karma requirement among the bits set in
+    /// `minted_milestones`, i.e. the tier a reputation badge should show.
+    pub fn highest_minted_tier(&self, minted_milestones: u64) -> Option<&MilestoneTier> {
+        self.tiers
+            .iter()
+            .filter(|tier| minted_milestones & tier.flag() != 0)
+            .max_by_key(|tier| tier.karma_required)
+    }
+
+    pub fn add_tier(&mut self, tier: MilestoneTier) -> Result<()> {
+        MilestoneTier::validate_metadata(&tier.name, &tier.symbol, &tier.uri)?;
+        // Level 0 means "no badge" in `UserAccount.badge_level`.
+        require!(tier.level > 0, VoixError::InvalidMilestoneLevel);
+        require!(
+            self.tiers.len() < MAX_MILESTONE_TIERS,
+            VoixError::MilestoneRegistryFull
+        );
+        require!(
+            self.tier(tier.level).is_none(),
+            VoixError::MilestoneTierExists
+        );
+        require!(
+            tier.flag_index <= MAX_MILESTONE_FLAG_INDEX
+                && self.tiers.iter().all(|t| t.flag_index != tier.flag_index),
+            VoixError::InvalidMilestoneFlag
+        );
+        self.tiers.push(tier);
+        Ok(())
+    }
+
+    /// Replaces the tier with the same level. The flag index can't be changed,
+    /// otherwise already-minted badges would point at the wrong bit.
+    pub fn update_tier(&mut self, tier: MilestoneTier) -> Result<()> {
+        MilestoneTier::validate_metadata(&tier.name, &tier.symbol, &tier.uri)?;
+        let existing = self
+            .tiers
+            .iter_mut()
+            .find(|t| t.level == tier.level)
+            .ok_or(VoixError::InvalidMilestoneLevel)?;
+        require!(
+            existing.flag_index == tier.flag_index,
+            VoixError::InvalidMilestoneFlag
+        );
+        *existing = tier;
+        Ok(())
+    }
+
+    /// Replaces only the canonical badge metadata of a tier.
+    pub fn set_metadata(&mut self, level: u8, name: String, symbol: String, uri: String) -> Result<()> {
+        MilestoneTier::validate_metadata(&name, &symbol, &uri)?;
+        let existing = self
+            .tiers
+            .iter_mut()
+            .find(|t| t.level == level)
+            .ok_or(VoixError::InvalidMilestoneLevel)?;
+        existing.name = name;
+        existing.symbol = symbol;
+        existing.uri = uri;
+        Ok(())
+    }
+
+    /// Soulbound badges are frozen in the holder's token account at mint time.
+    pub fn is_soulbound(&self, tier: &MilestoneTier) -> bool {
+        self.transferable_flags & tier.flag() == 0
+    }
+
+    pub fn set_transferable(&mut self, level: u8, transferable: bool) -> Result<()> {
+        let flag = self
+            .tier(level)
+            .ok_or(VoixError::InvalidMilestoneLevel)?
+            .flag();
+        if transferable {
+            self.transferable_flags |= flag;
+        } else {
+            self.transferable_flags &= !flag;
+        }
+        Ok(())
+    }
+
+    pub fn retire_tier(&mut self, level: u8) -> Result<&MilestoneTier> {
+        let existing = self
+            .tiers
+            .iter_mut()
+            .find(|t| t.level == level)
+            .ok_or(VoixError::InvalidMilestoneLevel)?;
+        existing.retired = true;
+        Ok(existing)
+    }
+}
@@ -4,3 +4,32 @@ pub use global_config::*;
 pub mod user_account;
 pub use user_account::*;
 
+pub mod council;
+pub use council::*;
+
+pub mod legacy;
+pub use legacy::*;
+
+pub mod milestone_registry;
+pub use milestone_registry::*;
+
+pub mod milestone_record;
+pub use milestone_record::*;
+
+pub mod achievement;
+pub use achievement::*;
+
+pub mod karma_voucher;
+pub use karma_voucher::*;
+
+pub mod karma_entry;
+pub use karma_entry::*;
+
+pub mod karma_history;
+pub use karma_history::*;
+
+pub mod tip_karma_credit;
+pub use tip_karma_credit::*;
+
+pub mod merkle_epoch;
+pub use merkle_epoch::*;
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Counts the tips one tipper has had credited as karma to one receiver in the
+/// current Merkle epoch, so `TipKarmaRule.max_credits_per_pair` can stop wash-tipping.
+/// PDA seeds: `[TIP_KARMA_SEED, tipper, receiver]`. Created by the tipper's first credited tip.
+#[account]
+#[derive(InitSpace)]
+pub struct TipKarmaCredit {
+    pub tipper: Pubkey,   // 32 bytes
+    pub receiver: Pubkey, // 32 bytes
+
+    /// The Merkle epoch `credits` is counted for.
+    /// UPDATED BY PROGRAM during: `tip_user_sol` / `tip_user_spl` instructions.
+    pub epoch: u64, // 8 bytes
+
+    /// How many of this pair's tips have earned karma during `epoch`.
+    /// UPDATED BY PROGRAM during: `tip_user_sol` / `tip_user_spl` instructions.
+    pub credits: u16, // 2 bytes
+}
@@ -1,25 +1,130 @@
+use crate::errors::VoixError;
 use anchor_lang::prelude::*;
 
 #[account]
 #[derive(InitSpace)]
 pub struct UserAccount {
+    /// The layout version of this account (see `USER_ACCOUNT_VERSION`).
+    /// SET BY PROGRAM during: `initialize_user` / `migrate_user_account` instructions.
+    pub version: u8, // 1 byte
+
     /// The user's wallet address.
     /// SET ONCE by: `initialize_user` instruction.
     pub user_pubkey: Pubkey, // 32 bytes
 
-    /// The user's karma score.
+    /// The user's karma score as of `last_karma_update`, before any decay since.
     /// This value is read from your off-chain 'Karma' table (`Karma.points`).
     /// UPDATED BY BACKEND using: `update_user_karma` instruction.
     pub karma: u32, // 4 bytes
 
     /// A bit-flag to track which milestone NFTs the user has already claimed.
-    /// This prevents double-minting.
+    /// This prevents double-minting. Bit `n` belongs to the registry tier with `flag_index = n`.
     /// (e.g., 1 = Bronze, 2 = Silver, 4 = Gold)
     /// UPDATED BY PROGRAM during: `mint_milestone_nft` instruction.
-    pub minted_milestones: u8, // 1 byte
+    pub minted_milestones: u64, // 8 bytes (1 byte in version 0)
 
     ///  A simple counter for total SOL received from tips.
     /// This is purely on-chain and not synced from your backend.
     /// UPDATED BY PROGRAM during: `tip_user_sol` instruction.
     pub total_sol_tipped: u64, // 8 bytes
+
+    /// The mint of the user's single reputation badge (`BadgeMode::Reputation`).
+    /// `Pubkey::default()` until the badge has been minted.
+    /// SET BY PROGRAM during: `mint_milestone_nft` instruction.
+    pub badge_mint: Pubkey, // 32 bytes (taken from `reserved`)
+
+    /// The milestone level currently shown by the reputation badge (0 = none).
+    /// UPDATED BY PROGRAM during: `mint_milestone_nft` / `upgrade_reputation_badge` instructions.
+    pub badge_level: u8, // 1 byte (taken from `reserved`)
+
+    /// How many tips this user has sent (SOL or SPL).
+    /// Only counted when the tipper passes their own `UserAccount` to the tip instruction.
+    /// UPDATED BY PROGRAM during: `tip_user_sol` / `tip_user_spl` instructions.
+    pub tips_sent: u32, // 4 bytes (taken from `reserved`)
+
+    /// When the account was created. 0 for accounts created before this field existed.
+    /// SET ONCE by: `initialize_user` instruction.
+    pub created_at: i64, // 8 bytes (taken from `reserved`)
+
+    /// A bit-flag of the achievements the user has claimed (bit `n` = achievement id `n`).
+    /// UPDATED BY PROGRAM during: `claim_achievement` instruction.
+    pub achievements: u64, // 8 bytes (taken from `reserved`)
+
+    /// The nonce of the last karma voucher redeemed. Vouchers must carry a higher
+    /// nonce, so each one can be used once and older ones can't overwrite newer karma.
+    /// UPDATED BY PROGRAM during: `claim_karma` instruction and every karma oracle write.
+    pub karma_nonce: u64, // 8 bytes (taken from `reserved`)
+
+    /// The Merkle epoch the user's karma was last synced from (0 = never).
+    /// Proofs must be for a later epoch, so an old proof can't roll karma back.
+    /// UPDATED BY PROGRAM during: `sync_karma_from_root` instruction and every karma oracle write.
+    pub karma_synced_epoch: u64, // 8 bytes (taken from `reserved`)
+
+    /// How many karma deltas have been applied to this account. The karma oracle
+    /// passes the value it expects, so racing or retried deltas fail instead of
+    /// being lost or counted twice.
+    /// UPDATED BY PROGRAM during: `apply_karma_delta` instruction.
+    pub karma_seq: u64, // 8 bytes (taken from `reserved`)
+
+    /// The Merkle epoch `epoch_karma_increase` is counted for.
+    /// UPDATED BY PROGRAM during: every karma write.
+    pub karma_limit_epoch: u64, // 8 bytes (taken from `reserved`)
+
+    /// Karma gained during `karma_limit_epoch`, capped by `KarmaLimits.max_increase_per_user`.
+    /// UPDATED BY PROGRAM during: every karma write.
+    pub epoch_karma_increase: u32, // 4 bytes (taken from `reserved`)
+
+    /// When the user's karma last went up. Starts the milestone mint cooldown.
+    /// UPDATED BY PROGRAM during: every karma write.
+    pub last_karma_increase_at: i64, // 8 bytes (taken from `reserved`)
+
+    /// When `karma` was last written or settled; `GlobalConfig.karma_decay` runs from here.
+    /// 0 until the first write, and karma doesn't decay before that.
+    /// UPDATED BY PROGRAM during: every karma write, badge mint and `settle_karma`.
+    pub last_karma_update: i64, // 8 bytes (taken from `reserved`)
+
+    /// Whether the user has a `KarmaHistory` PDA. Karma writes must then pass it.
+    /// SET BY USER using: `initialize_karma_history` instruction.
+    pub has_karma_history: bool, // 1 byte (taken from `reserved`)
+
+    /// The UTC day (days since the Unix epoch) `karma_gifted_today` is counted for.
+    /// UPDATED BY PROGRAM during: `gift_karma` instruction.
+    pub gift_day: u32, // 4 bytes (taken from `reserved`)
+
+    /// Karma this user has given away during `gift_day`, capped by `KarmaGiftRules.daily_cap`.
+    /// UPDATED BY PROGRAM during: `gift_karma` instruction.
+    pub karma_gifted_today: u32, // 4 bytes (taken from `reserved`)
+
+    /// Zeroed space kept for future fields, so new counters don't need a realloc.
+    /// A new field takes its bytes from here and must treat zero as its default.
+    pub reserved: [u8; 14], // 14 bytes
+}
+
+impl UserAccount {
+    /// Called when the karma oracle writes karma directly during Merkle `epoch`,
+    /// so neither a voucher signed before the write nor a proof from that epoch
+    /// or earlier can be redeemed afterwards and undo it.
+    pub fn supersede_karma_claims(&mut self, epoch: u64) -> Result<()> {
+        self.karma_nonce = self
+            .karma_nonce
+            .checked_add(1)
+            .ok_or(VoixError::MathOverflow)?;
+        self.karma_synced_epoch = self.karma_synced_epoch.max(epoch);
+        Ok(())
+    }
+}
+
+/// Why a karma delta was applied. Carried in `KarmaUpdated` for the backend's audit trail.
+/// `apply_karma_delta` rejects `Decay` and `Gift`, which only the program records.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KarmaReason {
+    Upvote,
+    Downvote,
+    Tip,
+    Moderation,
+    /// Karma lost to `GlobalConfig.karma_decay`, recorded whenever decay is settled
+    /// (every karma write, badge mint and the `settle_karma` crank).
+    Decay,
+    /// Karma moved from one user to another by `gift_karma`.
+    Gift,
 }
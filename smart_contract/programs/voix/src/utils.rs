@@ -0,0 +1,473 @@
+use crate::errors::VoixError;
+use crate::state::MilestoneTier;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::system_program::{
+    allocate, assign, create_account, transfer, Allocate, Assign, CreateAccount, Transfer,
+};
+use anchor_spl::metadata::{
+    create_master_edition_v3, create_metadata_accounts_v3, freeze_delegated_account,
+    update_metadata_accounts_v2, verify_sized_collection_item, CreateMasterEditionV3,
+    CreateMetadataAccountsV3, FreezeDelegatedAccount, UpdateMetadataAccountsV2,
+    VerifySizedCollectionItem,
+};
+use anchor_spl::token::{approve, mint_to, Approve, MintTo};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{
+    Field, TokenMetadata,
+};
+use anchor_spl::token_2022_extensions::{token_metadata_update_field, TokenMetadataUpdateField};
+use mpl_token_metadata::accounts::Metadata;
+use mpl_token_metadata::types::{Collection, Creator, DataV2};
+
+/// Grows `account` to `new_space` bytes (zero-filled) and tops up its lamports
+/// from `payer` so it stays rent-exempt at the new size.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_space: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_space);
+    let missing_lamports = required_lamports.saturating_sub(account.lamports());
+
+    if missing_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    account.resize(new_space)?;
+    Ok(())
+}
+
+/// Creates the PDA `account` with `space` bytes, owned by `owner` and holding at
+/// least `lamports`. A plain `create_account` fails once anyone has sent lamports
+/// to the address, so an already funded address is topped up, allocated and
+/// assigned instead.
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    owner: &Pubkey,
+    space: usize,
+    lamports: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    if account.lamports() == 0 {
+        return create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            lamports,
+            space as u64,
+            owner,
+        );
+    }
+
+    let missing_lamports = lamports.saturating_sub(account.lamports());
+    if missing_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer_seeds,
+        ),
+        owner,
+    )
+}
+
+/// The accounts `mint_badge_nft` works with. `user` receives the badge, pays for
+/// it and must sign; the rest are the accounts of the same name in `MintMilestoneNft`.
+pub struct BadgeNftAccounts<'info> {
+    pub user: AccountInfo<'info>,
+    pub mint_authority: AccountInfo<'info>,
+    pub mint: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub metadata_account: AccountInfo<'info>,
+    pub master_edition_account: AccountInfo<'info>,
+    pub collection_mint: AccountInfo<'info>,
+    pub collection_metadata: AccountInfo<'info>,
+    pub collection_master_edition: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+    pub token_metadata_program: AccountInfo<'info>,
+}
+
+/// Mints a badge as a 1/1 NFT in the Voix collection: mints the single token,
+/// creates its metadata (the `mint_authority` PDA is update authority and verified
+/// creator) and master edition, and verifies it into the collection. A soulbound
+/// badge is then frozen in the user's wallet.
+pub fn mint_badge_nft(
+    accounts: &BadgeNftAccounts,
+    signer_seeds: &[&[&[u8]]],
+    name: String,
+    symbol: String,
+    uri: String,
+    soulbound: bool,
+) -> Result<()> {
+    // 1. CPI to Token Program: Mint 1 token to the user's ATA
+    mint_to(
+        CpiContext::new_with_signer(
+            accounts.token_program.clone(),
+            MintTo {
+                mint: accounts.mint.clone(),
+                to: accounts.token_account.clone(),
+                authority: accounts.mint_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        1, // Mint 1 token
+    )?;
+    msg!("Token minted.");
+
+    // 2. CPI to Metaplex: Create the Metadata Account
+    // The PDA is listed as the verified creator and the badge points at the Voix collection.
+    let data_v2 = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: Some(vec![Creator {
+            address: accounts.mint_authority.key(),
+            verified: true,
+            share: 100,
+        }]),
+        collection: Some(Collection {
+            verified: false,
+            key: accounts.collection_mint.key(),
+        }),
+        uses: None,
+    };
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program.clone(),
+            CreateMetadataAccountsV3 {
+                metadata: accounts.metadata_account.clone(),
+                mint: accounts.mint.clone(),
+                mint_authority: accounts.mint_authority.clone(),
+                payer: accounts.user.clone(),
+                update_authority: accounts.mint_authority.clone(),
+                system_program: accounts.system_program.clone(),
+                rent: accounts.rent.clone(),
+            },
+            signer_seeds,
+        ),
+        data_v2,
+        true,
+        true,
+        None,
+    )?;
+    msg!("Metadata created.");
+
+    // 3. CPI to Metaplex: Create the Master Edition Account
+    create_master_edition_v3(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program.clone(),
+            CreateMasterEditionV3 {
+                edition: accounts.master_edition_account.clone(),
+                mint: accounts.mint.clone(),
+                update_authority: accounts.mint_authority.clone(),
+                mint_authority: accounts.mint_authority.clone(),
+                payer: accounts.user.clone(),
+                metadata: accounts.metadata_account.clone(),
+                token_program: accounts.token_program.clone(),
+                system_program: accounts.system_program.clone(),
+                rent: accounts.rent.clone(),
+            },
+            signer_seeds,
+        ),
+        Some(0),
+    )?;
+    msg!("Master Edition created.");
+
+    // 4. CPI to Metaplex: Verify the badge as a member of the Voix collection
+    verify_sized_collection_item(
+        CpiContext::new_with_signer(
+            accounts.token_metadata_program.clone(),
+            VerifySizedCollectionItem {
+                payer: accounts.user.clone(),
+                metadata: accounts.metadata_account.clone(),
+                collection_authority: accounts.mint_authority.clone(),
+                collection_mint: accounts.collection_mint.clone(),
+                collection_metadata: accounts.collection_metadata.clone(),
+                collection_master_edition: accounts.collection_master_edition.clone(),
+            },
+            signer_seeds,
+        ),
+        None,
+    )?;
+    msg!("Collection verified.");
+
+    // 5. Soulbound badges: freeze the badge in the user's wallet.
+    // The master edition now owns the freeze authority, so we go through
+    // Token Metadata's delegate freeze with the PDA as the token delegate.
+    // That keeps `thaw_badge` possible later.
+    if soulbound {
+        approve(
+            CpiContext::new(
+                accounts.token_program.clone(),
+                Approve {
+                    to: accounts.token_account.clone(),
+                    delegate: accounts.mint_authority.clone(),
+                    authority: accounts.user.clone(),
+                },
+            ),
+            1,
+        )?;
+
+        freeze_delegated_account(CpiContext::new_with_signer(
+            accounts.token_metadata_program.clone(),
+            FreezeDelegatedAccount {
+                metadata: accounts.token_metadata_program.clone(),
+                delegate: accounts.mint_authority.clone(),
+                token_account: accounts.token_account.clone(),
+                edition: accounts.master_edition_account.clone(),
+                mint: accounts.mint.clone(),
+                token_program: accounts.token_program.clone(),
+            },
+            signer_seeds,
+        ))?;
+        msg!("Badge frozen (soulbound).");
+    }
+
+    Ok(())
+}
+
+/// Rewrites the name, symbol and/or URI of a badge whose update authority is the
+/// `mint_authority` PDA. `None` keeps the current value; creators, collection and
+/// royalties are passed back unchanged. Returns the badge mint and the new data.
+pub fn rewrite_badge_metadata<'info>(
+    metadata: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    token_metadata_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    name: Option<&String>,
+    symbol: Option<&String>,
+    uri: Option<&String>,
+) -> Result<(Pubkey, DataV2)> {
+    require_keys_eq!(
+        *metadata.owner,
+        mpl_token_metadata::ID,
+        VoixError::InvalidBadgeMetadata
+    );
+    let current = Metadata::safe_deserialize(&metadata.try_borrow_data()?)
+        .map_err(|_| error!(VoixError::InvalidBadgeMetadata))?;
+    require_keys_eq!(
+        current.update_authority,
+        mint_authority.key(),
+        VoixError::InvalidBadgeMetadata
+    );
+
+    // Token Metadata pads stored strings with NUL bytes.
+    let keep = |value: &str| value.trim_end_matches('\0').to_string();
+    let data = DataV2 {
+        name: name.cloned().unwrap_or_else(|| keep(&current.name)),
+        symbol: symbol.cloned().unwrap_or_else(|| keep(&current.symbol)),
+        uri: uri.cloned().unwrap_or_else(|| keep(&current.uri)),
+        seller_fee_basis_points: current.seller_fee_basis_points,
+        creators: current.creators,
+        collection: current.collection,
+        uses: current.uses,
+    };
+    MilestoneTier::validate_metadata(&data.name, &data.symbol, &data.uri)?;
+
+    update_metadata_accounts_v2(
+        CpiContext::new_with_signer(
+            token_metadata_program.clone(),
+            UpdateMetadataAccountsV2 {
+                metadata: metadata.clone(),
+                update_authority: mint_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        None,
+        Some(data.clone()),
+        None,
+        None,
+    )?;
+
+    Ok((current.mint, data))
+}
+
+/// Same as `rewrite_badge_metadata`, for a Token-2022 badge that keeps its metadata
+/// in its own mint account. Token-2022 resizes the mint for longer values but
+/// doesn't fund it, so `payer` tops up the rent first. Returns the new metadata.
+#[allow(clippy::too_many_arguments)]
+pub fn rewrite_badge_metadata_2022<'info>(
+    mint: &AccountInfo<'info>,
+    mint_authority: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    name: Option<&String>,
+    symbol: Option<&String>,
+    uri: Option<&String>,
+) -> Result<TokenMetadata> {
+    require_keys_eq!(
+        *mint.owner,
+        anchor_spl::token_2022::ID,
+        VoixError::InvalidBadgeMetadata
+    );
+    let current = {
+        let data = mint.try_borrow_data()?;
+        StateWithExtensions::<MintState>::unpack(&data)
+            .and_then(|state| state.get_variable_len_extension::<TokenMetadata>())
+            .map_err(|_| error!(VoixError::InvalidBadgeMetadata))?
+    };
+    require!(
+        Option::<Pubkey>::from(current.update_authority) == Some(mint_authority.key()),
+        VoixError::InvalidBadgeMetadata
+    );
+
+    let mut updated = current.clone();
+    updated.name = name.cloned().unwrap_or_else(|| current.name.clone());
+    updated.symbol = symbol.cloned().unwrap_or_else(|| current.symbol.clone());
+    updated.uri = uri.cloned().unwrap_or_else(|| current.uri.clone());
+    MilestoneTier::validate_metadata(&updated.name, &updated.symbol, &updated.uri)?;
+
+    let new_len = (mint.data_len() + updated.tlv_size_of()?).saturating_sub(current.tlv_size_of()?);
+    let missing_lamports = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(mint.lamports());
+    if missing_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: mint.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    for (field, value) in [
+        (Field::Name, name),
+        (Field::Symbol, symbol),
+        (Field::Uri, uri),
+    ] {
+        if let Some(value) = value {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    token_program.clone(),
+                    TokenMetadataUpdateField {
+                        program_id: token_program.clone(),
+                        metadata: mint.clone(),
+                        update_authority: mint_authority.clone(),
+                    },
+                    signer_seeds,
+                ),
+                field,
+                value.clone(),
+            )?;
+        }
+    }
+
+    Ok(updated)
+}
+
+/// Checks that the instruction right before the current one is an ed25519
+/// precompile call that verified `message` signed by `signer`. The precompile
+/// has already checked the signature itself; this makes sure it checked the
+/// right key and the right message.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, VoixError::InvalidVoucherSignature);
+    let ix = load_instruction_at_checked(usize::from(current_index - 1), instructions_sysvar)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        VoixError::InvalidVoucherSignature
+    );
+
+    // Layout: [num_signatures: u8, padding: u8], then one set of u16 offsets:
+    // signature, signature ix, public key, public key ix, message, message size, message ix.
+    let data = &ix.data;
+    require!(
+        data.len() >= 16 && data[0] == 1,
+        VoixError::InvalidVoucherSignature
+    );
+    let read_u16 = |at: usize| usize::from(u16::from_le_bytes([data[at], data[at + 1]]));
+
+    // Every part must come from the precompile instruction itself (index u16::MAX),
+    // not from another instruction we haven't looked at.
+    let this_ix = usize::from(u16::MAX);
+    require!(
+        read_u16(4) == this_ix && read_u16(8) == this_ix && read_u16(14) == this_ix,
+        VoixError::InvalidVoucherSignature
+    );
+
+    let (public_key_offset, message_offset, message_size) =
+        (read_u16(6), read_u16(10), read_u16(12));
+    let signed_key = data.get(public_key_offset..public_key_offset + 32);
+    let signed_message = data.get(message_offset..message_offset + message_size);
+    require!(
+        signed_key == Some(signer.as_ref()) && signed_message == Some(message),
+        VoixError::InvalidVoucherSignature
+    );
+    Ok(())
+}
+
+/// Folds `leaf` up through `proof` (sorted-pair sha256 nodes) and checks that
+/// it ends at `root`.
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
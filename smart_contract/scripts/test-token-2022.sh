@@ -0,0 +1,35 @@
+#!/usr/bin/env bash
+# Runs the Token-2022 badge tests ("S. Token-2022 Badges") on a local validator
+# without the mainnet clone of Token Metadata that `anchor test` sets up.
+#
+# Only the sections the Token-2022 tests build on run as well: "A." creates the
+# config and User 1, "D." creates User 2.
+#
+# Usage: scripts/test-token-2022.sh (from anywhere; builds the program first)
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+PROGRAM_ID="41ixdTNskpBiezYZyjigB5k3MTgdv3CcFHYFuVPy3mbK"
+WALLET="./admin_key.json"
+RPC_URL="http://127.0.0.1:8899"
+LEDGER="$(mktemp -d)"
+
+anchor build
+
+solana-test-validator \
+  --reset \
+  --quiet \
+  --ledger "$LEDGER" \
+  --mint "$(solana-keygen pubkey "$WALLET")" \
+  --bpf-program "$PROGRAM_ID" target/deploy/voix.so &
+VALIDATOR_PID=$!
+trap 'kill "$VALIDATOR_PID"; rm -rf "$LEDGER"' EXIT
+
+until solana cluster-version --url "$RPC_URL" >/dev/null 2>&1; do
+  sleep 1
+done
+
+ANCHOR_PROVIDER_URL="$RPC_URL" ANCHOR_WALLET="$WALLET" \
+  yarn run ts-mocha -p ./tsconfig.json -t 10000000 tests/**/*.ts \
+  --grep "A\. Config|D\. Tip User SOL|S\. Token-2022"
@@ -14,8 +14,12 @@ import {
   mintTo,
   getAccount,
   createAssociatedTokenAccountInstruction,
+  TOKEN_2022_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getTokenMetadata,
 } from "@solana/spl-token";
 import * as assert from "assert/strict";
+import { createHash } from "crypto";
 import { Voix } from "../target/types/voix";
 
 const { BN } = anchor;
@@ -41,6 +45,15 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
   const CONFIG_SEED = Buffer.from("config");
   const USER_SEED = Buffer.from("user");
   const MINT_AUTHORITY_SEED = Buffer.from("mint_authority");
+  const COUNCIL_SEED = Buffer.from("council");
+  const COUNCIL_PROPOSAL_SEED = Buffer.from("council_proposal");
+  const MILESTONE_REGISTRY_SEED = Buffer.from("milestone_registry");
+  const BADGE_COLLECTION_SEED = Buffer.from("badge_collection");
+  const BADGE_SEED = Buffer.from("badge");
+  const MILESTONE_RECORD_SEED = Buffer.from("milestone_record");
+  const ACHIEVEMENT_SEED = Buffer.from("achievement");
+  const ACHIEVEMENT_BADGE_SEED = Buffer.from("achievement_badge");
+  const MERKLE_EPOCH_SEED = Buffer.from("merkle_epoch");
   const programId = program.programId;
 
   // Global Config PDA
@@ -55,6 +68,41 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
     programId
   );
 
+  // Council PDA
+  const [councilPda] = PublicKey.findProgramAddressSync(
+    [COUNCIL_SEED],
+    programId
+  );
+
+  // Milestone Registry PDA
+  const [milestoneRegistryPda] = PublicKey.findProgramAddressSync(
+    [MILESTONE_REGISTRY_SEED],
+    programId
+  );
+
+  // Badge Collection mint PDA and its Metaplex accounts
+  const [badgeCollectionMintPda] = PublicKey.findProgramAddressSync(
+    [BADGE_COLLECTION_SEED],
+    programId
+  );
+  const [badgeCollectionMetadataPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("metadata"),
+      MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      badgeCollectionMintPda.toBuffer(),
+    ],
+    MPL_TOKEN_METADATA_PROGRAM_ID
+  );
+  const [badgeCollectionEditionPda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("metadata"),
+      MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+      badgeCollectionMintPda.toBuffer(),
+      Buffer.from("edition"),
+    ],
+    MPL_TOKEN_METADATA_PROGRAM_ID
+  );
+
   // PDA for User 1
   const [user1AccountPda] = PublicKey.findProgramAddressSync(
     [USER_SEED, user1.publicKey.toBuffer()],
@@ -68,11 +116,94 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
   );
 
   // --- Helper Functions ---
+  // Each submitted root is kept in a PDA seeded by its epoch (u64 LE)
+  const merkleEpochPda = (epoch: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [MERKLE_EPOCH_SEED, epoch.toArrayLike(Buffer, "le", 8)],
+      programId
+    )[0];
+
   const airdrop = async (publicKey: PublicKey, amount: number) => {
     const tx = await connection.requestAirdrop(publicKey, amount);
     await connection.confirmTransaction(tx, "confirmed");
   };
 
+  // Badge mints and milestone records are PDAs of [seed, user, level].
+  // A milestone re-minted after a revocation gets the revocation count as an extra seed.
+  const getBadgeMintPda = (user: PublicKey, level: number, revocations = 0) =>
+    PublicKey.findProgramAddressSync(
+      revocations === 0
+        ? [BADGE_SEED, user.toBuffer(), Buffer.from([level])]
+        : [BADGE_SEED, user.toBuffer(), Buffer.from([level]), Buffer.from([revocations])],
+      programId
+    )[0];
+
+  const getMilestoneRecordPda = (user: PublicKey, level: number) =>
+    PublicKey.findProgramAddressSync(
+      [MILESTONE_RECORD_SEED, user.toBuffer(), Buffer.from([level])],
+      programId
+    )[0];
+
+  const getCouncilProposalPda = (id: anchor.BN) =>
+    PublicKey.findProgramAddressSync(
+      [COUNCIL_PROPOSAL_SEED, id.toArrayLike(Buffer, "le", 8)],
+      programId
+    )[0];
+
+  // Runs `action` through a 1-of-1 council of the admin, then turns council mode off again.
+  // `accounts` and `remainingAccounts` are passed on to `execute_council_proposal`.
+  const runCouncilAction = async (
+    action: any,
+    accounts: any = {},
+    remainingAccounts: anchor.web3.AccountMeta[] = []
+  ) => {
+    const propose = async (proposalAction: any) => {
+      const council = await program.account.council.fetch(councilPda);
+      const proposal = getCouncilProposalPda(council.proposalCount);
+      await program.methods
+        .createCouncilProposal(proposalAction)
+        .accounts({
+          proposer: admin.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          proposal,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      return proposal;
+    };
+    const execute = (proposal: PublicKey, extraAccounts: any = {}, extraRemaining = []) =>
+      program.methods
+        .executeCouncilProposal()
+        .accounts({
+          executor: admin.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          proposal,
+          ...extraAccounts,
+        })
+        .remainingAccounts(extraRemaining)
+        .signers([admin])
+        .rpc();
+
+    await program.methods
+      .enableCouncil([admin.publicKey], 1)
+      .accounts({
+        admin: admin.publicKey,
+        globalConfig: globalConfigPda,
+        council: councilPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+    try {
+      await execute(await propose(action), accounts, remainingAccounts);
+    } finally {
+      await execute(await propose({ disableCouncil: {} }));
+    }
+  };
+
   // --- Initialization and Setup ---
   before(async () => {
     // Airdrop SOL for all users for rent and transactions
@@ -94,6 +225,45 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       })
       .signers([admin])
       .rpc();
+
+    // Create the milestone registry with the default Bronze/Silver/Gold tiers
+    await program.methods
+      .initializeMilestoneRegistry()
+      .accounts({
+        admin: admin.publicKey,
+        globalConfig: globalConfigPda,
+        milestoneRegistry: milestoneRegistryPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    // Create the Voix collection that every milestone badge is verified into.
+    // Runs without Token Metadata (scripts/test-token-2022.sh) skip it.
+    if (await connection.getAccountInfo(MPL_TOKEN_METADATA_PROGRAM_ID)) {
+      await program.methods
+        .createBadgeCollection("Voix Milestones", "VOIX", "https://voix.com/metadata/collection")
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          mintAuthority: mintAuthorityPda,
+          collectionMint: badgeCollectionMintPda,
+          collectionTokenAccount: await getAssociatedTokenAddress(
+            badgeCollectionMintPda,
+            mintAuthorityPda,
+            true
+          ),
+          metadataAccount: badgeCollectionMetadataPda,
+          masterEditionAccount: badgeCollectionEditionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+    }
   });
 
   // ======================================================================
@@ -114,6 +284,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         0,
         "Initial epoch must be 0"
       );
+      assert.equal(configAccount.version, 1, "Config version must be 1");
     });
 
     it("2. initialize_config: Fails on re-initialization (Account already in use)", async () => {
@@ -139,7 +310,8 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .initializeUser()
         .accounts({
           user: user1.publicKey,
-          admin : admin.publicKey,
+          userSponsor: admin.publicKey,
+          globalConfig: globalConfigPda,
           userAccount: user1AccountPda,
           systemProgram: SystemProgram.programId,
         })
@@ -155,9 +327,10 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         userAccount.userPubkey.equals(user1.publicKey),
         "User Pubkey mismatch"
       );
+      assert.equal(userAccount.version, 1, "User account version must be 1");
       assert.equal(userAccount.karma, 0, "Initial karma should be 0");
       assert.equal(
-        userAccount.mintedMilestones,
+        userAccount.mintedMilestones.toNumber(),
         0,
         "Initial milestones should be 0"
       );
@@ -179,10 +352,12 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
     it("1. submit_merkle_root: Admin submits the first Merkle root (epoch 1)", async () => {
       const newEpoch = new BN(1);
       await program.methods
-        .submitMerkleRoot(Array.from(root1) as [number, ...number[]], newEpoch)
+        .submitMerkleRoot(Array.from(root1) as [number, ...number[]], newEpoch, new BN(1000), null)
         .accounts({
-          admin: admin.publicKey,
+          merklePublisher: admin.publicKey,
           globalConfig: globalConfigPda,
+          merkleEpoch: merkleEpochPda(newEpoch),
+          systemProgram: SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
@@ -201,10 +376,12 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
     it("2. submit_merkle_root: Admin submits a new root with sequential epoch (epoch 2)", async () => {
       const newEpoch = new BN(2);
       await program.methods
-        .submitMerkleRoot(Array.from(root2) as [number, ...number[]], newEpoch)
+        .submitMerkleRoot(Array.from(root2) as [number, ...number[]], newEpoch, new BN(2000), null)
         .accounts({
-          admin: admin.publicKey,
+          merklePublisher: admin.publicKey,
           globalConfig: globalConfigPda,
+          merkleEpoch: merkleEpochPda(newEpoch),
+          systemProgram: SystemProgram.programId,
         })
         .signers([admin])
         .rpc();
@@ -220,24 +397,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       );
     });
 
-    it("3. submit_merkle_root: Fails if non-admin attempts submission (VoixError::Unauthorized)", async () => {
+    it("3. submit_merkle_root: Fails if a non-publisher attempts submission (VoixError::MissingMerklePublisherRole)", async () => {
       const newEpoch = new BN(3);
       await assert.rejects(
         program.methods
           .submitMerkleRoot(
             Array.from(root1) as [number, ...number[]],
-            newEpoch
+            newEpoch,
+            new BN(1000),
+            null
           )
           .accounts({
-            admin: nonAdmin.publicKey, // Wrong admin
+            merklePublisher: nonAdmin.publicKey, // Not the Merkle publisher
             globalConfig: globalConfigPda,
+            merkleEpoch: merkleEpochPda(newEpoch),
+            systemProgram: SystemProgram.programId,
           })
           .signers([nonAdmin])
           .rpc(),
         (e: anchor.AnchorError) => {
-          return e.error.errorCode.code === "Unauthorized";
+          return e.error.errorCode.code === "MissingMerklePublisherRole";
         },
-        "Should have failed with VoixError::Unauthorized"
+        "Should have failed with VoixError::MissingMerklePublisherRole"
       );
     });
 
@@ -247,11 +428,15 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         program.methods
           .submitMerkleRoot(
             Array.from(root1) as [number, ...number[]],
-            invalidEpoch
+            invalidEpoch,
+            new BN(1000),
+            null
           )
           .accounts({
-            admin: admin.publicKey,
+            merklePublisher: admin.publicKey,
             globalConfig: globalConfigPda,
+            merkleEpoch: merkleEpochPda(invalidEpoch),
+            systemProgram: SystemProgram.programId,
           })
           .signers([admin])
           .rpc(),
@@ -261,6 +446,43 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         "Should have failed with VoixError::InvalidEpoch"
       );
     });
+
+    it("5. submit_merkle_root: Every epoch's root stays readable after a newer one is submitted", async () => {
+      const epoch1 = await program.account.merkleEpoch.fetch(merkleEpochPda(new BN(1)));
+      const epoch2 = await program.account.merkleEpoch.fetch(merkleEpochPda(new BN(2)));
+
+      assert.ok(Buffer.from(epoch1.merkleRoot).equals(root1), "Epoch 1 root was overwritten");
+      assert.ok(Buffer.from(epoch2.merkleRoot).equals(root2));
+      assert.ok(epoch1.leafCount.eq(new BN(1000)));
+      assert.ok(epoch1.publisher.equals(admin.publicKey));
+      assert.ok(epoch2.submittedSlot.gte(epoch1.submittedSlot));
+      assert.equal(epoch1.treeUri, null);
+    });
+
+    it("6. submit_merkle_root: Fails for a tree URI over the length limit (VoixError::TreeUriTooLong)", async () => {
+      const newEpoch = new BN(3);
+      await assert.rejects(
+        program.methods
+          .submitMerkleRoot(
+            Array.from(root1) as [number, ...number[]],
+            newEpoch,
+            new BN(1000),
+            "ipfs://" + "a".repeat(200)
+          )
+          .accounts({
+            merklePublisher: admin.publicKey,
+            globalConfig: globalConfigPda,
+            merkleEpoch: merkleEpochPda(newEpoch),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "TreeUriTooLong";
+        },
+        "Should have failed with VoixError::TreeUriTooLong"
+      );
+    });
   });
 
   // ======================================================================
@@ -272,7 +494,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       await program.methods
         .updateUserKarma(newKarma)
         .accounts({
-          admin: admin.publicKey,
+          karmaOracle: admin.publicKey,
           globalConfig: globalConfigPda,
           userAccount: user1AccountPda,
           userToUpdate: user1.publicKey,
@@ -290,13 +512,13 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       );
     });
 
-    it("2. update_user_karma: Fails if a non-admin attempts to update karma (VoixError::Unauthorized)", async () => {
+    it("2. update_user_karma: Fails if a non-oracle attempts to update karma (VoixError::MissingKarmaOracleRole)", async () => {
       const newKarma = 999;
       await assert.rejects(
         program.methods
           .updateUserKarma(newKarma)
           .accounts({
-            admin: nonAdmin.publicKey, // Wrong admin
+            karmaOracle: nonAdmin.publicKey, // Not the karma oracle
             globalConfig: globalConfigPda,
             userAccount: user1AccountPda,
             userToUpdate: user1.publicKey,
@@ -304,9 +526,9 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
           .signers([nonAdmin])
           .rpc(),
         (e: anchor.AnchorError) => {
-          return e.error.errorCode.code === "Unauthorized";
+          return e.error.errorCode.code === "MissingKarmaOracleRole";
         },
-        "Should have failed with VoixError::Unauthorized"
+        "Should have failed with VoixError::MissingKarmaOracleRole"
       );
     });
   });
@@ -331,7 +553,8 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
           .initializeUser()
           .accounts({
             user: user2.publicKey,
-            admin : admin.publicKey , 
+            userSponsor: admin.publicKey,
+            globalConfig: globalConfigPda,
             userAccount: user2AccountPda,
             systemProgram: SystemProgram.programId,
           })
@@ -358,6 +581,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .tipUserSol(tipAmountBN)
         .accounts({
           tipper: user1.publicKey,
+          globalConfig: globalConfigPda,
           receiver: user2.publicKey,
           receiverAccount: user2AccountPda,
           systemProgram: SystemProgram.programId,
@@ -395,6 +619,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
           .tipUserSol(new BN(0))
           .accounts({
             tipper: user1.publicKey,
+            globalConfig: globalConfigPda,
             receiver: user2.publicKey,
             receiverAccount: user2AccountPda,
             systemProgram: SystemProgram.programId,
@@ -517,6 +742,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .tipUserSpl(tipAmount)
         .accounts({
           tipper: user1.publicKey,
+          globalConfig: globalConfigPda,
           receiver: user2.publicKey,
           receiverAccount: user2AccountPda,
           mint: mint,
@@ -588,6 +814,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .tipUserSpl(secondTipAmount)
         .accounts({
           tipper: user1.publicKey,
+          globalConfig: globalConfigPda,
           receiver: user2.publicKey,
           receiverAccount: user2AccountPda,
           mint: mint,
@@ -639,6 +866,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
           .tipUserSpl(new BN(0))
           .accounts({
             tipper: user1.publicKey,
+            globalConfig: globalConfigPda,
             receiver: user2.publicKey,
             receiverAccount: user2AccountPda,
             mint: mint,
@@ -668,20 +896,17 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
   // F. Mint Milestone NFT
   // ======================================================================
   describe("F. Mint Milestone NFT", () => {
-    // NFT metadata constants
+    // Milestone levels of the default tiers (metadata comes from the registry)
     const BRONZE_LEVEL = 1;
     const SILVER_LEVEL = 2;
     const GOLD_LEVEL = 3;
-    const NFT_NAME = "Voix Milestone";
-    const NFT_SYMBOL = "VOIX";
-    const NFT_URI = "https://voix.com/metadata/";
 
-    // Karma Requirements from programs/voix/src/constants.rs
+    // Default karma requirements seeded by initialize_milestone_registry
     const BRONZE_KARMA_REQ = 1000;
     const SILVER_KARMA_REQ = 5000;
     const GOLD_KARMA_REQ = 10000;
 
-    // Milestone bit-flags from programs/voix/src/constants.rs
+    // Milestone bit-flags (1 << flag_index) of the default tiers
     const BRONZE_FLAG = 1;
     const SILVER_FLAG = 2;
     const GOLD_FLAG = 4; // Added for clarity
@@ -715,7 +940,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       await program.methods
         .updateUserKarma(0)
         .accounts({
-          admin: admin.publicKey,
+          karmaOracle: admin.publicKey,
           globalConfig: globalConfigPda,
           userAccount: user2AccountPda,
           userToUpdate: user2.publicKey,
@@ -723,8 +948,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .signers([admin])
         .rpc();
 
-      const mintKeypair = Keypair.generate();
-      const mint = mintKeypair.publicKey;
+      const mint = getBadgeMintPda(user2.publicKey, BRONZE_LEVEL);
 
       const { metadataAccount, masterEditionAccount } = await getMetaplexPDAs(
         mint
@@ -736,27 +960,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
 
       await assert.rejects(
         program.methods
-          .mintMilestoneNft(
-            BRONZE_LEVEL,
-            `${NFT_NAME} Bronze`,
-            NFT_SYMBOL,
-            `${NFT_URI}bronze`
-          )
+          .mintMilestoneNft(BRONZE_LEVEL)
           .accounts({
             user: user2.publicKey,
+            globalConfig: globalConfigPda,
+            milestoneRegistry: milestoneRegistryPda,
             userAccount: user2AccountPda,
             mintAuthority: mintAuthorityPda,
             mint: mint,
             tokenAccount: tokenAccount,
+            milestoneRecord: getMilestoneRecordPda(user2.publicKey, BRONZE_LEVEL),
             metadataAccount: metadataAccount,
             masterEditionAccount: masterEditionAccount,
+            collectionMint: badgeCollectionMintPda,
+            collectionMetadata: badgeCollectionMetadataPda,
+            collectionMasterEdition: badgeCollectionEditionPda,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY, // Rent sysvar
             tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
           })
-          .signers([user2, mintKeypair])
+          .signers([user2])
           .rpc(), 
         (e: anchor.AnchorError) => {
           return e.error.errorCode.code === "InsufficientKarma";
@@ -766,8 +991,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
     });
 
     it("2. mint_milestone_nft: Fails with Invalid Milestone Level", async () => {
-      const mintKeypair = Keypair.generate();
-      const mint = mintKeypair.publicKey;
+      const mint = getBadgeMintPda(user2.publicKey, invalidLevel);
 
       const { metadataAccount, masterEditionAccount } = await getMetaplexPDAs(
         mint
@@ -780,27 +1004,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
 
       await assert.rejects(
         program.methods
-          .mintMilestoneNft(
-            invalidLevel,
-            `${NFT_NAME} Invalid`,
-            NFT_SYMBOL,
-            `${NFT_URI}invalid`
-          )
+          .mintMilestoneNft(invalidLevel)
           .accounts({
             user: user2.publicKey,
+            globalConfig: globalConfigPda,
+            milestoneRegistry: milestoneRegistryPda,
             userAccount: user2AccountPda,
             mintAuthority: mintAuthorityPda,
             mint: mint,
             tokenAccount: tokenAccount,
+            milestoneRecord: getMilestoneRecordPda(user2.publicKey, invalidLevel),
             metadataAccount: metadataAccount,
             masterEditionAccount: masterEditionAccount,
+            collectionMint: badgeCollectionMintPda,
+            collectionMetadata: badgeCollectionMetadataPda,
+            collectionMasterEdition: badgeCollectionEditionPda,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY,
             tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
           })
-          .signers([user2, mintKeypair])
+          .signers([user2])
           .rpc(), 
         (e: anchor.AnchorError) => {
           return e.error.errorCode.code === "InvalidMilestoneLevel";
@@ -815,7 +1040,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       await program.methods
         .updateUserKarma(bronzeKarma)
         .accounts({
-          admin: admin.publicKey,
+          karmaOracle: admin.publicKey,
           globalConfig: globalConfigPda,
           userAccount: user2AccountPda,
           userToUpdate: user2.publicKey,
@@ -824,8 +1049,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .rpc();
 
       // 2. Prepare accounts and mint
-      const mintKeypair = Keypair.generate();
-      const mint = mintKeypair.publicKey;
+      const mint = getBadgeMintPda(user2.publicKey, BRONZE_LEVEL);
       const { metadataAccount, masterEditionAccount } = await getMetaplexPDAs(
         mint
       );
@@ -835,27 +1059,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       );
 
       await program.methods
-        .mintMilestoneNft(
-          BRONZE_LEVEL,
-          `${NFT_NAME} Bronze`,
-          NFT_SYMBOL,
-          `${NFT_URI}bronze`
-        )
+        .mintMilestoneNft(BRONZE_LEVEL)
         .accounts({
           user: user2.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
           userAccount: user2AccountPda,
           mintAuthority: mintAuthorityPda,
           mint: mint,
           tokenAccount: tokenAccount,
+          milestoneRecord: getMilestoneRecordPda(user2.publicKey, BRONZE_LEVEL),
           metadataAccount: metadataAccount,
           masterEditionAccount: masterEditionAccount,
+          collectionMint: badgeCollectionMintPda,
+          collectionMetadata: badgeCollectionMetadataPda,
+          collectionMasterEdition: badgeCollectionEditionPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
         })
-        .signers([user2, mintKeypair])
+        .signers([user2])
         .rpc(); 
 
       // 3. Verify state changes
@@ -866,13 +1091,26 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         "1",
         "User should own 1 NFT token"
       );
+      assert.equal(
+        tokenAccountInfo.isFrozen,
+        true,
+        "Badges are soulbound by default, so the token account should be frozen"
+      );
+
+      // b. Check the milestone record points at the deterministic badge mint
+      const record = await program.account.milestoneRecord.fetch(
+        getMilestoneRecordPda(user2.publicKey, BRONZE_LEVEL)
+      );
+      assert.ok(record.mint.equals(mint), "Record should store the badge mint");
+      assert.equal(record.level, BRONZE_LEVEL);
+      assert.equal(record.karma, BRONZE_KARMA_REQ, "Record should store the karma at mint time");
 
-      // b. Check user account milestones
+      // c. Check user account milestones
       const userAccountAfterMint = await program.account.userAccount.fetch(
         user2AccountPda
       );
       assert.equal(
-        userAccountAfterMint.mintedMilestones,
+        userAccountAfterMint.mintedMilestones.toNumber(),
         BRONZE_FLAG,
         "Bronze flag should be set (value 1)"
       );
@@ -882,7 +1120,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       await program.methods
         .updateUserKarma(BRONZE_KARMA_REQ)
         .accounts({
-          admin: admin.publicKey,
+          karmaOracle: admin.publicKey,
           globalConfig: globalConfigPda,
           userAccount: user2AccountPda,
           userToUpdate: user2.publicKey,
@@ -890,8 +1128,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
         .signers([admin])
         .rpc();
 
-      const mintKeypair = Keypair.generate(); // Need a new mint to simulate re-mint attempt
-      const mint = mintKeypair.publicKey;
+      const mint = getBadgeMintPda(user2.publicKey, BRONZE_LEVEL);
 
       const { metadataAccount, masterEditionAccount } = await getMetaplexPDAs(
         mint
@@ -904,27 +1141,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       // We rely on the PDA persistence from Test 3's successful state update.
       await assert.rejects(
         program.methods
-          .mintMilestoneNft(
-            BRONZE_LEVEL,
-            `${NFT_NAME} Bronze Duplicate`,
-            NFT_SYMBOL,
-            `${NFT_URI}bronze_dup`
-          )
+          .mintMilestoneNft(BRONZE_LEVEL)
           .accounts({
             user: user2.publicKey,
+            globalConfig: globalConfigPda,
+            milestoneRegistry: milestoneRegistryPda,
             userAccount: user2AccountPda,
             mintAuthority: mintAuthorityPda,
             mint: mint,
             tokenAccount: tokenAccount,
+            milestoneRecord: getMilestoneRecordPda(user2.publicKey, BRONZE_LEVEL),
             metadataAccount: metadataAccount,
             masterEditionAccount: masterEditionAccount,
+            collectionMint: badgeCollectionMintPda,
+            collectionMetadata: badgeCollectionMetadataPda,
+            collectionMasterEdition: badgeCollectionEditionPda,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
             rent: anchor.web3.SYSVAR_RENT_PUBKEY,
             tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
           })
-          .signers([user2, mintKeypair])
+          .signers([user2])
           .rpc(), 
         (e: anchor.AnchorError) => {
           return e.error.errorCode.code === "MilestoneAlreadyMinted";
@@ -939,7 +1177,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       await program.methods
         .updateUserKarma(silverKarma)
         .accounts({
-          admin: admin.publicKey,
+          karmaOracle: admin.publicKey,
           globalConfig: globalConfigPda,
           userAccount: user2AccountPda,
           userToUpdate: user2.publicKey,
@@ -949,8 +1187,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
 
       
       // 3. Prepare accounts and mint Silver
-      const mintKeypair = Keypair.generate();
-      const mint = mintKeypair.publicKey;
+      const mint = getBadgeMintPda(user2.publicKey, SILVER_LEVEL);
       const { metadataAccount, masterEditionAccount } = await getMetaplexPDAs(
         mint
       );
@@ -960,27 +1197,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       );
 
       await program.methods
-        .mintMilestoneNft(
-          SILVER_LEVEL,
-          `${NFT_NAME} Silver`,
-          NFT_SYMBOL,
-          `${NFT_URI}silver`
-        )
+        .mintMilestoneNft(SILVER_LEVEL)
         .accounts({
           user: user2.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
           userAccount: user2AccountPda,
           mintAuthority: mintAuthorityPda,
           mint: mint,
           tokenAccount: tokenAccount,
+          milestoneRecord: getMilestoneRecordPda(user2.publicKey, SILVER_LEVEL),
           metadataAccount: metadataAccount,
           masterEditionAccount: masterEditionAccount,
+          collectionMint: badgeCollectionMintPda,
+          collectionMetadata: badgeCollectionMetadataPda,
+          collectionMasterEdition: badgeCollectionEditionPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
         })
-        .signers([user2, mintKeypair])
+        .signers([user2])
         .rpc(); 
       // 4. Verify state changes
       const userAccountAfterMint = await program.account.userAccount.fetch(
@@ -988,7 +1226,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       );
       const expectedFlags = BRONZE_FLAG | SILVER_FLAG; // 1 | 2 = 3
       assert.equal(
-        userAccountAfterMint.mintedMilestones,
+        userAccountAfterMint.mintedMilestones.toNumber(),
         expectedFlags,
         "Milestone flags should be Bronze and Silver (value 3)"
       );
@@ -1000,7 +1238,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       await program.methods
         .updateUserKarma(goldKarma)
         .accounts({
-          admin: admin.publicKey,
+          karmaOracle: admin.publicKey,
           globalConfig: globalConfigPda,
           userAccount: user2AccountPda,
           userToUpdate: user2.publicKey,
@@ -1010,8 +1248,7 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
 
       
       // 3. Prepare accounts and mint Gold
-      const mintKeypair = Keypair.generate();
-      const mint = mintKeypair.publicKey;
+      const mint = getBadgeMintPda(user2.publicKey, GOLD_LEVEL);
       const { metadataAccount, masterEditionAccount } = await getMetaplexPDAs(
         mint
       );
@@ -1021,27 +1258,28 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       );
 
       await program.methods
-        .mintMilestoneNft(
-          GOLD_LEVEL,
-          `${NFT_NAME} Gold`,
-          NFT_SYMBOL,
-          `${NFT_URI}gold`
-        )
+        .mintMilestoneNft(GOLD_LEVEL)
         .accounts({
           user: user2.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
           userAccount: user2AccountPda,
           mintAuthority: mintAuthorityPda,
           mint: mint,
           tokenAccount: tokenAccount,
+          milestoneRecord: getMilestoneRecordPda(user2.publicKey, GOLD_LEVEL),
           metadataAccount: metadataAccount,
           masterEditionAccount: masterEditionAccount,
+          collectionMint: badgeCollectionMintPda,
+          collectionMetadata: badgeCollectionMetadataPda,
+          collectionMasterEdition: badgeCollectionEditionPda,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: anchor.web3.SYSVAR_RENT_PUBKEY,
           tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
         })
-        .signers([user2, mintKeypair])
+        .signers([user2])
         .rpc(); 
 
       // 4. Verify state changes
@@ -1051,10 +1289,3488 @@ describe("voix: Contract Tests (Comprehensive Suite)", () => {
       // We assert that the Gold flag (4) is set, in addition to the Bronze(1) and Silver(2) flags.
       const expectedFlags = BRONZE_FLAG | SILVER_FLAG | GOLD_FLAG; // 1 | 2 | 4 = 7
       assert.equal(
-        userAccountAfterMint.mintedMilestones,
+        userAccountAfterMint.mintedMilestones.toNumber(),
         expectedFlags,
         "Milestone flags should be Bronze, Silver, and Gold (value 7)"
       );
     });
   });
+
+  // ======================================================================
+  // G. Admin Handover
+  // ======================================================================
+  describe("G. Admin Handover", () => {
+    const newAdmin = Keypair.generate();
+
+    before(async () => {
+      await airdrop(newAdmin.publicKey, 2 * LAMPORTS_PER_SOL);
+    });
+
+    it("1. accept_admin: Fails when no handover has been proposed (VoixError::NoPendingAdmin)", async () => {
+      await assert.rejects(
+        program.methods
+          .acceptAdmin()
+          .accounts({
+            newAdmin: newAdmin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([newAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "NoPendingAdmin";
+        },
+        "Should have failed with VoixError::NoPendingAdmin"
+      );
+    });
+
+    it("2. propose_admin: Fails if a non-admin proposes (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .proposeAdmin(nonAdmin.publicKey)
+          .accounts({
+            admin: nonAdmin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("3. cancel_admin_proposal: Admin proposes and then cancels a handover", async () => {
+      await program.methods
+        .proposeAdmin(newAdmin.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      let configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.ok(
+        configAccount.pendingAdmin.equals(newAdmin.publicKey),
+        "Pending admin was not recorded"
+      );
+
+      await program.methods
+        .cancelAdminProposal()
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      configAccount = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.equal(
+        configAccount.pendingAdmin,
+        null,
+        "Pending admin should be cleared"
+      );
+    });
+
+    it("4. accept_admin: Fails if someone other than the nominee accepts (VoixError::Unauthorized)", async () => {
+      await program.methods
+        .proposeAdmin(newAdmin.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      await assert.rejects(
+        program.methods
+          .acceptAdmin()
+          .accounts({
+            newAdmin: nonAdmin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("5. accept_admin: Nominee accepts and admin-gated instructions follow the new key", async () => {
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: newAdmin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([newAdmin])
+        .rpc();
+
+      const configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.ok(
+        configAccount.admin.equals(newAdmin.publicKey),
+        "Admin was not handed over"
+      );
+      assert.equal(configAccount.pendingAdmin, null);
+
+      // The old admin is now rejected by `has_one = admin`
+      await assert.rejects(
+        program.methods
+          .grantRole({ pauser: {} }, nonAdmin.publicKey)
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Old admin should no longer be authorized"
+      );
+
+      // Hand the role back so the remaining suites keep using `admin`
+      await program.methods
+        .proposeAdmin(admin.publicKey)
+        .accounts({
+          admin: newAdmin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([newAdmin])
+        .rpc();
+      await program.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+    });
+  });
+
+  // ======================================================================
+  // H. Role Management
+  // ======================================================================
+  describe("H. Role Management", () => {
+    const oracle = Keypair.generate();
+
+    before(async () => {
+      await airdrop(oracle.publicKey, 2 * LAMPORTS_PER_SOL);
+    });
+
+    it("1. initialize_config: Every role starts out with the admin", async () => {
+      const configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.ok(configAccount.karmaOracle.equals(admin.publicKey));
+      assert.ok(configAccount.merklePublisher.equals(admin.publicKey));
+      assert.ok(configAccount.userSponsor.equals(admin.publicKey));
+      assert.ok(configAccount.pauser.equals(admin.publicKey));
+    });
+
+    it("2. grant_role: Fails if a non-admin grants a role (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .grantRole({ karmaOracle: {} }, nonAdmin.publicKey)
+          .accounts({
+            admin: nonAdmin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("3. grant_role: The new karma oracle can write karma and the admin no longer can", async () => {
+      await program.methods
+        .grantRole({ karmaOracle: {} }, oracle.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      await program.methods
+        .updateUserKarma(42)
+        .accounts({
+          karmaOracle: oracle.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          userToUpdate: user1.publicKey,
+        })
+        .signers([oracle])
+        .rpc();
+
+      const userAccount = await program.account.userAccount.fetch(
+        user1AccountPda
+      );
+      assert.equal(userAccount.karma, 42);
+
+      await assert.rejects(
+        program.methods
+          .updateUserKarma(43)
+          .accounts({
+            karmaOracle: admin.publicKey,
+            globalConfig: globalConfigPda,
+            userAccount: user1AccountPda,
+            userToUpdate: user1.publicKey,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MissingKarmaOracleRole";
+        },
+        "Should have failed with VoixError::MissingKarmaOracleRole"
+      );
+    });
+
+    it("4. revoke_role: A revoked role can no longer be used", async () => {
+      await program.methods
+        .revokeRole({ karmaOracle: {} })
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      await assert.rejects(
+        program.methods
+          .updateUserKarma(44)
+          .accounts({
+            karmaOracle: oracle.publicKey,
+            globalConfig: globalConfigPda,
+            userAccount: user1AccountPda,
+            userToUpdate: user1.publicKey,
+          })
+          .signers([oracle])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MissingKarmaOracleRole";
+        },
+        "Should have failed with VoixError::MissingKarmaOracleRole"
+      );
+
+      // Give the role back to the admin for the remaining suites
+      await program.methods
+        .grantRole({ karmaOracle: {} }, admin.publicKey)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([admin])
+        .rpc();
+    });
+  });
+
+  // ======================================================================
+  // I. Admin Council
+  // ======================================================================
+  describe("I. Admin Council", () => {
+    const memberA = Keypair.generate();
+    const memberB = Keypair.generate();
+    const outsider = Keypair.generate();
+    let leftoverProposal: PublicKey;
+
+    // Creates a proposal for `action` signed by `proposer` and returns its PDA
+    const createProposal = async (proposer: Keypair, action: any) => {
+      const council = await program.account.council.fetch(councilPda);
+      const proposalPda = getCouncilProposalPda(council.proposalCount);
+      await program.methods
+        .createCouncilProposal(action)
+        .accounts({
+          proposer: proposer.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          proposal: proposalPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([proposer])
+        .rpc();
+      return proposalPda;
+    };
+
+    const approveProposal = (member: Keypair, proposalPda: PublicKey) =>
+      program.methods
+        .approveCouncilProposal()
+        .accounts({
+          member: member.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          proposal: proposalPda,
+        })
+        .signers([member])
+        .rpc();
+
+    const executeProposal = (executor: Keypair, proposalPda: PublicKey) =>
+      program.methods
+        .executeCouncilProposal()
+        .accounts({
+          executor: executor.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          proposal: proposalPda,
+        })
+        .signers([executor])
+        .rpc();
+
+    before(async () => {
+      await Promise.all([
+        airdrop(memberA.publicKey, 2 * LAMPORTS_PER_SOL),
+        airdrop(memberB.publicKey, 2 * LAMPORTS_PER_SOL),
+        airdrop(outsider.publicKey, 2 * LAMPORTS_PER_SOL),
+      ]);
+    });
+
+    it("1. enable_council: Fails with a threshold above the member count (VoixError::InvalidCouncilConfig)", async () => {
+      await assert.rejects(
+        program.methods
+          .enableCouncil([admin.publicKey, memberA.publicKey], 3)
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+            council: councilPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidCouncilConfig";
+        },
+        "Should have failed with VoixError::InvalidCouncilConfig"
+      );
+    });
+
+    it("2. enable_council: Admin enables a 2-of-3 council and loses direct control", async () => {
+      await program.methods
+        .enableCouncil(
+          [admin.publicKey, memberA.publicKey, memberB.publicKey],
+          2
+        )
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.equal(configAccount.councilEnabled, true);
+
+      await assert.rejects(
+        program.methods
+          .grantRole({ pauser: {} }, memberA.publicKey)
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "CouncilModeActive";
+        },
+        "Should have failed with VoixError::CouncilModeActive"
+      );
+    });
+
+    it("3. create_council_proposal: Fails for a non-member (VoixError::NotCouncilMember)", async () => {
+      const council = await program.account.council.fetch(councilPda);
+      await assert.rejects(
+        program.methods
+          .createCouncilProposal({ disableCouncil: {} })
+          .accounts({
+            proposer: outsider.publicKey,
+            globalConfig: globalConfigPda,
+            council: councilPda,
+            proposal: getCouncilProposalPda(council.proposalCount),
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([outsider])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "NotCouncilMember";
+        },
+        "Should have failed with VoixError::NotCouncilMember"
+      );
+    });
+
+    it("4. execute_council_proposal: Grants a role only once the threshold is met", async () => {
+      const proposalPda = await createProposal(memberA, {
+        grantRole: { role: { pauser: {} }, authority: memberB.publicKey },
+      });
+
+      // One approval (the proposer's) is not enough for a 2-of-3 council
+      await assert.rejects(
+        executeProposal(outsider, proposalPda),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProposalThresholdNotMet";
+        },
+        "Should have failed with VoixError::ProposalThresholdNotMet"
+      );
+
+      await assert.rejects(
+        approveProposal(memberA, proposalPda),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProposalAlreadyApproved";
+        },
+        "Should have failed with VoixError::ProposalAlreadyApproved"
+      );
+
+      await approveProposal(memberB, proposalPda);
+      await executeProposal(outsider, proposalPda);
+
+      const configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.ok(configAccount.pauser.equals(memberB.publicKey));
+
+      await assert.rejects(
+        executeProposal(outsider, proposalPda),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProposalAlreadyExecuted";
+        },
+        "Should have failed with VoixError::ProposalAlreadyExecuted"
+      );
+    });
+
+    it("5. execute_council_proposal: The council restores the pauser and disables itself", async () => {
+      const grantBack = await createProposal(admin, {
+        grantRole: { role: { pauser: {} }, authority: admin.publicKey },
+      });
+      await approveProposal(memberA, grantBack);
+      await executeProposal(admin, grantBack);
+
+      // Left open when the council goes away
+      leftoverProposal = await createProposal(memberA, {
+        setAdmin: { newAdmin: memberA.publicKey },
+      });
+
+      const disable = await createProposal(admin, { disableCouncil: {} });
+      await approveProposal(memberB, disable);
+      await executeProposal(admin, disable);
+
+      const configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.equal(configAccount.councilEnabled, false);
+      assert.ok(configAccount.pauser.equals(admin.publicKey));
+    });
+
+    it("6. approve_council_proposal: Fails for a proposal left over from an earlier council (VoixError::ProposalStale)", async () => {
+      await program.methods
+        .enableCouncil([admin.publicKey, memberA.publicKey, memberB.publicKey], 2)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          council: councilPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      await assert.rejects(
+        approveProposal(memberB, leftoverProposal),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProposalStale";
+        },
+        "Should have failed with VoixError::ProposalStale"
+      );
+      const proposal = await program.account.councilProposal.fetch(leftoverProposal);
+      assert.ok(proposal.expiresAt.gt(proposal.createdAt));
+    });
+
+    it("7. create_council_proposal: Fails to propose the current admin (VoixError::InvalidAdminProposal)", async () => {
+      await assert.rejects(
+        createProposal(memberA, { setAdmin: { newAdmin: admin.publicKey } }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidAdminProposal";
+        },
+        "Should have failed with VoixError::InvalidAdminProposal"
+      );
+
+      // Disable the council again for later suites
+      const disable = await createProposal(admin, { disableCouncil: {} });
+      await approveProposal(memberB, disable);
+      await executeProposal(admin, disable);
+    });
+  });
+
+  // ======================================================================
+  // J. Pause Switch
+  // ======================================================================
+  describe("J. Pause Switch", () => {
+    const PAUSE_TIPS = 1;
+    const PAUSE_MINTS = 2;
+    const PAUSE_KARMA = 8;
+
+    const setPaused = (flags: number, signer: Keypair = admin) =>
+      program.methods
+        .setPaused(flags)
+        .accounts({
+          pauser: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    const tipSol = () =>
+      program.methods
+        .tipUserSol(new BN(1000))
+        .accounts({
+          tipper: user1.publicKey,
+          globalConfig: globalConfigPda,
+          receiver: user2.publicKey,
+          receiverAccount: user2AccountPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+    // Mints user2's Bronze badge; only used while mints can't go through.
+    const mintBronze = async () => {
+      const mint = getBadgeMintPda(user2.publicKey, 1);
+      const [metadataAccount] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+        ],
+        MPL_TOKEN_METADATA_PROGRAM_ID
+      );
+      const [masterEditionAccount] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+          Buffer.from("edition"),
+        ],
+        MPL_TOKEN_METADATA_PROGRAM_ID
+      );
+      return program.methods
+        .mintMilestoneNft(1)
+        .accounts({
+          user: user2.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
+          userAccount: user2AccountPda,
+          mintAuthority: mintAuthorityPda,
+          mint: mint,
+          tokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+          milestoneRecord: getMilestoneRecordPda(user2.publicKey, 1),
+          metadataAccount: metadataAccount,
+          masterEditionAccount: masterEditionAccount,
+          collectionMint: badgeCollectionMintPda,
+          collectionMetadata: badgeCollectionMetadataPda,
+          collectionMasterEdition: badgeCollectionEditionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+    };
+
+    it("1. set_paused: Fails if a non-pauser changes the flags (VoixError::MissingPauserRole)", async () => {
+      await assert.rejects(
+        setPaused(PAUSE_MINTS, nonAdmin),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MissingPauserRole";
+        },
+        "Should have failed with VoixError::MissingPauserRole"
+      );
+    });
+
+    it("2. set_paused: Pausing mints blocks minting but not tips", async () => {
+      await setPaused(PAUSE_MINTS);
+
+      const configAccount = await program.account.globalConfig.fetch(
+        globalConfigPda
+      );
+      assert.equal(configAccount.pausedFlags, PAUSE_MINTS);
+
+      // Tips keep working
+      await tipSol();
+
+      // Mints are stopped before any karma or milestone checks run
+      await assert.rejects(
+        mintBronze(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProgramPaused";
+        },
+        "Should have failed with VoixError::ProgramPaused"
+      );
+    });
+
+    it("3. set_paused: Pausing tips blocks tip_user_sol until resumed", async () => {
+      await setPaused(PAUSE_TIPS);
+      await assert.rejects(
+        tipSol(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProgramPaused";
+        },
+        "Should have failed with VoixError::ProgramPaused"
+      );
+
+      await setPaused(0);
+      await tipSol();
+    });
+
+    it("4. set_paused: Rejects unknown flag bits (VoixError::InvalidPauseFlags)", async () => {
+      await assert.rejects(
+        setPaused(0x80),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidPauseFlags";
+        },
+        "Should have failed with VoixError::InvalidPauseFlags"
+      );
+    });
+
+    it("5. set_paused: Pausing karma writes also blocks minting (decay can't be saved)", async () => {
+      await setPaused(PAUSE_KARMA);
+      await assert.rejects(
+        mintBronze(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProgramPaused";
+        },
+        "Should have failed with VoixError::ProgramPaused"
+      );
+
+      await setPaused(0);
+    });
+  });
+
+  // ======================================================================
+  // K. Account Migration
+  // ======================================================================
+  describe("K. Account Migration", () => {
+    it("1. migrate_config: Fails on an account that already uses the current layout (VoixError::AccountAlreadyMigrated)", async () => {
+      await assert.rejects(
+        program.methods
+          .migrateConfig()
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "AccountAlreadyMigrated";
+        },
+        "Should have failed with VoixError::AccountAlreadyMigrated"
+      );
+    });
+
+    it("2. migrate_user_account: Fails if the payer is neither the user nor the admin (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .migrateUserAccount()
+          .accounts({
+            payer: nonAdmin.publicKey,
+            globalConfig: globalConfigPda,
+            user: user1.publicKey,
+            userAccount: user1AccountPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("3. migrate_user_account: Fails on an account that already uses the current layout (VoixError::AccountAlreadyMigrated)", async () => {
+      await assert.rejects(
+        program.methods
+          .migrateUserAccount()
+          .accounts({
+            payer: user1.publicKey,
+            globalConfig: globalConfigPda,
+            user: user1.publicKey,
+            userAccount: user1AccountPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "AccountAlreadyMigrated";
+        },
+        "Should have failed with VoixError::AccountAlreadyMigrated"
+      );
+    });
+
+    // A version 0 `UserAccount` loaded by the validator from
+    // tests/fixtures/legacy_user_account.json (see Anchor.toml):
+    // 1200 karma, milestones 0 and 1, 2 SOL tipped.
+    const legacyUser = new PublicKey(
+      "EZTgNLx6Gd9EhEJks1w7hB6mMZ1ZGz9LzqELEwfWQGoz"
+    );
+    const [legacyUserAccountPda] = PublicKey.findProgramAddressSync(
+      [USER_SEED, legacyUser.toBuffer()],
+      programId
+    );
+
+    it("4. migrate_user_account: Success (Admin migrates a version 0 account and pays the extra rent)", async () => {
+      const legacyInfo = await connection.getAccountInfo(
+        legacyUserAccountPda
+      );
+      assert.ok(legacyInfo, "The legacy fixture account must be loaded");
+      assert.equal(
+        legacyInfo.data.length,
+        8 + 45,
+        "Fixture must use the version 0 layout"
+      );
+      const adminBalanceBefore = await connection.getBalance(admin.publicKey);
+
+      await program.methods
+        .migrateUserAccount()
+        .accounts({
+          payer: admin.publicKey,
+          globalConfig: globalConfigPda,
+          user: legacyUser,
+          userAccount: legacyUserAccountPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      // Realloc: the account now has the current size and is rent exempt
+      const migratedInfo = await connection.getAccountInfo(
+        legacyUserAccountPda
+      );
+      const currentSize = program.account.userAccount.size;
+      assert.equal(
+        migratedInfo.data.length,
+        currentSize,
+        "Account must be resized to the current layout"
+      );
+      const rentExempt = await connection.getMinimumBalanceForRentExemption(
+        currentSize
+      );
+      assert.equal(
+        migratedInfo.lamports,
+        rentExempt,
+        "Account must hold exactly the new rent"
+      );
+
+      // The admin pays only the missing rent (the provider wallet pays the fee)
+      const adminBalanceAfter = await connection.getBalance(admin.publicKey);
+      assert.equal(
+        adminBalanceBefore - adminBalanceAfter,
+        rentExempt - legacyInfo.lamports,
+        "Admin must pay the extra rent"
+      );
+
+      // Old values are kept, new fields start at their defaults
+      const account = await program.account.userAccount.fetch(
+        legacyUserAccountPda
+      );
+      assert.equal(account.version, 1, "User account version must be 1");
+      assert.ok(
+        account.userPubkey.equals(legacyUser),
+        "User pubkey must be kept"
+      );
+      assert.equal(account.karma, 1200, "Karma must be kept");
+      assert.ok(
+        account.mintedMilestones.eq(new BN(0b11)),
+        "Minted milestones must be kept"
+      );
+      assert.ok(
+        account.totalSolTipped.eq(new BN(2 * LAMPORTS_PER_SOL)),
+        "Tip total must be kept"
+      );
+      assert.ok(
+        account.badgeMint.equals(PublicKey.default),
+        "Badge mint must start unset"
+      );
+      assert.equal(account.badgeLevel, 0, "Badge level must start at 0");
+      assert.ok(account.tipsSent.eq(new BN(0)), "Tips sent must start at 0");
+      assert.ok(
+        account.karmaNonce.eq(new BN(0)),
+        "Karma nonce must start at 0"
+      );
+      assert.ok(
+        account.karmaSyncedEpoch.eq(new BN(0)),
+        "Synced epoch must start at 0"
+      );
+      assert.equal(
+        account.hasKarmaHistory,
+        false,
+        "Karma history must start disabled"
+      );
+    });
+
+    it("5. migrate_user_account: Fails on a second migration (VoixError::AccountAlreadyMigrated)", async () => {
+      await assert.rejects(
+        program.methods
+          .migrateUserAccount()
+          .accounts({
+            payer: admin.publicKey,
+            globalConfig: globalConfigPda,
+            user: legacyUser,
+            userAccount: legacyUserAccountPda,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "AccountAlreadyMigrated";
+        },
+        "Should have failed with VoixError::AccountAlreadyMigrated"
+      );
+    });
+  });
+
+  // ======================================================================
+  // L. Milestone Registry
+  // ======================================================================
+  describe("L. Milestone Registry", () => {
+    const platinumTier = {
+      level: 4,
+      karmaRequired: 20000,
+      name: "Voix Milestone Platinum",
+      symbol: "VOIX",
+      uri: "https://voix.com/metadata/platinum",
+      flagIndex: 3,
+      retired: false,
+    };
+
+    const registryAccounts = (signer: Keypair) => ({
+      admin: signer.publicKey,
+      globalConfig: globalConfigPda,
+      milestoneRegistry: milestoneRegistryPda,
+    });
+
+    it("1. initialize_milestone_registry: Seeds the default Bronze/Silver/Gold tiers", async () => {
+      const registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.deepEqual(
+        registry.tiers.map((t) => [t.level, t.karmaRequired, t.flagIndex]),
+        [
+          [1, 1000, 0],
+          [2, 5000, 1],
+          [3, 10000, 2],
+        ]
+      );
+    });
+
+    it("2. add_milestone_tier: Fails if a non-admin adds a tier (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .addMilestoneTier(platinumTier)
+          .accounts(registryAccounts(nonAdmin))
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("3. add_milestone_tier: Admin adds a fourth tier beyond the old hard-coded levels", async () => {
+      await program.methods
+        .addMilestoneTier(platinumTier)
+        .accounts(registryAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      const registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers.length, 4);
+      assert.equal(registry.tiers[3].karmaRequired, 20000);
+    });
+
+    it("4. add_milestone_tier: Fails for an existing level (VoixError::MilestoneTierExists)", async () => {
+      await assert.rejects(
+        program.methods
+          .addMilestoneTier({ ...platinumTier, flagIndex: 9 })
+          .accounts(registryAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MilestoneTierExists";
+        },
+        "Should have failed with VoixError::MilestoneTierExists"
+      );
+    });
+
+    it("5. update_milestone_tier: Edits the karma requirement but refuses to move the flag (VoixError::InvalidMilestoneFlag)", async () => {
+      await program.methods
+        .updateMilestoneTier({ ...platinumTier, karmaRequired: 25000 })
+        .accounts(registryAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      const registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers[3].karmaRequired, 25000);
+
+      await assert.rejects(
+        program.methods
+          .updateMilestoneTier({ ...platinumTier, flagIndex: 4 })
+          .accounts(registryAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidMilestoneFlag";
+        },
+        "Should have failed with VoixError::InvalidMilestoneFlag"
+      );
+    });
+
+    it("6. retire_milestone_tier: Marks the tier as retired", async () => {
+      await program.methods
+        .retireMilestoneTier(platinumTier.level)
+        .accounts(registryAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      const registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers[3].retired, true);
+    });
+
+    it("7. add_milestone_tier: Fails for level 0, which means \"no badge\" (VoixError::InvalidMilestoneLevel)", async () => {
+      await assert.rejects(
+        program.methods
+          .addMilestoneTier({ ...platinumTier, level: 0, flagIndex: 9 })
+          .accounts(registryAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidMilestoneLevel";
+        },
+        "Should have failed with VoixError::InvalidMilestoneLevel"
+      );
+    });
+  });
+
+  // ======================================================================
+  // M. Canonical Badge Metadata
+  // ======================================================================
+  describe("M. Canonical Badge Metadata", () => {
+    const BRONZE_LEVEL = 1;
+    const BRONZE_NAME = "Voix Milestone Bronze";
+    const BRONZE_SYMBOL = "VOIX";
+    const BRONZE_URI = "https://voix.com/metadata/bronze";
+
+    const metadataAccounts = (signer: Keypair) => ({
+      admin: signer.publicKey,
+      globalConfig: globalConfigPda,
+      milestoneRegistry: milestoneRegistryPda,
+    });
+
+    it("1. update_milestone_metadata: Fails if a non-admin changes the metadata (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .updateMilestoneMetadata(BRONZE_LEVEL, "Fake Gold", "FAKE", "https://evil.example/gold.json")
+          .accounts(metadataAccounts(nonAdmin))
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. update_milestone_metadata: Fails for an oversize name (VoixError::BadgeNameTooLong)", async () => {
+      await assert.rejects(
+        program.methods
+          .updateMilestoneMetadata(BRONZE_LEVEL, "X".repeat(33), BRONZE_SYMBOL, BRONZE_URI)
+          .accounts(metadataAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeNameTooLong";
+        },
+        "Should have failed with VoixError::BadgeNameTooLong"
+      );
+    });
+
+    it("3. update_milestone_metadata: Fails for an oversize symbol (VoixError::BadgeSymbolTooLong)", async () => {
+      await assert.rejects(
+        program.methods
+          .updateMilestoneMetadata(BRONZE_LEVEL, BRONZE_NAME, "X".repeat(11), BRONZE_URI)
+          .accounts(metadataAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeSymbolTooLong";
+        },
+        "Should have failed with VoixError::BadgeSymbolTooLong"
+      );
+    });
+
+    it("4. add_milestone_tier: Fails for an oversize URI (VoixError::BadgeUriTooLong)", async () => {
+      await assert.rejects(
+        program.methods
+          .addMilestoneTier({
+            level: 9,
+            karmaRequired: 50000,
+            name: "Voix Milestone Diamond",
+            symbol: "VOIX",
+            uri: `https://voix.com/${"x".repeat(200)}`,
+            flagIndex: 8,
+            retired: false,
+          })
+          .accounts(metadataAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeUriTooLong";
+        },
+        "Should have failed with VoixError::BadgeUriTooLong"
+      );
+    });
+
+    it("5. update_milestone_metadata: Admin updates the canonical metadata of a tier", async () => {
+      const newUri = "https://voix.com/metadata/v2/bronze";
+      await program.methods
+        .updateMilestoneMetadata(BRONZE_LEVEL, BRONZE_NAME, BRONZE_SYMBOL, newUri)
+        .accounts(metadataAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      let registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers[0].uri, newUri);
+      assert.equal(registry.tiers[0].karmaRequired, 1000, "Only the metadata should change");
+
+      // Restore the original metadata for later suites
+      await program.methods
+        .updateMilestoneMetadata(BRONZE_LEVEL, BRONZE_NAME, BRONZE_SYMBOL, BRONZE_URI)
+        .accounts(metadataAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers[0].uri, BRONZE_URI);
+    });
+
+    it("6. update_milestone_metadata: Fails for an empty name or URI (VoixError::BadgeNameEmpty / BadgeUriEmpty)", async () => {
+      await assert.rejects(
+        program.methods
+          .updateMilestoneMetadata(BRONZE_LEVEL, "", BRONZE_SYMBOL, BRONZE_URI)
+          .accounts(metadataAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeNameEmpty";
+        },
+        "Should have failed with VoixError::BadgeNameEmpty"
+      );
+      await assert.rejects(
+        program.methods
+          .updateMilestoneMetadata(BRONZE_LEVEL, BRONZE_NAME, BRONZE_SYMBOL, "")
+          .accounts(metadataAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeUriEmpty";
+        },
+        "Should have failed with VoixError::BadgeUriEmpty"
+      );
+    });
+
+    it("7. execute_council_proposal: The council updates the canonical metadata of a tier", async () => {
+      const newUri = "https://voix.com/metadata/v3/bronze";
+      await runCouncilAction(
+        {
+          updateMilestoneMetadata: {
+            level: BRONZE_LEVEL,
+            name: BRONZE_NAME,
+            symbol: BRONZE_SYMBOL,
+            uri: newUri,
+          },
+        },
+        { milestoneRegistry: milestoneRegistryPda }
+      );
+
+      let registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers[0].uri, newUri);
+
+      // Restore the original metadata for later suites
+      await program.methods
+        .updateMilestoneMetadata(BRONZE_LEVEL, BRONZE_NAME, BRONZE_SYMBOL, BRONZE_URI)
+        .accounts(metadataAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.tiers[0].uri, BRONZE_URI);
+    });
+  });
+
+  // ======================================================================
+  // N. Badge Collection
+  // ======================================================================
+  describe("N. Badge Collection", () => {
+    it("1. create_badge_collection: Stores the collection mint on GlobalConfig", async () => {
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.ok(config.badgeCollection.equals(badgeCollectionMintPda));
+
+      const collectionMetadata = await connection.getAccountInfo(
+        badgeCollectionMetadataPda
+      );
+      assert.ok(
+        collectionMetadata.owner.equals(MPL_TOKEN_METADATA_PROGRAM_ID),
+        "The collection metadata should be owned by Token Metadata"
+      );
+
+      const collectionToken = await getAccount(
+        connection,
+        await getAssociatedTokenAddress(badgeCollectionMintPda, mintAuthorityPda, true)
+      );
+      assert.equal(collectionToken.amount, BigInt(1), "The PDA should hold the collection NFT");
+    });
+
+    it("2. create_badge_collection: Fails if the collection already exists (VoixError::BadgeCollectionExists)", async () => {
+      await assert.rejects(
+        program.methods
+          .createBadgeCollection("Voix Milestones", "VOIX", "https://voix.com/metadata/collection")
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+            mintAuthority: mintAuthorityPda,
+            collectionMint: badgeCollectionMintPda,
+            collectionTokenAccount: await getAssociatedTokenAddress(
+              badgeCollectionMintPda,
+              mintAuthorityPda,
+              true
+            ),
+            metadataAccount: badgeCollectionMetadataPda,
+            masterEditionAccount: badgeCollectionEditionPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+          })
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeCollectionExists";
+        },
+        "Should have failed with VoixError::BadgeCollectionExists"
+      );
+    });
+  });
+
+  // ======================================================================
+  // O. Soulbound Badges
+  // ======================================================================
+  describe("O. Soulbound Badges", () => {
+    const GOLD_LEVEL = 3;
+
+    const registryAccounts = (signer: Keypair) => ({
+      admin: signer.publicKey,
+      globalConfig: globalConfigPda,
+      milestoneRegistry: milestoneRegistryPda,
+    });
+
+    // Finds one of User 2's badges minted in section F (all frozen by default)
+    const findFrozenBadge = async () => {
+      const { value } = await connection.getParsedTokenAccountsByOwner(
+        user2.publicKey,
+        { programId: TOKEN_PROGRAM_ID }
+      );
+      const badge = value.find(
+        ({ account }) => account.data.parsed.info.state === "frozen"
+      );
+      assert.ok(badge, "User 2 should hold a frozen badge");
+      return {
+        tokenAccount: badge.pubkey,
+        mint: new PublicKey(badge.account.data.parsed.info.mint),
+      };
+    };
+
+    const thawAccounts = (signer: Keypair, mint: PublicKey, tokenAccount: PublicKey) => ({
+      admin: signer.publicKey,
+      globalConfig: globalConfigPda,
+      badge: {
+        mintAuthority: mintAuthorityPda,
+        mint,
+        tokenAccount,
+        masterEditionAccount: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("metadata"),
+            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            mint.toBuffer(),
+            Buffer.from("edition"),
+          ],
+          MPL_TOKEN_METADATA_PROGRAM_ID
+        )[0],
+        tokenProgram: TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+      },
+    });
+
+    it("1. set_tier_transferable: Fails if a non-admin changes a tier (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .setTierTransferable(GOLD_LEVEL, true)
+          .accounts(registryAccounts(nonAdmin))
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. set_tier_transferable: Admin toggles a tier between transferable and soulbound", async () => {
+      await program.methods
+        .setTierTransferable(GOLD_LEVEL, true)
+        .accounts(registryAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      let registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.transferableFlags.toNumber(), 4, "Gold (flag index 2) should be transferable");
+
+      await program.methods
+        .setTierTransferable(GOLD_LEVEL, false)
+        .accounts(registryAccounts(admin))
+        .signers([admin])
+        .rpc();
+
+      registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      assert.equal(registry.transferableFlags.toNumber(), 0);
+    });
+
+    it("3. thaw_badge: Fails if a non-admin thaws a badge (VoixError::Unauthorized)", async () => {
+      const { mint, tokenAccount } = await findFrozenBadge();
+      await assert.rejects(
+        program.methods
+          .thawBadge()
+          .accounts(thawAccounts(nonAdmin, mint, tokenAccount))
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("4. thaw_badge: Admin unfreezes a soulbound badge", async () => {
+      const { mint, tokenAccount } = await findFrozenBadge();
+      await program.methods
+        .thawBadge()
+        .accounts(thawAccounts(admin, mint, tokenAccount))
+        .signers([admin])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(connection, tokenAccount);
+      assert.equal(tokenAccountInfo.isFrozen, false, "The badge should be thawed");
+
+      // Thawing it a second time is rejected
+      await assert.rejects(
+        program.methods
+          .thawBadge()
+          .accounts(thawAccounts(admin, mint, tokenAccount))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "BadgeNotFrozen";
+        },
+        "Should have failed with VoixError::BadgeNotFrozen"
+      );
+    });
+
+    it("5. thaw_badge: The council thaws a badge, passing its accounts as remaining accounts", async () => {
+      const { mint, tokenAccount } = await findFrozenBadge();
+      // The badge's accounts, without the admin and global config of `thaw_badge`
+      const badgeAccounts = (
+        await program.methods
+          .thawBadge()
+          .accounts(thawAccounts(admin, mint, tokenAccount))
+          .instruction()
+      ).keys.slice(2);
+
+      // A proposal naming another badge can't be run with these accounts
+      await assert.rejects(
+        runCouncilAction({ thawBadge: { mint: Keypair.generate().publicKey } }, {}, badgeAccounts),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "CouncilAccountsMismatch";
+        },
+        "Should have failed with VoixError::CouncilAccountsMismatch"
+      );
+
+      await runCouncilAction({ thawBadge: { mint } }, {}, badgeAccounts);
+
+      const tokenAccountInfo = await getAccount(connection, tokenAccount);
+      assert.equal(tokenAccountInfo.isFrozen, false, "The council should have thawed the badge");
+    });
+  });
+
+  // ======================================================================
+  // P. Reputation Badge
+  // ======================================================================
+  describe("P. Reputation Badge", () => {
+    const BRONZE_LEVEL = 1;
+    const SILVER_LEVEL = 2;
+    const GOLD_LEVEL = 3;
+    const reputationMint = getBadgeMintPda(user1.publicKey, BRONZE_LEVEL);
+
+    const getMetadataPda = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+        ],
+        MPL_TOKEN_METADATA_PROGRAM_ID
+      )[0];
+
+    const setBadgeMode = (signer: Keypair, mode: any) =>
+      program.methods
+        .setBadgeMode(mode)
+        .accounts({
+          admin: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    const mintBadge = async (level: number) => {
+      const mint = getBadgeMintPda(user1.publicKey, level);
+      return program.methods
+        .mintMilestoneNft(level)
+        .accounts({
+          user: user1.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
+          userAccount: user1AccountPda,
+          mintAuthority: mintAuthorityPda,
+          mint,
+          tokenAccount: await getAssociatedTokenAddress(mint, user1.publicKey),
+          milestoneRecord: getMilestoneRecordPda(user1.publicKey, level),
+          metadataAccount: getMetadataPda(mint),
+          masterEditionAccount: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("metadata"),
+              MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+              mint.toBuffer(),
+              Buffer.from("edition"),
+            ],
+            MPL_TOKEN_METADATA_PROGRAM_ID
+          )[0],
+          collectionMint: badgeCollectionMintPda,
+          collectionMetadata: badgeCollectionMetadataPda,
+          collectionMasterEdition: badgeCollectionEditionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+    };
+
+    const upgradeBadge = async (level: number) =>
+      program.methods
+        .upgradeReputationBadge(level)
+        .accounts({
+          user: user1.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
+          userAccount: user1AccountPda,
+          mintAuthority: mintAuthorityPda,
+          badgeMint: reputationMint,
+          badgeTokenAccount: await getAssociatedTokenAddress(
+            reputationMint,
+            user1.publicKey
+          ),
+          metadataAccount: getMetadataPda(reputationMint),
+          milestoneRecord: getMilestoneRecordPda(user1.publicKey, level),
+          systemProgram: SystemProgram.programId,
+          tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        })
+        .signers([user1])
+        .rpc();
+
+    before(async () => {
+      // Give User 1 enough karma for Silver, but not Gold
+      await program.methods
+        .updateUserKarma(5000)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          userToUpdate: user1.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("1. set_badge_mode: Fails if a non-admin changes the badge mode (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        setBadgeMode(nonAdmin, { reputation: {} }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. upgrade_reputation_badge: Fails in per-milestone mode (VoixError::WrongBadgeMode)", async () => {
+      await assert.rejects(
+        upgradeBadge(SILVER_LEVEL),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "WrongBadgeMode";
+        },
+        "Should have failed with VoixError::WrongBadgeMode"
+      );
+    });
+
+    it("3. mint_milestone_nft: In reputation mode, records the single badge on the user account", async () => {
+      await setBadgeMode(admin, { reputation: {} });
+      await mintBadge(BRONZE_LEVEL);
+
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.ok(userAccount.badgeMint.equals(reputationMint));
+      assert.equal(userAccount.badgeLevel, BRONZE_LEVEL);
+      assert.equal(userAccount.mintedMilestones.toNumber(), 1);
+    });
+
+    it("4. mint_milestone_nft: Fails to mint a second reputation badge (VoixError::ReputationBadgeExists)", async () => {
+      await assert.rejects(
+        mintBadge(SILVER_LEVEL),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ReputationBadgeExists";
+        },
+        "Should have failed with VoixError::ReputationBadgeExists"
+      );
+    });
+
+    it("5. upgrade_reputation_badge: Rewrites the same badge to Silver", async () => {
+      await upgradeBadge(SILVER_LEVEL);
+
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.equal(userAccount.badgeLevel, SILVER_LEVEL);
+      assert.equal(userAccount.mintedMilestones.toNumber(), 3, "Bronze and Silver bits should be set");
+      assert.ok(userAccount.badgeMint.equals(reputationMint), "The badge mint should not change");
+
+      // The Silver record points at the same single badge
+      const record = await program.account.milestoneRecord.fetch(
+        getMilestoneRecordPda(user1.publicKey, SILVER_LEVEL)
+      );
+      assert.ok(record.mint.equals(reputationMint));
+    });
+
+    it("6. upgrade_reputation_badge: Fails without enough karma for Gold (VoixError::InsufficientKarma)", async () => {
+      await assert.rejects(
+        upgradeBadge(GOLD_LEVEL),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InsufficientKarma";
+        },
+        "Should have failed with VoixError::InsufficientKarma"
+      );
+    });
+
+    it("7. upgrade_reputation_badge: Restores a revoked lower level without rewriting the badge", async () => {
+      await program.methods
+        .revokeMilestone(BRONZE_LEVEL, 1)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          milestone: {
+            milestoneRegistry: milestoneRegistryPda,
+            user: user1.publicKey,
+            userAccount: user1AccountPda,
+            milestoneRecord: getMilestoneRecordPda(user1.publicKey, BRONZE_LEVEL),
+            mintAuthority: mintAuthorityPda,
+            mint: reputationMint,
+            tokenAccount: await getAssociatedTokenAddress(
+              reputationMint,
+              user1.publicKey
+            ),
+            masterEditionAccount: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("metadata"),
+                MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+                reputationMint.toBuffer(),
+                Buffer.from("edition"),
+              ],
+              MPL_TOKEN_METADATA_PROGRAM_ID
+            )[0],
+            metadataAccount: getMetadataPda(reputationMint),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+          },
+        })
+        .signers([admin])
+        .rpc();
+
+      let userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.equal(userAccount.mintedMilestones.toNumber(), 2, "Only the Silver bit should be left");
+      assert.equal(userAccount.badgeLevel, SILVER_LEVEL, "The badge should still show Silver");
+
+      await upgradeBadge(BRONZE_LEVEL);
+
+      userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.equal(userAccount.mintedMilestones.toNumber(), 3, "The Bronze bit should be set again");
+      assert.equal(userAccount.badgeLevel, SILVER_LEVEL, "The badge should still show Silver");
+
+      const record = await program.account.milestoneRecord.fetch(
+        getMilestoneRecordPda(user1.publicKey, BRONZE_LEVEL)
+      );
+      assert.equal(record.revoked, false);
+      assert.ok(record.mint.equals(reputationMint));
+
+      // Restore the default mode for later suites
+      await setBadgeMode(admin, { perMilestone: {} });
+    });
+  });
+
+  // ======================================================================
+  // Q. Badge Metadata Refresh
+  // ======================================================================
+  describe("Q. Badge Metadata Refresh", () => {
+    const NEW_HOST = "https://cdn.voix.com/metadata/";
+
+    const getMetadataPda = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+        ],
+        MPL_TOKEN_METADATA_PROGRAM_ID
+      )[0];
+
+    const metadataContains = async (mint: PublicKey, value: string) => {
+      const info = await connection.getAccountInfo(getMetadataPda(mint));
+      return info.data.includes(Buffer.from(value));
+    };
+
+    const updateAccounts = (signer: Keypair, mint: PublicKey) => ({
+      admin: signer.publicKey,
+      globalConfig: globalConfigPda,
+      badge: {
+        payer: signer.publicKey,
+        mintAuthority: mintAuthorityPda,
+        mint,
+        metadataAccount: getMetadataPda(mint),
+        tokenProgram: null,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+      },
+    });
+
+    const batchAccounts = {
+      admin: admin.publicKey,
+      globalConfig: globalConfigPda,
+      mintAuthority: mintAuthorityPda,
+      tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+    };
+
+    it("1. update_badge_metadata: Fails if a non-admin rewrites a badge (VoixError::Unauthorized)", async () => {
+      const bronzeMint = getBadgeMintPda(user2.publicKey, 1);
+      await assert.rejects(
+        program.methods
+          .updateBadgeMetadata(null, null, `${NEW_HOST}bronze`)
+          .accounts(updateAccounts(nonAdmin, bronzeMint))
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. update_badge_metadata: Fails when nothing is changed (VoixError::EmptyMetadataUpdate)", async () => {
+      const bronzeMint = getBadgeMintPda(user2.publicKey, 1);
+      await assert.rejects(
+        program.methods
+          .updateBadgeMetadata(null, null, null)
+          .accounts(updateAccounts(admin, bronzeMint))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "EmptyMetadataUpdate";
+        },
+        "Should have failed with VoixError::EmptyMetadataUpdate"
+      );
+    });
+
+    it("3. update_badge_metadata: Admin moves a minted badge to the new metadata host", async () => {
+      const bronzeMint = getBadgeMintPda(user2.publicKey, 1);
+      await program.methods
+        .updateBadgeMetadata(null, null, `${NEW_HOST}bronze`)
+        .accounts(updateAccounts(admin, bronzeMint))
+        .signers([admin])
+        .rpc();
+
+      assert.ok(await metadataContains(bronzeMint, `${NEW_HOST}bronze`));
+      assert.ok(
+        await metadataContains(bronzeMint, "Voix Milestone Bronze"),
+        "The name should be kept"
+      );
+    });
+
+    it("4. batch_update_badge_metadata: Admin renames several badges in one transaction", async () => {
+      const silverMint = getBadgeMintPda(user2.publicKey, 2);
+      const goldMint = getBadgeMintPda(user2.publicKey, 3);
+
+      await program.methods
+        .batchUpdateBadgeMetadata("Voix Badge", null, null)
+        .accounts(batchAccounts)
+        .remainingAccounts(
+          [silverMint, goldMint].map((mint) => ({
+            pubkey: getMetadataPda(mint),
+            isWritable: true,
+            isSigner: false,
+          }))
+        )
+        .signers([admin])
+        .rpc();
+
+      assert.ok(await metadataContains(silverMint, "Voix Badge"));
+      assert.ok(await metadataContains(goldMint, "Voix Badge"));
+    });
+
+    it("5. batch_update_badge_metadata: Fails for an account that isn't badge metadata (VoixError::InvalidBadgeMetadata)", async () => {
+      await assert.rejects(
+        program.methods
+          .batchUpdateBadgeMetadata("Voix Badge", null, null)
+          .accounts(batchAccounts)
+          .remainingAccounts([
+            { pubkey: user2AccountPda, isWritable: true, isSigner: false },
+          ])
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidBadgeMetadata";
+        },
+        "Should have failed with VoixError::InvalidBadgeMetadata"
+      );
+    });
+
+    it("6. update_badge_metadata: The council rewrites a badge, passing its accounts as remaining accounts", async () => {
+      const bronzeMint = getBadgeMintPda(user2.publicKey, 1);
+      // The badge's accounts, without the admin and global config of `update_badge_metadata`
+      const badgeAccounts = (
+        await program.methods
+          .updateBadgeMetadata(null, null, `${NEW_HOST}council/bronze`)
+          .accounts(updateAccounts(admin, bronzeMint))
+          .instruction()
+      ).keys.slice(2);
+
+      await runCouncilAction(
+        {
+          updateBadgeMetadata: {
+            mint: bronzeMint,
+            name: null,
+            symbol: null,
+            uri: `${NEW_HOST}council/bronze`,
+          },
+        },
+        {},
+        badgeAccounts
+      );
+
+      assert.ok(await metadataContains(bronzeMint, `${NEW_HOST}council/bronze`));
+    });
+  });
+
+  // ======================================================================
+  // R. Milestone Revocation
+  // ======================================================================
+  describe("R. Milestone Revocation", () => {
+    const GOLD_LEVEL = 3;
+    const GOLD_FLAG = 4;
+    const REASON_FRAUD = 7;
+
+    const getEditionPda = (mint: PublicKey) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("metadata"),
+          MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+          mint.toBuffer(),
+          Buffer.from("edition"),
+        ],
+        MPL_TOKEN_METADATA_PROGRAM_ID
+      )[0];
+
+    const revokeAccounts = async (signer: Keypair, revocations = 0) => {
+      const mint = getBadgeMintPda(user2.publicKey, GOLD_LEVEL, revocations);
+      return {
+        admin: signer.publicKey,
+        globalConfig: globalConfigPda,
+        milestone: {
+          milestoneRegistry: milestoneRegistryPda,
+          user: user2.publicKey,
+          userAccount: user2AccountPda,
+          milestoneRecord: getMilestoneRecordPda(user2.publicKey, GOLD_LEVEL),
+          mintAuthority: mintAuthorityPda,
+          mint,
+          tokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+          masterEditionAccount: getEditionPda(mint),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        },
+      };
+    };
+
+    // Mints User 2's Gold badge again after `revocations` revocations
+    const remintGold = async (revocations: number) => {
+      const mint = getBadgeMintPda(user2.publicKey, GOLD_LEVEL, revocations);
+      await program.methods
+        .mintMilestoneNft(GOLD_LEVEL)
+        .accounts({
+          user: user2.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
+          userAccount: user2AccountPda,
+          mintAuthority: mintAuthorityPda,
+          milestoneRecord: getMilestoneRecordPda(user2.publicKey, GOLD_LEVEL),
+          mint,
+          tokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+          metadataAccount: PublicKey.findProgramAddressSync(
+            [
+              Buffer.from("metadata"),
+              MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+              mint.toBuffer(),
+            ],
+            MPL_TOKEN_METADATA_PROGRAM_ID
+          )[0],
+          masterEditionAccount: getEditionPda(mint),
+          collectionMint: badgeCollectionMintPda,
+          collectionMetadata: badgeCollectionMetadataPda,
+          collectionMasterEdition: badgeCollectionEditionPda,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+        })
+        .signers([user2])
+        .rpc();
+      return mint;
+    };
+
+    it("1. revoke_milestone: Fails if a non-admin revokes a milestone (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        program.methods
+          .revokeMilestone(GOLD_LEVEL, REASON_FRAUD)
+          .accounts(await revokeAccounts(nonAdmin))
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. revoke_milestone: Admin burns the Gold badge and clears the milestone bit", async () => {
+      const accounts = await revokeAccounts(admin);
+      await program.methods
+        .revokeMilestone(GOLD_LEVEL, REASON_FRAUD)
+        .accounts(accounts)
+        .signers([admin])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(connection, accounts.milestone.tokenAccount);
+      assert.equal(tokenAccountInfo.amount.toString(), "0", "The badge should be burned");
+
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(
+        userAccount.mintedMilestones.toNumber() & GOLD_FLAG,
+        0,
+        "Gold flag should be cleared"
+      );
+
+      const record = await program.account.milestoneRecord.fetch(
+        accounts.milestone.milestoneRecord
+      );
+      assert.equal(record.revoked, true);
+      assert.equal(record.reasonCode, REASON_FRAUD);
+    });
+
+    it("3. revoke_milestone: Fails for an already revoked milestone (VoixError::MilestoneAlreadyRevoked)", async () => {
+      await assert.rejects(
+        program.methods
+          .revokeMilestone(GOLD_LEVEL, REASON_FRAUD)
+          .accounts(await revokeAccounts(admin))
+          .signers([admin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MilestoneAlreadyRevoked";
+        },
+        "Should have failed with VoixError::MilestoneAlreadyRevoked"
+      );
+    });
+
+    it("4. mint_milestone_nft: A revoked milestone can be re-minted at a fresh badge mint", async () => {
+      await program.methods
+        .updateUserKarma(10000)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user2AccountPda,
+          userToUpdate: user2.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const mint = await remintGold(1);
+      const milestoneRecord = getMilestoneRecordPda(user2.publicKey, GOLD_LEVEL);
+
+      const record = await program.account.milestoneRecord.fetch(milestoneRecord);
+      assert.equal(record.revoked, false);
+      assert.equal(record.revocations, 1);
+      assert.ok(record.mint.equals(mint));
+
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(userAccount.mintedMilestones.toNumber() & GOLD_FLAG, GOLD_FLAG);
+    });
+
+    it("5. revoke_milestone: Revokes the re-minted badge as well", async () => {
+      const accounts = await revokeAccounts(admin, 1);
+      await program.methods
+        .revokeMilestone(GOLD_LEVEL, REASON_FRAUD)
+        .accounts(accounts)
+        .signers([admin])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(connection, accounts.milestone.tokenAccount);
+      assert.equal(tokenAccountInfo.amount.toString(), "0", "The badge should be burned");
+
+      const record = await program.account.milestoneRecord.fetch(
+        accounts.milestone.milestoneRecord
+      );
+      assert.equal(record.revoked, true);
+      assert.equal(record.revocations, 2);
+    });
+
+    it("6. revoke_milestone: The council revokes a milestone, passing its accounts as remaining accounts", async () => {
+      await remintGold(2);
+      const accounts = await revokeAccounts(admin, 2);
+      // The milestone's accounts, without the admin and global config of `revoke_milestone`
+      const milestoneAccounts = (
+        await program.methods
+          .revokeMilestone(GOLD_LEVEL, REASON_FRAUD)
+          .accounts(accounts)
+          .instruction()
+      ).keys.slice(2);
+
+      // A proposal naming another user can't be run with User 2's accounts
+      await assert.rejects(
+        runCouncilAction(
+          {
+            revokeMilestone: {
+              user: user1.publicKey,
+              milestoneLevel: GOLD_LEVEL,
+              reasonCode: REASON_FRAUD,
+            },
+          },
+          {},
+          milestoneAccounts
+        ),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "CouncilAccountsMismatch";
+        },
+        "Should have failed with VoixError::CouncilAccountsMismatch"
+      );
+
+      await runCouncilAction(
+        {
+          revokeMilestone: {
+            user: user2.publicKey,
+            milestoneLevel: GOLD_LEVEL,
+            reasonCode: REASON_FRAUD,
+          },
+        },
+        {},
+        milestoneAccounts
+      );
+
+      const tokenAccountInfo = await getAccount(connection, accounts.milestone.tokenAccount);
+      assert.equal(tokenAccountInfo.amount.toString(), "0", "The badge should be burned");
+
+      const record = await program.account.milestoneRecord.fetch(
+        accounts.milestone.milestoneRecord
+      );
+      assert.equal(record.revoked, true);
+      assert.equal(record.revocations, 3);
+    });
+  });
+
+  describe("S. Token-2022 Badges", () => {
+    const GOLD_LEVEL = 3;
+    const goldMint = getBadgeMintPda(user1.publicKey, GOLD_LEVEL);
+    const goldTokenAccount = getAssociatedTokenAddressSync(
+      goldMint,
+      user1.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const setBadgeStandard = (signer: Keypair, standard: any) =>
+      program.methods
+        .setBadgeStandard(standard)
+        .accounts({
+          admin: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    before(async () => {
+      // Give User 1 enough karma for Gold
+      await program.methods
+        .updateUserKarma(10000)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          userToUpdate: user1.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("1. set_badge_standard: Fails if a non-admin changes the badge standard (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        setBadgeStandard(nonAdmin, { token2022: {} }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. mint_milestone_badge_2022: Mints a Gold badge carrying its metadata on the mint", async () => {
+      await setBadgeStandard(admin, { token2022: {} });
+      const config = await program.account.globalConfig.fetch(globalConfigPda);
+      assert.deepEqual(config.badgeStandard, { token2022: {} });
+
+      await program.methods
+        .mintMilestoneBadge2022(GOLD_LEVEL)
+        .accounts({
+          user: user1.publicKey,
+          globalConfig: globalConfigPda,
+          milestoneRegistry: milestoneRegistryPda,
+          userAccount: user1AccountPda,
+          mintAuthority: mintAuthorityPda,
+          mint: goldMint,
+          tokenAccount: goldTokenAccount,
+          milestoneRecord: getMilestoneRecordPda(user1.publicKey, GOLD_LEVEL),
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(
+        connection,
+        goldTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(tokenAccountInfo.amount.toString(), "1");
+
+      const metadata = await getTokenMetadata(connection, goldMint);
+      const registry = await program.account.milestoneRegistry.fetch(
+        milestoneRegistryPda
+      );
+      const goldTier = registry.tiers.find((t) => t.level === GOLD_LEVEL);
+      assert.equal(metadata.name, goldTier.name);
+      assert.equal(metadata.uri, goldTier.uri);
+      assert.ok(metadata.updateAuthority.equals(mintAuthorityPda));
+
+      const record = await program.account.milestoneRecord.fetch(
+        getMilestoneRecordPda(user1.publicKey, GOLD_LEVEL)
+      );
+      assert.ok(record.mint.equals(goldMint));
+    });
+
+    it("3. mint_milestone_nft: Fails while Token-2022 is the badge standard (VoixError::WrongBadgeStandard)", async () => {
+      // User 2's Gold milestone was revoked three times in section R.
+      const mint = getBadgeMintPda(user2.publicKey, GOLD_LEVEL, 3);
+      await assert.rejects(
+        program.methods
+          .mintMilestoneNft(GOLD_LEVEL)
+          .accounts({
+            user: user2.publicKey,
+            globalConfig: globalConfigPda,
+            milestoneRegistry: milestoneRegistryPda,
+            userAccount: user2AccountPda,
+            mintAuthority: mintAuthorityPda,
+            mint,
+            tokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+            milestoneRecord: getMilestoneRecordPda(user2.publicKey, GOLD_LEVEL),
+            metadataAccount: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("metadata"),
+                MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+                mint.toBuffer(),
+              ],
+              MPL_TOKEN_METADATA_PROGRAM_ID
+            )[0],
+            masterEditionAccount: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("metadata"),
+                MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+                mint.toBuffer(),
+                Buffer.from("edition"),
+              ],
+              MPL_TOKEN_METADATA_PROGRAM_ID
+            )[0],
+            collectionMint: badgeCollectionMintPda,
+            collectionMetadata: badgeCollectionMetadataPda,
+            collectionMasterEdition: badgeCollectionEditionPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+          })
+          .signers([user2])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "WrongBadgeStandard";
+        },
+        "Should have failed with VoixError::WrongBadgeStandard"
+      );
+
+      // Restore the default standard for any later tests
+      await setBadgeStandard(admin, { metaplexNft: {} });
+    });
+
+    it("4. update_badge_metadata: Rewrites the metadata stored on a Token-2022 badge mint", async () => {
+      const newUri = "https://cdn.voix.com/metadata/token-2022/gold-badge.json";
+      await program.methods
+        .updateBadgeMetadata(null, null, newUri)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          badge: {
+            payer: admin.publicKey,
+            mintAuthority: mintAuthorityPda,
+            mint: goldMint,
+            metadataAccount: null,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+          },
+        })
+        .signers([admin])
+        .rpc();
+
+      const metadata = await getTokenMetadata(connection, goldMint);
+      assert.equal(metadata.uri, newUri);
+    });
+
+    it("5. revoke_milestone: Burns a Token-2022 badge through the permanent delegate", async () => {
+      await program.methods
+        .revokeMilestone(GOLD_LEVEL, 7)
+        .accounts({
+          admin: admin.publicKey,
+          globalConfig: globalConfigPda,
+          milestone: {
+            milestoneRegistry: milestoneRegistryPda,
+            user: user1.publicKey,
+            userAccount: user1AccountPda,
+            milestoneRecord: getMilestoneRecordPda(user1.publicKey, GOLD_LEVEL),
+            mintAuthority: mintAuthorityPda,
+            mint: goldMint,
+            tokenAccount: goldTokenAccount,
+            masterEditionAccount: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("metadata"),
+                MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+                goldMint.toBuffer(),
+                Buffer.from("edition"),
+              ],
+              MPL_TOKEN_METADATA_PROGRAM_ID
+            )[0],
+            metadataAccount: null,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+            tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+          },
+        })
+        .signers([admin])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(
+        connection,
+        goldTokenAccount,
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.equal(tokenAccountInfo.amount.toString(), "0", "The badge should be burned");
+
+      const record = await program.account.milestoneRecord.fetch(
+        getMilestoneRecordPda(user1.publicKey, GOLD_LEVEL)
+      );
+      assert.equal(record.revoked, true);
+    });
+  });
+
+  describe("T. Achievements", () => {
+    const FIRST_TIP_ID = 0;
+    const INSIDER_ID = 1;
+
+    const getAchievementPda = (id: number) =>
+      PublicKey.findProgramAddressSync(
+        [ACHIEVEMENT_SEED, Buffer.from([id])],
+        programId
+      )[0];
+
+    const claimAccounts = async (id: number, karmaOracle: PublicKey | null) => {
+      const mint = PublicKey.findProgramAddressSync(
+        [ACHIEVEMENT_BADGE_SEED, user2.publicKey.toBuffer(), Buffer.from([id])],
+        programId
+      )[0];
+      return {
+        user: user2.publicKey,
+        globalConfig: globalConfigPda,
+        achievement: getAchievementPda(id),
+        userAccount: user2AccountPda,
+        karmaOracle,
+        mintAuthority: mintAuthorityPda,
+        mint,
+        tokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+        metadataAccount: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("metadata"),
+            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            mint.toBuffer(),
+          ],
+          MPL_TOKEN_METADATA_PROGRAM_ID
+        )[0],
+        masterEditionAccount: PublicKey.findProgramAddressSync(
+          [
+            Buffer.from("metadata"),
+            MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+            mint.toBuffer(),
+            Buffer.from("edition"),
+          ],
+          MPL_TOKEN_METADATA_PROGRAM_ID
+        )[0],
+        collectionMint: badgeCollectionMintPda,
+        collectionMetadata: badgeCollectionMetadataPda,
+        collectionMasterEdition: badgeCollectionEditionPda,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+      };
+    };
+
+    const createAchievementAccounts = (signer: Keypair, id: number) => ({
+      admin: signer.publicKey,
+      globalConfig: globalConfigPda,
+      newAchievement: {
+        payer: signer.publicKey,
+        achievement: getAchievementPda(id),
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+    const createAchievement = (
+      signer: Keypair,
+      id: number,
+      criterion: any,
+      threshold: number,
+      name: string,
+      soulbound = true
+    ) =>
+      program.methods
+        .createAchievement(
+          id,
+          criterion,
+          new BN(threshold),
+          name,
+          "VOIX",
+          `https://voix.com/metadata/achievements/${id}.json`,
+          soulbound
+        )
+        .accounts(createAchievementAccounts(signer, id))
+        .signers([signer])
+        .rpc();
+
+    it("1. create_achievement: Fails if a non-admin defines an achievement (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        createAchievement(nonAdmin, FIRST_TIP_ID, { tipsSent: {} }, 1, "First Tip"),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. create_achievement: Admin defines a counter and a transferable attestation achievement", async () => {
+      await createAchievement(admin, FIRST_TIP_ID, { tipsSent: {} }, 1, "First Tip");
+      await createAchievement(admin, INSIDER_ID, { attestation: {} }, 0, "Voix Insider", false);
+
+      const achievement = await program.account.achievement.fetch(
+        getAchievementPda(FIRST_TIP_ID)
+      );
+      assert.deepEqual(achievement.criterion, { tipsSent: {} });
+      assert.equal(achievement.threshold.toNumber(), 1);
+      assert.equal(achievement.name, "First Tip");
+      assert.equal(achievement.soulbound, true);
+    });
+
+    it("3. claim_achievement: Fails before the criterion is met (VoixError::AchievementCriterionNotMet)", async () => {
+      await assert.rejects(
+        program.methods
+          .claimAchievement(FIRST_TIP_ID)
+          .accounts(await claimAccounts(FIRST_TIP_ID, null))
+          .signers([user2])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "AchievementCriterionNotMet";
+        },
+        "Should have failed with VoixError::AchievementCriterionNotMet"
+      );
+    });
+
+    it("4. claim_achievement: Claims the First Tip badge after sending a counted tip", async () => {
+      await program.methods
+        .tipUserSol(new BN(1000))
+        .accounts({
+          tipper: user2.publicKey,
+          globalConfig: globalConfigPda,
+          receiver: user1.publicKey,
+          receiverAccount: user1AccountPda,
+          tipperAccount: user2AccountPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      let userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(userAccount.tipsSent, 1);
+      assert.ok(userAccount.createdAt.toNumber() > 0);
+
+      const accounts = await claimAccounts(FIRST_TIP_ID, null);
+      await program.methods
+        .claimAchievement(FIRST_TIP_ID)
+        .accounts(accounts)
+        .signers([user2])
+        .rpc();
+
+      userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(userAccount.achievements.toNumber(), 1 << FIRST_TIP_ID);
+
+      const tokenAccountInfo = await getAccount(connection, accounts.tokenAccount);
+      assert.equal(tokenAccountInfo.amount.toString(), "1");
+      assert.equal(tokenAccountInfo.isFrozen, true, "Achievement badges are soulbound");
+    });
+
+    it("5. claim_achievement: Fails when claiming the same achievement twice (VoixError::AchievementAlreadyClaimed)", async () => {
+      await assert.rejects(
+        program.methods
+          .claimAchievement(FIRST_TIP_ID)
+          .accounts(await claimAccounts(FIRST_TIP_ID, null))
+          .signers([user2])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "AchievementAlreadyClaimed";
+        },
+        "Should have failed with VoixError::AchievementAlreadyClaimed"
+      );
+    });
+
+    it("6. claim_achievement: Attestation achievements need the karma oracle's signature", async () => {
+      await assert.rejects(
+        program.methods
+          .claimAchievement(INSIDER_ID)
+          .accounts(await claimAccounts(INSIDER_ID, null))
+          .signers([user2])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "AchievementCriterionNotMet";
+        },
+        "Should have failed with VoixError::AchievementCriterionNotMet"
+      );
+
+      const accounts = await claimAccounts(INSIDER_ID, admin.publicKey);
+      await program.methods
+        .claimAchievement(INSIDER_ID)
+        .accounts(accounts)
+        .signers([user2, admin])
+        .rpc();
+
+      const tokenAccountInfo = await getAccount(connection, accounts.tokenAccount);
+      assert.equal(tokenAccountInfo.isFrozen, false, "Voix Insider badges are transferable");
+
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(
+        userAccount.achievements.toNumber(),
+        (1 << FIRST_TIP_ID) | (1 << INSIDER_ID)
+      );
+    });
+
+    it("7. create_achievement: The council defines an achievement, passing its accounts as remaining accounts", async () => {
+      const COUNCIL_PICK_ID = 2;
+      const action = (id: number) => ({
+        createAchievement: {
+          achievementId: id,
+          criterion: { attestation: {} },
+          threshold: new BN(0),
+          name: "Council Pick",
+          symbol: "VOIX",
+          uri: `https://voix.com/metadata/achievements/${id}.json`,
+          soulbound: true,
+        },
+      });
+
+      // Ids above MAX_ACHIEVEMENT_ID are rejected when proposed
+      await assert.rejects(
+        runCouncilAction(action(64)),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidAchievementId";
+        },
+        "Should have failed with VoixError::InvalidAchievementId"
+      );
+
+      // The achievement's accounts, without the admin and global config of `create_achievement`
+      const achievementAccounts = (
+        await program.methods
+          .createAchievement(
+            COUNCIL_PICK_ID,
+            { attestation: {} },
+            new BN(0),
+            "Council Pick",
+            "VOIX",
+            "",
+            true
+          )
+          .accounts(createAchievementAccounts(admin, COUNCIL_PICK_ID))
+          .instruction()
+      ).keys.slice(2);
+      await runCouncilAction(action(COUNCIL_PICK_ID), {}, achievementAccounts);
+
+      const achievement = await program.account.achievement.fetch(
+        getAchievementPda(COUNCIL_PICK_ID)
+      );
+      assert.equal(achievement.id, COUNCIL_PICK_ID);
+      assert.deepEqual(achievement.criterion, { attestation: {} });
+      assert.equal(achievement.name, "Council Pick");
+    });
+  });
+
+  describe("U. Karma Vouchers", () => {
+    const KARMA_VOUCHER_PREFIX = Buffer.from("voix:karma_voucher");
+
+    // Borsh layout of `KarmaVoucher`: program_id, user, karma (u32), nonce (u64), expires_at (i64)
+    const voucherMessage = (voucher: any) =>
+      Buffer.concat([
+        KARMA_VOUCHER_PREFIX,
+        voucher.programId.toBuffer(),
+        voucher.user.toBuffer(),
+        new BN(voucher.karma).toArrayLike(Buffer, "le", 4),
+        voucher.nonce.toArrayLike(Buffer, "le", 8),
+        voucher.expiresAt.toTwos(64).toArrayLike(Buffer, "le", 8),
+      ]);
+
+    const makeVoucher = (karma: number, nonce: number, expiresInSecs: number) => ({
+      programId,
+      user: user1.publicKey,
+      karma,
+      nonce: new BN(nonce),
+      expiresAt: new BN(Math.floor(Date.now() / 1000) + expiresInSecs),
+    });
+
+    const claimKarma = (voucher: any, oracle: Keypair) =>
+      program.methods
+        .claimKarma(voucher)
+        .accounts({
+          user: user1.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([
+          anchor.web3.Ed25519Program.createInstructionWithPrivateKey({
+            privateKey: oracle.secretKey,
+            message: voucherMessage(voucher),
+          }),
+        ])
+        .signers([user1])
+        .rpc();
+
+    // Karma oracle writes in earlier sections already moved the nonce on.
+    let nonce: number;
+    before(async () => {
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      nonce = userAccount.karmaNonce.toNumber() + 1;
+    });
+
+    it("1. claim_karma: Fails for a voucher not signed by the karma oracle (VoixError::InvalidVoucherSignature)", async () => {
+      await assert.rejects(
+        claimKarma(makeVoucher(12000, nonce, 600), nonAdmin),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidVoucherSignature";
+        },
+        "Should have failed with VoixError::InvalidVoucherSignature"
+      );
+    });
+
+    it("2. claim_karma: Sets karma from a voucher signed by the karma oracle", async () => {
+      await claimKarma(makeVoucher(12000, nonce, 600), admin);
+
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.equal(userAccount.karma, 12000);
+      assert.equal(userAccount.karmaNonce.toNumber(), nonce);
+    });
+
+    it("3. claim_karma: Fails when a voucher is replayed (VoixError::VoucherAlreadyUsed)", async () => {
+      await assert.rejects(
+        claimKarma(makeVoucher(12000, nonce, 600), admin),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "VoucherAlreadyUsed";
+        },
+        "Should have failed with VoixError::VoucherAlreadyUsed"
+      );
+    });
+
+    it("4. claim_karma: Fails for an expired voucher (VoixError::VoucherExpired)", async () => {
+      await assert.rejects(
+        claimKarma(makeVoucher(13000, nonce + 1, -600), admin),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "VoucherExpired";
+        },
+        "Should have failed with VoixError::VoucherExpired"
+      );
+    });
+
+    it("5. claim_karma: A voucher signed before a karma oracle write can't undo it (VoixError::VoucherAlreadyUsed)", async () => {
+      // Signed for the next nonce, then overtaken by a moderation cut
+      const voucher = makeVoucher(14000, nonce + 1, 600);
+      await program.methods
+        .updateUserKarma(9000)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          userToUpdate: user1.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      await assert.rejects(
+        claimKarma(voucher, admin),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "VoucherAlreadyUsed";
+        },
+        "Should have failed with VoixError::VoucherAlreadyUsed"
+      );
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.equal(userAccount.karma, 9000);
+    });
+  });
+
+  describe("V. Karma Merkle Sync", () => {
+    const KARMA_LEAF_PREFIX = Buffer.from("voix:karma_leaf");
+    const sha256 = (...parts: Buffer[]) =>
+      createHash("sha256").update(Buffer.concat(parts)).digest();
+
+    const karmaLeaf = (user: PublicKey, karma: number, epoch: anchor.BN) =>
+      sha256(
+        KARMA_LEAF_PREFIX,
+        user.toBuffer(),
+        new BN(karma).toArrayLike(Buffer, "le", 4),
+        epoch.toArrayLike(Buffer, "le", 8)
+      );
+
+    // Sorted-pair parent, matching `verify_merkle_proof`.
+    const parent = (a: Buffer, b: Buffer) =>
+      Buffer.compare(a, b) <= 0 ? sha256(a, b) : sha256(b, a);
+
+    let epoch: anchor.BN;
+    let user1Leaf: Buffer;
+    let user2Leaf: Buffer;
+
+    const syncKarma = (karma: number, proof: Buffer[]) =>
+      program.methods
+        .syncKarmaFromRoot(
+          karma,
+          proof.map((node) => Array.from(node) as [number, ...number[]])
+        )
+        .accounts({
+          user: user1.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+        })
+        .signers([user1])
+        .rpc();
+
+    before(async () => {
+      const configAccount = await program.account.globalConfig.fetch(globalConfigPda);
+      epoch = configAccount.epoch.add(new BN(1));
+      user1Leaf = karmaLeaf(user1.publicKey, 15000, epoch);
+      user2Leaf = karmaLeaf(user2.publicKey, 300, epoch);
+
+      await program.methods
+        .submitMerkleRoot(
+          Array.from(parent(user1Leaf, user2Leaf)) as [number, ...number[]],
+          epoch,
+          new BN(2),
+          null
+        )
+        .accounts({
+          merklePublisher: admin.publicKey,
+          globalConfig: globalConfigPda,
+          merkleEpoch: merkleEpochPda(epoch),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("1. sync_karma_from_root: Fails for a leaf that isn't in the root (VoixError::InvalidMerkleProof)", async () => {
+      await assert.rejects(
+        syncKarma(20000, [user2Leaf]),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidMerkleProof";
+        },
+        "Should have failed with VoixError::InvalidMerkleProof"
+      );
+    });
+
+    it("2. sync_karma_from_root: Sets karma from a valid proof and records the epoch", async () => {
+      await syncKarma(15000, [user2Leaf]);
+
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.equal(userAccount.karma, 15000);
+      assert.ok(userAccount.karmaSyncedEpoch.eq(epoch));
+    });
+
+    it("3. sync_karma_from_root: Fails to sync the same epoch twice (VoixError::KarmaAlreadySynced)", async () => {
+      await assert.rejects(
+        syncKarma(15000, [user2Leaf]),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "KarmaAlreadySynced";
+        },
+        "Should have failed with VoixError::KarmaAlreadySynced"
+      );
+    });
+
+    it("4. sync_karma_from_root: A proof from before a karma oracle write can't undo it (VoixError::KarmaAlreadySynced)", async () => {
+      // User 2's leaf is in the current root, but the oracle overrides their karma first
+      await program.methods
+        .updateUserKarma(500)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user2AccountPda,
+          userToUpdate: user2.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      await assert.rejects(
+        program.methods
+          .syncKarmaFromRoot(300, [Array.from(user1Leaf) as [number, ...number[]]])
+          .accounts({
+            user: user2.publicKey,
+            globalConfig: globalConfigPda,
+            userAccount: user2AccountPda,
+          })
+          .signers([user2])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "KarmaAlreadySynced";
+        },
+        "Should have failed with VoixError::KarmaAlreadySynced"
+      );
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(userAccount.karma, 500);
+    });
+  });
+
+  describe("W. Karma Deltas", () => {
+    const applyDelta = (delta: number, reason: any, expectedSeq: anchor.BN) =>
+      program.methods
+        .applyKarmaDelta(delta, reason, expectedSeq)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user2AccountPda,
+          userToUpdate: user2.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    it("1. apply_karma_delta: Adds the delta and bumps the sequence number", async () => {
+      const before = await program.account.userAccount.fetch(user2AccountPda);
+      await applyDelta(5, { tip: {} }, before.karmaSeq);
+
+      const after = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(after.karma, before.karma + 5);
+      assert.ok(after.karmaSeq.eq(before.karmaSeq.add(new BN(1))));
+    });
+
+    it("2. apply_karma_delta: Fails on a stale sequence number (VoixError::KarmaSequenceMismatch)", async () => {
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      await assert.rejects(
+        applyDelta(1, { upvote: {} }, userAccount.karmaSeq.sub(new BN(1))),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "KarmaSequenceMismatch";
+        },
+        "Should have failed with VoixError::KarmaSequenceMismatch"
+      );
+    });
+
+    it("3. apply_karma_delta: A negative delta stops at 0 karma", async () => {
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      await applyDelta(-(userAccount.karma + 10), { moderation: {} }, userAccount.karmaSeq);
+
+      const after = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(after.karma, 0);
+    });
+
+    it("4. apply_karma_delta: Fails if a non-oracle applies a delta (VoixError::MissingKarmaOracleRole)", async () => {
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      await assert.rejects(
+        program.methods
+          .applyKarmaDelta(1, { upvote: {} }, userAccount.karmaSeq)
+          .accounts({
+            karmaOracle: nonAdmin.publicKey, // Not the karma oracle
+            globalConfig: globalConfigPda,
+            userAccount: user2AccountPda,
+            userToUpdate: user2.publicKey,
+          })
+          .signers([nonAdmin])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MissingKarmaOracleRole";
+        },
+        "Should have failed with VoixError::MissingKarmaOracleRole"
+      );
+    });
+
+    it("5. apply_karma_delta: Fails for the program's own reasons (VoixError::ReservedKarmaReason)", async () => {
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      for (const reason of [{ decay: {} }, { gift: {} }]) {
+        await assert.rejects(
+          applyDelta(-1, reason, userAccount.karmaSeq),
+          (e: anchor.AnchorError) => {
+            return e.error.errorCode.code === "ReservedKarmaReason";
+          },
+          "Should have failed with VoixError::ReservedKarmaReason"
+        );
+      }
+    });
+  });
+
+  describe("X. Batch Karma Updates", () => {
+    const batchUpdate = (entries: { user: PublicKey; karma: number }[], userAccounts: PublicKey[]) =>
+      program.methods
+        .batchUpdateKarma(entries)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .remainingAccounts(
+          userAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .signers([admin])
+        .rpc();
+
+    it("1. batch_update_karma: Sets karma for every user in the batch", async () => {
+      await batchUpdate(
+        [
+          { user: user1.publicKey, karma: 16000 },
+          { user: user2.publicKey, karma: 700 },
+        ],
+        [user1AccountPda, user2AccountPda]
+      );
+
+      const user1Account = await program.account.userAccount.fetch(user1AccountPda);
+      const user2Account = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(user1Account.karma, 16000);
+      assert.equal(user2Account.karma, 700);
+    });
+
+    it("2. batch_update_karma: Fails and names the index of a mismatched account (VoixError::InvalidBatchUserAccount)", async () => {
+      await assert.rejects(
+        batchUpdate(
+          [
+            { user: user1.publicKey, karma: 1 },
+            { user: user2.publicKey, karma: 1 },
+          ],
+          [user1AccountPda, user1AccountPda] // Wrong PDA for entry 1
+        ),
+        (e: anchor.AnchorError) => {
+          return (
+            e.error.errorCode.code === "InvalidBatchUserAccount" &&
+            e.error.origin === "remaining_accounts[1]"
+          );
+        },
+        "Should have failed with VoixError::InvalidBatchUserAccount at index 1"
+      );
+    });
+
+    it("3. batch_update_karma: Fails when entries and accounts don't line up (VoixError::KarmaBatchLengthMismatch)", async () => {
+      await assert.rejects(
+        batchUpdate([{ user: user1.publicKey, karma: 1 }], [user1AccountPda, user2AccountPda]),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "KarmaBatchLengthMismatch";
+        },
+        "Should have failed with VoixError::KarmaBatchLengthMismatch"
+      );
+    });
+  });
+
+  describe("Y. Karma Limits", () => {
+    const noLimits = { maxIncreasePerUser: 0, maxIncreasePerEpoch: new BN(0), mintCooldownSecs: 0 };
+
+    const setKarmaLimits = (signer: Keypair, limits: any) =>
+      program.methods
+        .setKarmaLimits(limits)
+        .accounts({
+          admin: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    const setKarma = (user: Keypair, userAccount: PublicKey, karma: number) =>
+      program.methods
+        .updateUserKarma(karma)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount,
+          userToUpdate: user.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    before(async () => {
+      // Start a fresh epoch so earlier karma writes don't count against the limits.
+      const configAccount = await program.account.globalConfig.fetch(globalConfigPda);
+      const epoch = configAccount.epoch.add(new BN(1));
+      await program.methods
+        .submitMerkleRoot(Array.from(Buffer.alloc(32, 7)) as [number, ...number[]], epoch, new BN(0), null)
+        .accounts({
+          merklePublisher: admin.publicKey,
+          globalConfig: globalConfigPda,
+          merkleEpoch: merkleEpochPda(epoch),
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    after(async () => {
+      await setKarmaLimits(admin, noLimits);
+    });
+
+    it("1. set_karma_limits: Fails if a non-admin sets the limits (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        setKarmaLimits(nonAdmin, noLimits),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. update_user_karma: Fails above the per-user increase limit (VoixError::UserKarmaLimitExceeded)", async () => {
+      await setKarmaLimits(admin, { ...noLimits, maxIncreasePerUser: 100 });
+      const { karma } = await program.account.userAccount.fetch(user2AccountPda);
+
+      await assert.rejects(
+        setKarma(user2, user2AccountPda, karma + 101),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "UserKarmaLimitExceeded";
+        },
+        "Should have failed with VoixError::UserKarmaLimitExceeded"
+      );
+
+      await setKarma(user2, user2AccountPda, karma + 100);
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(userAccount.karma, karma + 100);
+      assert.equal(userAccount.epochKarmaIncrease, 100);
+    });
+
+    it("3. update_user_karma: Fails above the total increase limit for the epoch (VoixError::EpochKarmaLimitExceeded)", async () => {
+      // 100 karma was already added this epoch in the previous test.
+      await setKarmaLimits(admin, { ...noLimits, maxIncreasePerEpoch: new BN(150) });
+      const { karma } = await program.account.userAccount.fetch(user1AccountPda);
+
+      await assert.rejects(
+        setKarma(user1, user1AccountPda, karma + 51),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "EpochKarmaLimitExceeded";
+        },
+        "Should have failed with VoixError::EpochKarmaLimitExceeded"
+      );
+
+      // Lowering karma is never limited.
+      await setKarma(user1, user1AccountPda, karma - 1);
+    });
+
+    it("4. mint_milestone_nft: Fails right after a karma jump (VoixError::MintCooldownActive)", async () => {
+      await setKarmaLimits(admin, { ...noLimits, mintCooldownSecs: 3600 });
+      await setKarma(user2, user2AccountPda, 10000);
+
+      // User 2's Gold milestone was revoked three times in section R.
+      const GOLD_LEVEL = 3;
+      const mint = getBadgeMintPda(user2.publicKey, GOLD_LEVEL, 3);
+      await assert.rejects(
+        program.methods
+          .mintMilestoneNft(GOLD_LEVEL)
+          .accounts({
+            user: user2.publicKey,
+            globalConfig: globalConfigPda,
+            milestoneRegistry: milestoneRegistryPda,
+            userAccount: user2AccountPda,
+            mintAuthority: mintAuthorityPda,
+            mint,
+            tokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+            milestoneRecord: getMilestoneRecordPda(user2.publicKey, GOLD_LEVEL),
+            metadataAccount: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("metadata"),
+                MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+                mint.toBuffer(),
+              ],
+              MPL_TOKEN_METADATA_PROGRAM_ID
+            )[0],
+            masterEditionAccount: PublicKey.findProgramAddressSync(
+              [
+                Buffer.from("metadata"),
+                MPL_TOKEN_METADATA_PROGRAM_ID.toBuffer(),
+                mint.toBuffer(),
+                Buffer.from("edition"),
+              ],
+              MPL_TOKEN_METADATA_PROGRAM_ID
+            )[0],
+            collectionMint: badgeCollectionMintPda,
+            collectionMetadata: badgeCollectionMetadataPda,
+            collectionMasterEdition: badgeCollectionEditionPda,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+          })
+          .signers([user2])
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MintCooldownActive";
+        },
+        "Should have failed with VoixError::MintCooldownActive"
+      );
+    });
+  });
+
+  describe("Z. Karma Decay", () => {
+    const setKarmaDecay = (signer: Keypair, decay: any) =>
+      program.methods
+        .setKarmaDecay(decay)
+        .accounts({
+          admin: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    after(async () => {
+      await setKarmaDecay(admin, { none: {} });
+    });
+
+    it("1. set_karma_decay: Fails if a non-admin sets the decay (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        setKarmaDecay(nonAdmin, { linear: { pointsPerDay: 10 } }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. set_karma_decay: Fails for a zero half-life (VoixError::InvalidKarmaDecay)", async () => {
+      await assert.rejects(
+        setKarmaDecay(admin, { halfLife: { halfLifeSecs: 0 } }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "InvalidKarmaDecay";
+        },
+        "Should have failed with VoixError::InvalidKarmaDecay"
+      );
+    });
+
+    it("3. settle_karma: Anyone can store a user's decayed karma", async () => {
+      // 10 karma per second, so the decay shows up within the test.
+      await setKarmaDecay(admin, { linear: { pointsPerDay: 864000 } });
+      await program.methods
+        .updateUserKarma(1000)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          userToUpdate: user1.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      await new Promise((resolve) => setTimeout(resolve, 3000));
+      await program.methods
+        .settleKarma()
+        .accounts({
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          user: user1.publicKey,
+        })
+        .rpc(); // Sent by the provider wallet, not the admin or the user
+
+      const userAccount = await program.account.userAccount.fetch(user1AccountPda);
+      assert.ok(userAccount.karma < 1000, "Karma should have decayed");
+      assert.ok(userAccount.lastKarmaUpdate.toNumber() > 0);
+    });
+
+    it("4. settle_karma: Fails while karma writes are paused (VoixError::ProgramPaused)", async () => {
+      const PAUSE_KARMA = 8;
+      const setPaused = (flags: number) =>
+        program.methods
+          .setPaused(flags)
+          .accounts({
+            pauser: admin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([admin])
+          .rpc();
+
+      await setPaused(PAUSE_KARMA);
+      await assert.rejects(
+        program.methods
+          .settleKarma()
+          .accounts({
+            globalConfig: globalConfigPda,
+            userAccount: user1AccountPda,
+            user: user1.publicKey,
+          })
+          .rpc(),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "ProgramPaused";
+        },
+        "Should have failed with VoixError::ProgramPaused"
+      );
+
+      await setPaused(0);
+    });
+  });
+
+  describe("AA. Karma History", () => {
+    const [user2KarmaHistoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("karma_history"), user2.publicKey.toBuffer()],
+      programId
+    );
+
+    const updateUser2Karma = (newKarma: number, karmaHistory: PublicKey | null) =>
+      program.methods
+        .updateUserKarma(newKarma)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user2AccountPda,
+          karmaHistory,
+          userToUpdate: user2.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    it("1. initialize_karma_history: A user opts in to an on-chain karma history", async () => {
+      await program.methods
+        .initializeKarmaHistory()
+        .accounts({
+          user: user2.publicKey,
+          userAccount: user2AccountPda,
+          karmaHistory: user2KarmaHistoryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const userAccount = await program.account.userAccount.fetch(user2AccountPda);
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      assert.ok(userAccount.hasKarmaHistory);
+      assert.ok(karmaHistory.user.equals(user2.publicKey));
+      assert.equal(karmaHistory.changes.length, 0);
+    });
+
+    it("2. update_user_karma: Fails if the user's history is left out (VoixError::MissingKarmaHistory)", async () => {
+      await assert.rejects(
+        updateUser2Karma(800, null),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MissingKarmaHistory";
+        },
+        "Should have failed with VoixError::MissingKarmaHistory"
+      );
+    });
+
+    it("3. update_user_karma / apply_karma_delta: Every write is recorded with its signer and reason", async () => {
+      const before = await program.account.userAccount.fetch(user2AccountPda);
+      await updateUser2Karma(800, user2KarmaHistoryPda);
+      await program.methods
+        .applyKarmaDelta(-50, { downvote: {} }, before.karmaSeq)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user2AccountPda,
+          karmaHistory: user2KarmaHistoryPda,
+          userToUpdate: user2.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      assert.equal(karmaHistory.changes.length, 2);
+      const [absolute, delta] = karmaHistory.changes;
+      assert.equal(absolute.oldKarma, before.karma);
+      assert.equal(absolute.newKarma, 800);
+      assert.equal(absolute.reason, null);
+      assert.ok(absolute.signer.equals(admin.publicKey));
+      assert.equal(delta.oldKarma, 800);
+      assert.equal(delta.newKarma, 750);
+      assert.deepEqual(delta.reason, { downvote: {} });
+    });
+
+    it("4. batch_update_karma: Records the change when the history follows the user's account", async () => {
+      await program.methods
+        .batchUpdateKarma([{ user: user2.publicKey, karma: 900 }])
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .remainingAccounts([
+          { pubkey: user2AccountPda, isSigner: false, isWritable: true },
+          { pubkey: user2KarmaHistoryPda, isSigner: false, isWritable: true },
+        ])
+        .signers([admin])
+        .rpc();
+
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      const latest = karmaHistory.changes[karmaHistory.changes.length - 1];
+      assert.equal(latest.oldKarma, 750);
+      assert.equal(latest.newKarma, 900);
+    });
+
+    it("5. update_user_karma: The oldest change is overwritten once the history is full", async () => {
+      // 3 changes so far; fill the 16 slots and wrap around once.
+      for (let karma = 1; karma <= 14; karma++) {
+        await updateUser2Karma(karma, user2KarmaHistoryPda);
+      }
+
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      assert.equal(karmaHistory.changes.length, 16);
+      assert.equal(karmaHistory.nextIndex, 1);
+      assert.equal(karmaHistory.changes[0].newKarma, 14);
+      assert.equal(karmaHistory.changes[1].newKarma, 750); // Now the oldest
+    });
+
+    it("6. update_user_karma: Decay settled by a write is recorded before the write", async () => {
+      const setKarmaDecay = (decay: any) =>
+        program.methods
+          .setKarmaDecay(decay)
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([admin])
+          .rpc();
+
+      await updateUser2Karma(1000, user2KarmaHistoryPda);
+      // 10 karma per second, so the decay shows up within the test.
+      await setKarmaDecay({ linear: { pointsPerDay: 864000 } });
+      await new Promise((resolve) => setTimeout(resolve, 2000));
+      await updateUser2Karma(1000, user2KarmaHistoryPda);
+      await setKarmaDecay({ none: {} });
+
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      const decay = karmaHistory.changes[(karmaHistory.nextIndex + 14) % 16];
+      const write = karmaHistory.changes[(karmaHistory.nextIndex + 15) % 16];
+      assert.deepEqual(decay.reason, { decay: {} });
+      assert.equal(decay.oldKarma, 1000);
+      assert.ok(decay.newKarma < 1000, "Karma should have decayed");
+      assert.ok(decay.signer.equals(PublicKey.default));
+      assert.equal(write.reason, null);
+      assert.equal(write.oldKarma, decay.newKarma);
+      assert.equal(write.newKarma, 1000);
+    });
+  });
+
+  describe("AB. Karma Gifts", () => {
+    const [user2KarmaHistoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("karma_history"), user2.publicKey.toBuffer()],
+      programId
+    );
+
+    const setKarmaGiftRules = (signer: Keypair, rules: { dailyCap: number; minBalance: number }) =>
+      program.methods
+        .setKarmaGiftRules(rules)
+        .accounts({
+          admin: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    const giftKarma = (amount: number, receiver: Keypair, receiverAccount: PublicKey, receiverKarmaHistory: PublicKey | null) =>
+      program.methods
+        .giftKarma(amount)
+        .accounts({
+          giver: user1.publicKey,
+          globalConfig: globalConfigPda,
+          giverAccount: user1AccountPda,
+          receiverAccount,
+          giverKarmaHistory: null,
+          receiverKarmaHistory,
+          receiver: receiver.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+    before(async () => {
+      await program.methods
+        .updateUserKarma(500)
+        .accounts({
+          karmaOracle: admin.publicKey,
+          globalConfig: globalConfigPda,
+          userAccount: user1AccountPda,
+          karmaHistory: null,
+          userToUpdate: user1.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    after(async () => {
+      await setKarmaGiftRules(admin, { dailyCap: 0, minBalance: 0 });
+    });
+
+    it("1. gift_karma: Fails while gifting is turned off (VoixError::KarmaGiftingDisabled)", async () => {
+      await assert.rejects(
+        giftKarma(10, user2, user2AccountPda, user2KarmaHistoryPda),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "KarmaGiftingDisabled";
+        },
+        "Should have failed with VoixError::KarmaGiftingDisabled"
+      );
+    });
+
+    it("2. set_karma_gift_rules: Fails if a non-admin sets the rules (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        setKarmaGiftRules(nonAdmin, { dailyCap: 1000, minBalance: 0 }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("3. gift_karma: Fails for a gift to yourself (VoixError::SelfGiftNotAllowed)", async () => {
+      await setKarmaGiftRules(admin, { dailyCap: 100, minBalance: 50 });
+      await assert.rejects(
+        giftKarma(10, user1, user1AccountPda, null),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "SelfGiftNotAllowed";
+        },
+        "Should have failed with VoixError::SelfGiftNotAllowed"
+      );
+    });
+
+    it("4. gift_karma: Moves karma from the giver to the receiver", async () => {
+      const receiverBefore = await program.account.userAccount.fetch(user2AccountPda);
+      await giftKarma(60, user2, user2AccountPda, user2KarmaHistoryPda);
+
+      const giverAccount = await program.account.userAccount.fetch(user1AccountPda);
+      const receiverAccount = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(giverAccount.karma, 440);
+      assert.equal(giverAccount.karmaGiftedToday, 60);
+      assert.equal(receiverAccount.karma, receiverBefore.karma + 60);
+
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      const latest = karmaHistory.changes[(karmaHistory.nextIndex + 15) % 16];
+      assert.deepEqual(latest.reason, { gift: {} });
+      assert.ok(latest.signer.equals(user1.publicKey));
+    });
+
+    it("5. gift_karma: Fails over the daily cap (VoixError::GiftDailyCapExceeded)", async () => {
+      await assert.rejects(
+        giftKarma(41, user2, user2AccountPda, user2KarmaHistoryPda), // 60 + 41 > 100
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "GiftDailyCapExceeded";
+        },
+        "Should have failed with VoixError::GiftDailyCapExceeded"
+      );
+    });
+
+    it("6. gift_karma: Fails if the giver would drop below the minimum balance (VoixError::GiftBelowMinBalance)", async () => {
+      await setKarmaGiftRules(admin, { dailyCap: 100, minBalance: 420 });
+      await assert.rejects(
+        giftKarma(30, user2, user2AccountPda, user2KarmaHistoryPda), // 440 - 30 < 420
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "GiftBelowMinBalance";
+        },
+        "Should have failed with VoixError::GiftBelowMinBalance"
+      );
+    });
+  });
+
+  describe("AC. Tip Karma", () => {
+    const [user2KarmaHistoryPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("karma_history"), user2.publicKey.toBuffer()],
+      programId
+    );
+    const [tipKarmaCreditPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("tip_karma"), user1.publicKey.toBuffer(), user2.publicKey.toBuffer()],
+      programId
+    );
+    const minSolAmount = new BN(0.01 * LAMPORTS_PER_SOL);
+
+    const setTipKarmaRule = (signer: Keypair, rule: any) =>
+      program.methods
+        .setTipKarmaRule(rule)
+        .accounts({
+          admin: signer.publicKey,
+          globalConfig: globalConfigPda,
+        })
+        .signers([signer])
+        .rpc();
+
+    const tipUser2 = (amount: BN, tipKarmaCredit: PublicKey | null) =>
+      program.methods
+        .tipUserSol(amount)
+        .accounts({
+          tipper: user1.publicKey,
+          globalConfig: globalConfigPda,
+          receiver: user2.publicKey,
+          receiverAccount: user2AccountPda,
+          tipperAccount: user1AccountPda,
+          tipKarmaCredit,
+          receiverKarmaHistory: user2KarmaHistoryPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+    after(async () => {
+      await setTipKarmaRule(admin, {
+        karmaPerTip: 0,
+        maxCreditsPerPair: 0,
+        minSolAmount: new BN(0),
+        minSplAmount: new BN(0),
+        splMint: PublicKey.default,
+      });
+    });
+
+    it("1. set_tip_karma_rule: Fails if a non-admin sets the rule (VoixError::Unauthorized)", async () => {
+      await assert.rejects(
+        setTipKarmaRule(nonAdmin, {
+          karmaPerTip: 1000,
+          maxCreditsPerPair: 0,
+          minSolAmount: new BN(1),
+          minSplAmount: new BN(0),
+          splMint: PublicKey.default,
+        }),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "Unauthorized";
+        },
+        "Should have failed with VoixError::Unauthorized"
+      );
+    });
+
+    it("2. tip_user_sol: A qualifying tip credits karma to the receiver", async () => {
+      await setTipKarmaRule(admin, {
+        karmaPerTip: 5,
+        maxCreditsPerPair: 2,
+        minSolAmount,
+        minSplAmount: new BN(0),
+        splMint: PublicKey.default,
+      });
+      const before = await program.account.userAccount.fetch(user2AccountPda);
+      await tipUser2(minSolAmount, tipKarmaCreditPda);
+
+      const after = await program.account.userAccount.fetch(user2AccountPda);
+      const tipKarmaCredit = await program.account.tipKarmaCredit.fetch(tipKarmaCreditPda);
+      const karmaHistory = await program.account.karmaHistory.fetch(user2KarmaHistoryPda);
+      const latest = karmaHistory.changes[(karmaHistory.nextIndex + 15) % 16];
+      assert.equal(after.karma, before.karma + 5);
+      assert.equal(tipKarmaCredit.credits, 1);
+      assert.deepEqual(latest.reason, { tip: {} });
+      assert.ok(latest.signer.equals(user1.publicKey));
+    });
+
+    it("3. tip_user_sol: A tip below the minimum earns no karma", async () => {
+      const before = await program.account.userAccount.fetch(user2AccountPda);
+      await tipUser2(minSolAmount.subn(1), tipKarmaCreditPda);
+
+      const after = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(after.karma, before.karma);
+    });
+
+    it("4. tip_user_sol: Tips over the per-pair cap still go through but earn no karma", async () => {
+      await tipUser2(minSolAmount, tipKarmaCreditPda); // Second credit, the cap
+      const before = await program.account.userAccount.fetch(user2AccountPda);
+      await tipUser2(minSolAmount, tipKarmaCreditPda);
+
+      const after = await program.account.userAccount.fetch(user2AccountPda);
+      const tipKarmaCredit = await program.account.tipKarmaCredit.fetch(tipKarmaCreditPda);
+      assert.equal(after.karma, before.karma);
+      assert.equal(tipKarmaCredit.credits, 2);
+    });
+
+    it("5. tip_user_sol: Fails if a qualifying tip leaves out the credit account (VoixError::MissingTipKarmaCredit)", async () => {
+      await assert.rejects(
+        tipUser2(minSolAmount, null),
+        (e: anchor.AnchorError) => {
+          return e.error.errorCode.code === "MissingTipKarmaCredit";
+        },
+        "Should have failed with VoixError::MissingTipKarmaCredit"
+      );
+    });
+
+    it("6. tip_user_sol: Tips over a karma limit still go through but earn no karma", async () => {
+      const setKarmaLimits = (maxIncreasePerUser: number) =>
+        program.methods
+          .setKarmaLimits({
+            maxIncreasePerUser,
+            maxIncreasePerEpoch: new BN(0),
+            mintCooldownSecs: 0,
+          })
+          .accounts({
+            admin: admin.publicKey,
+            globalConfig: globalConfigPda,
+          })
+          .signers([admin])
+          .rpc();
+
+      await setTipKarmaRule(admin, {
+        karmaPerTip: 5,
+        maxCreditsPerPair: 0,
+        minSolAmount,
+        minSplAmount: new BN(0),
+        splMint: PublicKey.default,
+      });
+      await setKarmaLimits(1); // Below a single credit
+      const before = await program.account.userAccount.fetch(user2AccountPda);
+      const creditBefore = await program.account.tipKarmaCredit.fetch(tipKarmaCreditPda);
+      await tipUser2(minSolAmount, tipKarmaCreditPda);
+      await setKarmaLimits(0);
+
+      const after = await program.account.userAccount.fetch(user2AccountPda);
+      const tipKarmaCredit = await program.account.tipKarmaCredit.fetch(tipKarmaCreditPda);
+      assert.equal(after.karma, before.karma);
+      assert.equal(tipKarmaCredit.credits, creditBefore.credits);
+    });
+
+    it("7. tip_user_spl: Only tips in the rule's mint earn karma", async () => {
+      // A fresh mint with 1000 base units in User 1's wallet
+      const createFundedMint = async () => {
+        const mint = await createMint(connection, admin, admin.publicKey, null, 0);
+        const tipperTokenAccount = await getAssociatedTokenAddress(mint, user1.publicKey);
+        await provider.sendAndConfirm(
+          new anchor.web3.Transaction().add(
+            createAssociatedTokenAccountInstruction(
+              admin.publicKey,
+              tipperTokenAccount,
+              user1.publicKey,
+              mint
+            )
+          ),
+          [admin]
+        );
+        await mintTo(connection, admin, mint, tipperTokenAccount, admin.publicKey, 1000);
+        return { mint, tipperTokenAccount };
+      };
+      const tipUser2Spl = async (
+        { mint, tipperTokenAccount }: { mint: PublicKey; tipperTokenAccount: PublicKey },
+        amount: BN
+      ) =>
+        program.methods
+          .tipUserSpl(amount)
+          .accounts({
+            tipper: user1.publicKey,
+            globalConfig: globalConfigPda,
+            receiver: user2.publicKey,
+            receiverAccount: user2AccountPda,
+            tipperAccount: user1AccountPda,
+            tipKarmaCredit: tipKarmaCreditPda,
+            receiverKarmaHistory: user2KarmaHistoryPda,
+            mint,
+            tipperTokenAccount,
+            receiverTokenAccount: await getAssociatedTokenAddress(mint, user2.publicKey),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+      const allowed = await createFundedMint();
+      const other = await createFundedMint();
+      await setTipKarmaRule(admin, {
+        karmaPerTip: 5,
+        maxCreditsPerPair: 0,
+        minSolAmount,
+        minSplAmount: new BN(100),
+        splMint: allowed.mint,
+      });
+
+      // Another mint earns nothing, however large the tip
+      let before = await program.account.userAccount.fetch(user2AccountPda);
+      await tipUser2Spl(other, new BN(1000));
+      let after = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(after.karma, before.karma, "A tip in another mint should earn no karma");
+
+      before = after;
+      await tipUser2Spl(allowed, new BN(100));
+      after = await program.account.userAccount.fetch(user2AccountPda);
+      assert.equal(after.karma, before.karma + 5);
+    });
+  });
 });